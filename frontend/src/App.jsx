@@ -5,6 +5,11 @@ import i18n from './i18n/index.js'
 import { open, save } from '@tauri-apps/plugin-dialog'
 import './App.css'
 
+// Non-empty lines of a textarea value, or of a list loaded from the backend
+function splitLines(value) {
+  return [].concat(value || []).join('\n').split('\n').map(line => line.trim()).filter(line => line)
+}
+
 function App() {
   const { t } = useTranslation()
   const [launchers, setLaunchers] = useState([])
@@ -19,8 +24,23 @@ function App() {
     name: '',
     type: 'app',
     target: '',
-    icon: ''
+    icon: '',
+    script: '',
+    group: null,
+    chain: '',
+    preconditions: '',
+    fallbacks: '',
+    parameters: '',
+    web: null,
+    ssh: null,
+    container: null,
+    options: null
   })
+  const [searchQuery, setSearchQuery] = useState('')
+  const [browsers, setBrowsers] = useState([])
+  const [containerStatus, setContainerStatus] = useState({})
+  const [bookmarkImport, setBookmarkImport] = useState(null)
+  const [configImport, setConfigImport] = useState(null)
   const [notification, setNotification] = useState({ show: false, message: '', type: 'success' })
   const fileInputRef = useRef(null)
   const backgroundFileInputRef = useRef(null)
@@ -28,6 +48,7 @@ function App() {
   useEffect(() => {
     loadLaunchers()
     invoke("get_settings").then(s => setSettings(s)).catch(() => {})
+    invoke('get_browsers').then(b => setBrowsers(b)).catch(() => {})
     // Charger le fond d'écran au démarrage
     invoke('get_background').then(bg => {
       if (bg) {
@@ -51,6 +72,7 @@ function App() {
       const result = await invoke('get_launchers')
       setLaunchers(result)
       setError(null)
+      result.filter(l => l.launch_type === 'container').forEach(l => refreshContainerStatus(l.id))
     } catch (err) {
       setError(err.toString())
     } finally {
@@ -58,20 +80,57 @@ function App() {
     }
   }
 
+  async function refreshContainerStatus(id) {
+    try {
+      const status = await invoke('get_container_status', { id })
+      setContainerStatus(previous => ({ ...previous, [id]: status.state }))
+    } catch {
+      setContainerStatus(previous => ({ ...previous, [id]: null }))
+    }
+  }
+
+  async function containerAction(id, action) {
+    try {
+      const output = await invoke('container_action_cmd', { id, action })
+      if (action === 'logs') {
+        window.alert(output.stdout + output.stderr)
+      } else {
+        showNotification(`${t('exitCode')}: ${output.status_code}`, output.success ? 'success' : 'error')
+      }
+      await refreshContainerStatus(id)
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
   async function addLauncher(e) {
     e.preventDefault()
     try {
-      if (selectedLauncher) {
-        await invoke('remove_launcher_cmd', { id: selectedLauncher.id })
-      }
-      
-      await invoke('add_launcher_cmd', {
+      // Parse every JSON field before touching the stored launcher
+      const launcher = {
         name: formData.name,
         launchType: formData.type,
         target: formData.target,
         icon: formData.icon || null,
-      })
-      setFormData({ name: '', type: 'app', target: '', icon: '' })
+        script: formData.type === 'script' ? formData.script : null,
+        group: formData.type === 'group' ? (formData.group || { members: [], mode: 'ordered', stop_on_failure: false }) : null,
+        chain: formData.type === 'chain' ? JSON.parse(formData.chain || '[]') : null,
+        preconditions: formData.preconditions.trim() ? JSON.parse(formData.preconditions) : null,
+        fallbacks: formData.fallbacks.trim() ? JSON.parse(formData.fallbacks) : null,
+        parameters: formData.parameters.trim() ? JSON.parse(formData.parameters) : null,
+        web: formData.type === 'web' ? formData.web : null,
+        ssh: formData.type === 'ssh' ? formData.ssh : null,
+        container: formData.type === 'container' ? formData.container : null,
+        options: formData.options,
+      }
+
+      if (selectedLauncher) {
+        await invoke('update_launcher_cmd', { id: selectedLauncher.id, ...launcher })
+      } else {
+        await invoke('add_launcher_cmd', launcher)
+      }
+
+      setFormData({ name: '', type: 'app', target: '', icon: '', script: '', group: null, chain: '', preconditions: '', fallbacks: '', parameters: '', web: null, ssh: null, container: null, options: null })
       setShowModal(false)
       setSelectedLauncher(null)
       await loadLaunchers()
@@ -91,15 +150,83 @@ function App() {
     }
   }
 
+  function promptParameters(parameters) {
+    const values = {}
+    for (const parameter of parameters) {
+      const label = parameter.label || parameter.name
+      const hint = parameter.kind === 'choice' ? ` (${parameter.choices.join(', ')})` : parameter.kind === 'boolean' ? ' (true/false)' : ''
+      const value = window.prompt(`${label}${hint}`, parameter.default || '')
+      if (value === null) return null
+      values[parameter.name] = value
+    }
+    return values
+  }
+
   async function executeLauncher(id) {
     try {
-      const result = await invoke('execute_launcher_cmd', { id })
-      console.log(result)
+      const launcher = launchers.find(l => l.id === id)
+      if (launcher && launcher.launch_type === 'search') {
+        const query = window.prompt(launcher.name, '')
+        if (query === null) return
+        await invoke('search_web', { query, id })
+        return
+      }
+      let values = null
+      if (launcher && launcher.parameters && launcher.parameters.length > 0) {
+        values = promptParameters(launcher.parameters)
+        if (values === null) return
+      }
+      const result = await invoke('execute_launcher_cmd', { id, values })
+      console.log(result.message)
+      if (result.output) {
+        showNotification(`${t('exitCode')}: ${result.output.status_code} (${result.output.duration_ms} ms)`, result.output.success ? 'success' : 'error')
+      } else if (result.report.members.length > 0) {
+        showNotification(result.report.success ? result.message : result.report.error, result.report.success ? 'success' : 'error')
+      }
     } catch (err) {
       setError('Error executing launcher: ' + err.toString())
     }
   }
 
+  async function handleSearch(e) {
+    if (e.key !== 'Enter' || !searchQuery.trim()) return
+    try {
+      await invoke('search_web', { query: searchQuery, id: null })
+      setSearchQuery('')
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function handleExportSearchEngines() {
+    try {
+      const json = await invoke('export_search_engines')
+      const filePath = await save({
+        defaultPath: 'search_engines.json',
+        filters: [{ name: 'JSON', extensions: ['json'] }]
+      })
+      if (filePath) {
+        await invoke('write_file', { path: filePath, content: json })
+        showNotification(t('exportSuccess'))
+      }
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function handleImportSearchEngines() {
+    try {
+      const filePath = await open({ filters: [{ name: 'JSON', extensions: ['json'] }] })
+      if (filePath) {
+        const json = await invoke('read_file_as_text', { path: filePath })
+        await invoke('import_search_engines', { json })
+        showNotification(t('importSuccess'))
+      }
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
   async function handleExport() {
     try {
       const json = await invoke('export_config')
@@ -124,9 +251,196 @@ function App() {
       })
       if (filePath) {
         const json = await invoke('read_file_as_text', { path: filePath })
-        await invoke('import_config', { json })
+        await previewConfigImport(json, { mode: 'replace', conflict: 'skip' })
+      }
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function previewConfigImport(json, options) {
+    try {
+      const diff = await invoke('preview_config_import', { json, options })
+      setConfigImport({ json, options, diff })
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function applyConfigImport() {
+    try {
+      const { json, options } = configImport
+      await invoke('import_config', { json, options })
+      setConfigImport(null)
+      await loadLaunchers()
+      showNotification(t('importSuccess'))
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function handleImportInstalledApps() {
+    try {
+      const apps = (await invoke('get_installed_apps')).filter(a => !a.exists)
+      if (apps.length === 0) {
+        showNotification(t('noNewApps'), 'error')
+        return
+      }
+      const list = apps.map(a => '+ ' + a.name + ' (' + a.type + ': ' + a.id + ')').join('\n')
+      if (window.confirm(t('confirmAppsImport') + '\n\n' + list)) {
+        const added = await invoke('import_installed_apps', { ids: apps.map(a => a.id) })
         await loadLaunchers()
-        showNotification(t('importSuccess'))
+        showNotification(t('appsImported', { count: added }))
+      }
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function openBookmarkImport() {
+    try {
+      const sources = await invoke('get_bookmark_sources')
+      setBookmarkImport({ sources, path: null, format: null, folders: [], selected: [] })
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function chooseBookmarkSource(path, format) {
+    try {
+      const folders = await invoke('get_bookmark_folders', { path, format })
+      setBookmarkImport({ ...bookmarkImport, path, format, folders, selected: folders.map(f => f.path) })
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function chooseBookmarkFile() {
+    const filePath = await open({ filters: [{ name: 'HTML', extensions: ['html', 'htm'] }] })
+    if (filePath) {
+      await chooseBookmarkSource(filePath, 'html')
+    }
+  }
+
+  function toggleBookmarkFolder(path) {
+    const selected = bookmarkImport.selected.includes(path)
+      ? bookmarkImport.selected.filter(f => f !== path)
+      : [...bookmarkImport.selected, path]
+    setBookmarkImport({ ...bookmarkImport, selected })
+  }
+
+  async function importSelectedBookmarks() {
+    try {
+      const { path, format, selected } = bookmarkImport
+      const added = await invoke('import_bookmarks', { path, format, folders: selected })
+      setBookmarkImport(null)
+      await loadLaunchers()
+      showNotification(t('appsImported', { count: added }))
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function handleExportBookmarks() {
+    try {
+      const html = await invoke('export_bookmarks')
+      const filePath = await save({
+        defaultPath: 'bookmarks.html',
+        filters: [{ name: 'HTML', extensions: ['html'] }]
+      })
+      if (filePath) {
+        await invoke('write_file', { path: filePath, content: html })
+        showNotification(t('exportSuccess'))
+      }
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function handleImportShortcuts() {
+    try {
+      const selected = await open({
+        multiple: true,
+        filters: [{ name: t('shortcuts'), extensions: ['url', 'lnk', 'webloc', 'URL', 'LNK'] }]
+      })
+      if (!selected) return
+      const paths = Array.isArray(selected) ? selected : [selected]
+      const summary = await invoke('import_shortcuts', { paths })
+      await loadLaunchers()
+      showNotification(t('shortcutsImported', { added: summary.added, skipped: summary.skipped, failed: summary.failed.length }), summary.failed.length > 0 ? 'error' : 'success')
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function handleImportSteamGames() {
+    try {
+      const games = (await invoke('get_steam_games')).filter(g => !g.exists)
+      if (games.length === 0) {
+        showNotification(t('noNewSteamGames'), 'error')
+        return
+      }
+      const list = games.map(g => '+ ' + g.name + ' (' + g.app_id + ')').join('\n')
+      if (window.confirm(t('confirmSteamImport') + '\n\n' + list)) {
+        const added = await invoke('import_steam_games', { appIds: games.map(g => g.app_id) })
+        await loadLaunchers()
+        showNotification(t('appsImported', { count: added }))
+      }
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function handleImportProjects() {
+    try {
+      const projects = (await invoke('get_projects')).filter(p => !p.exists)
+      if (projects.length > 0) {
+        const list = projects.map(p => '+ ' + p.name + ' (' + p.kind + ': ' + p.path + ')').join('\n')
+        if (!window.confirm(t('confirmProjectsImport') + '\n\n' + list)) return
+      }
+      const summary = await invoke('import_projects')
+      if (summary.added === 0 && summary.updated === 0) {
+        showNotification(t('noNewProjects'), 'error')
+        return
+      }
+      await loadLaunchers()
+      showNotification(t('projectsImported', { added: summary.added, updated: summary.updated }))
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function handleImportAppImages() {
+    try {
+      const appimages = (await invoke('get_appimages')).filter(a => !a.exists)
+      if (appimages.length > 0) {
+        const list = appimages.map(a => '+ ' + a.name + (a.version ? ' ' + a.version : '') + ' (' + a.path + ')').join('\n')
+        if (!window.confirm(t('confirmAppImagesImport') + '\n\n' + list)) return
+      }
+      const summary = await invoke('import_appimages')
+      if (summary.added === 0 && summary.updated === 0) {
+        showNotification(t('noNewAppImages'), 'error')
+        return
+      }
+      await loadLaunchers()
+      showNotification(t('appImagesImported', { added: summary.added, updated: summary.updated }))
+    } catch (err) {
+      setError(err.toString())
+    }
+  }
+
+  async function handleImportSshHosts() {
+    try {
+      const hosts = await invoke('get_ssh_hosts')
+      if (hosts.length === 0) {
+        showNotification(t('sshNoHosts'), 'error')
+        return
+      }
+      const list = hosts.map(h => (h.exists ? '✓ ' : '+ ') + h.alias + (h.hostname ? ' (' + h.hostname + ')' : '')).join('\n')
+      if (window.confirm(t('confirmSshImport') + '\n\n' + list)) {
+        const summary = await invoke('import_ssh_hosts', { aliases: null })
+        await loadLaunchers()
+        showNotification(t('sshImported', summary))
       }
     } catch (err) {
       setError(err.toString())
@@ -157,7 +471,7 @@ function App() {
 
   const openAddModal = () => {
     setSelectedLauncher(null)
-    setFormData({ name: '', type: 'app', target: '', icon: '' })
+    setFormData({ name: '', type: 'app', target: '', icon: '', script: '', group: null, chain: '', preconditions: '', fallbacks: '', parameters: '', web: null, ssh: null, container: null, options: null })
     setShowModal(true)
   }
 
@@ -167,7 +481,17 @@ function App() {
       name: launcher.name,
       type: launcher.launch_type,
       target: launcher.target,
-      icon: launcher.icon || ''
+      icon: launcher.icon || '',
+      script: launcher.script || '',
+      group: launcher.group || null,
+      chain: launcher.chain ? JSON.stringify(launcher.chain, null, 2) : '',
+      preconditions: launcher.preconditions ? JSON.stringify(launcher.preconditions, null, 2) : '',
+      fallbacks: launcher.fallbacks ? JSON.stringify(launcher.fallbacks, null, 2) : '',
+      parameters: launcher.parameters ? JSON.stringify(launcher.parameters, null, 2) : '',
+      web: launcher.web || null,
+      ssh: launcher.ssh || null,
+      container: launcher.container || null,
+      options: launcher.options || null
     })
     setShowModal(true)
   }
@@ -175,7 +499,7 @@ function App() {
   const closeModal = () => {
     setShowModal(false)
     setSelectedLauncher(null)
-    setFormData({ name: '', type: 'app', target: '', icon: '' })
+    setFormData({ name: '', type: 'app', target: '', icon: '', script: '', group: null, chain: '', preconditions: '', fallbacks: '', parameters: '', web: null, ssh: null, container: null, options: null })
   }
 
   const showNotification = (message, type = 'success') => {
@@ -232,6 +556,17 @@ function App() {
         language: settings.language,
         background: JSON.stringify(backgroundColor)
       })
+      await invoke('set_terminal', { terminal: settings.terminal || null })
+      await invoke('set_appimage_dirs', { dirs: splitLines(settings.appimage_dirs) })
+      const projects = settings.projects || {}
+      await invoke('set_project_settings', {
+        settings: {
+          roots: splitLines(projects.roots),
+          max_depth: Number(projects.max_depth) || 3,
+          ignore: splitLines(projects.ignore),
+          editor: projects.editor || null
+        }
+      })
       showNotification(t('settingsSaved'))
     } catch (err) {
       setError(err.toString())
@@ -288,9 +623,17 @@ function App() {
           <p>{t('subtitle')}</p>
         </div>
         <div className="header-actions">
+          <input type="search" className="search-input" placeholder={t('searchPlaceholder')} value={searchQuery} onChange={(e) => setSearchQuery(e.target.value)} onKeyDown={handleSearch} />
           <button className="btn-add" onClick={openAddModal}>{t('addLauncher')}</button>
           <button className="btn-export" onClick={handleExport}>{t('export')}</button>
           <button className="btn-import" onClick={handleImport}>{t('import')}</button>
+          <button className="btn-import" onClick={handleImportInstalledApps}>{t('importApps')}</button>
+          <button className="btn-import" onClick={handleImportAppImages}>{t('importAppImages')}</button>
+          <button className="btn-import" onClick={handleImportSteamGames}>{t('importSteam')}</button>
+          <button className="btn-import" onClick={openBookmarkImport}>{t('bookmarks')}</button>
+          <button className="btn-import" onClick={handleImportShortcuts}>{t('shortcuts')}</button>
+          <button className="btn-import" onClick={handleImportProjects}>{t('importProjects')}</button>
+          <button className="btn-import" onClick={handleImportSshHosts}>{t('importSsh')}</button>
           <button className="btn-settings" onClick={openSettingsModal}>⚙️</button>
         </div>
       </header>
@@ -300,7 +643,7 @@ function App() {
       {notification.show && (<div className={`notification-banner ${notification.type}`}><span>{notification.message}</span><button onClick={() => setNotification({ show: false, message: '', type: 'success' })}>✕</button></div>)}
 
       <main className="main-content">
-        {loading ? (<div className="loading">{t('loading')}</div>) : launchers.length === 0 ? (<div className="empty-state"><div className="empty-icon">📭</div><h2>{t('noLaunchers')}</h2><p>{t('noLaunchersDesc')}</p><button className="btn-add-large" onClick={openAddModal}>{t('addFirstLauncher')}</button></div>) : (<div className="launchers-grid">{launchers.map((launcher) => (<div key={launcher.id} className="launcher-card"><div className="card-icon" onClick={() => executeLauncher(launcher.id)} style={{cursor: "pointer"}}>{launcher.icon ? (<img src={launcher.icon} alt={launcher.name} />) : (<div className="icon-placeholder">{launcher.launch_type === 'web' ? '🌐' : '⚙️'}</div>)}</div><div className="card-content"><h3>{launcher.name}</h3><p className="card-type">{launcher.launch_type.toUpperCase()}</p><p className="card-target" title={launcher.target}>{launcher.target}</p></div><div className="card-actions"><button className="btn-edit" onClick={() => openEditModal(launcher)}>✏️</button><button className="btn-delete" onClick={() => removeLauncher(launcher.id)}>🗑</button></div></div>))}</div>)}
+        {loading ? (<div className="loading">{t('loading')}</div>) : launchers.length === 0 ? (<div className="empty-state"><div className="empty-icon">📭</div><h2>{t('noLaunchers')}</h2><p>{t('noLaunchersDesc')}</p><button className="btn-add-large" onClick={openAddModal}>{t('addFirstLauncher')}</button></div>) : (<div className="launchers-grid">{launchers.map((launcher) => (<div key={launcher.id} className="launcher-card"><div className="card-icon" onClick={() => executeLauncher(launcher.id)} style={{cursor: "pointer"}}>{launcher.icon ? (<img src={launcher.icon} alt={launcher.name} />) : (<div className="icon-placeholder">{launcher.launch_type === 'web' ? '🌐' : '⚙️'}</div>)}</div><div className="card-content"><h3>{launcher.name}</h3><p className="card-type">{launcher.launch_type.toUpperCase()}{containerStatus[launcher.id] ? ' · ' + t('containerState_' + containerStatus[launcher.id]) : ''}</p><p className="card-target" title={launcher.target}>{launcher.target}</p></div><div className="card-actions">{launcher.launch_type === 'container' && (<><button className="btn-execute" title={t('containerStop')} onClick={() => containerAction(launcher.id, 'stop')}>⏹</button><button className="btn-execute" title={t('containerLogs')} onClick={() => containerAction(launcher.id, 'logs')}>📜</button></>)}<button className="btn-edit" onClick={() => openEditModal(launcher)}>✏️</button><button className="btn-delete" onClick={() => removeLauncher(launcher.id)}>🗑</button></div></div>))}</div>)}
       </main>
 
       {showModal && (
@@ -320,17 +663,174 @@ function App() {
                 <select value={formData.type} onChange={(e) => setFormData({...formData, type: e.target.value})}>
                   <option value="app">{t('application')}</option>
                   <option value="web">{t('website')}</option>
+                  <option value="command">{t('command')}</option>
+                  <option value="script">{t('script')}</option>
+                  <option value="group">{t('group')}</option>
+                  <option value="chain">{t('chain')}</option>
+                  <option value="search">{t('search')}</option>
+                  <option value="ssh">{t('ssh')}</option>
+                  <option value="container">{t('container')}</option>
+                  <option value="flatpak">Flatpak</option>
+                  <option value="snap">Snap</option>
                 </select>
               </div>
               <div className="form-group">
                 <label>{t('target')}</label>
-                <input type="text" placeholder="/usr/bin/app or https://example.com" value={formData.target} onChange={(e) => setFormData({...formData, target: e.target.value})} required />
+                <input type="text" placeholder="/usr/bin/app or https://example.com" value={formData.target} onChange={(e) => setFormData({...formData, target: e.target.value})} required={!['script', 'group', 'chain'].includes(formData.type)} />
+              </div>
+              {formData.type === 'group' && (() => {
+                const group = formData.group || { members: [], mode: 'ordered', stop_on_failure: false }
+                return (
+                  <div className="form-group">
+                    <label>{t('groupMembers')}</label>
+                    <select multiple value={group.members.map(m => m.id)} onChange={(e) => { const ids = Array.from(e.target.selectedOptions, o => o.value); setFormData({...formData, group: {...group, members: ids.map(id => group.members.find(m => m.id === id) || { id, delay_ms: 0 })}}) }}>
+                      {launchers.filter(l => l.id !== selectedLauncher?.id).map(l => (<option key={l.id} value={l.id}>{l.name}</option>))}
+                    </select>
+                    <select value={group.mode} onChange={(e) => setFormData({...formData, group: {...group, mode: e.target.value}})}>
+                      <option value="ordered">{t('groupOrdered')}</option>
+                      <option value="parallel">{t('groupParallel')}</option>
+                    </select>
+                    <label><input type="checkbox" checked={group.stop_on_failure} onChange={(e) => setFormData({...formData, group: {...group, stop_on_failure: e.target.checked}})} /> {t('groupStopOnFailure')}</label>
+                  </div>
+                )
+              })()}
+              {formData.type === 'web' && (() => {
+                const web = formData.web || { urls: [], browser: null, profile: null, new_window: false, private_window: false, display: 'browser' }
+                const setWeb = (changes) => setFormData({...formData, web: {...web, ...changes}})
+                return (
+                  <div className="form-group">
+                    <label>{t('display')}</label>
+                    <select value={web.display || 'browser'} onChange={(e) => setWeb({ display: e.target.value })}>
+                      <option value="browser">{t('displayBrowser')}</option>
+                      <option value="embedded">{t('displayEmbedded')}</option>
+                    </select>
+                    <label>{t('extraUrls')}</label>
+                    <textarea rows={3} placeholder="https://example.com/dashboard" value={web.urls.join('\n')} onChange={(e) => setWeb({ urls: e.target.value.split('\n').filter(u => u.trim()) })} />
+                    <label>{t('browser')}</label>
+                    <input type="text" list="browser-list" placeholder="firefox, chromium..." value={web.browser || ''} onChange={(e) => setWeb({ browser: e.target.value || null })} />
+                    <datalist id="browser-list">{browsers.map(b => (<option key={b.id} value={b.command}>{b.name}</option>))}</datalist>
+                    <label>{t('browserProfile')}</label>
+                    <input type="text" placeholder="work" value={web.profile || ''} onChange={(e) => setWeb({ profile: e.target.value || null })} />
+                    <label><input type="checkbox" checked={web.new_window} onChange={(e) => setWeb({ new_window: e.target.checked })} /> {t('newWindow')}</label>
+                    <label><input type="checkbox" checked={web.private_window} onChange={(e) => setWeb({ private_window: e.target.checked })} /> {t('privateWindow')}</label>
+                  </div>
+                )
+              })()}
+              {formData.type === 'chain' && (
+                <div className="form-group">
+                  <label>{t('chainSteps')}</label>
+                  <textarea rows={8} placeholder={'[{"step": "start", "target": "npm", "args": ["run", "dev"]},\n {"step": "wait", "probe": {"kind": "tcp", "address": "localhost:5173"}, "timeout_secs": 60},\n {"step": "open", "url": "http://localhost:5173"}]'} value={formData.chain} onChange={(e) => setFormData({...formData, chain: e.target.value})} required />
+                </div>
+              )}
+              {formData.type === 'ssh' && (() => {
+                const ssh = formData.ssh || { user: null, port: null, identity: null, jump_host: null }
+                const setSsh = (changes) => setFormData({...formData, ssh: {...ssh, ...changes}})
+                return (
+                  <div className="form-group">
+                    <label>{t('sshUser')}</label>
+                    <input type="text" placeholder="root" value={ssh.user || ''} onChange={(e) => setSsh({ user: e.target.value || null })} />
+                    <label>{t('sshPort')}</label>
+                    <input type="number" min="1" max="65535" placeholder="22" value={ssh.port || ''} onChange={(e) => setSsh({ port: e.target.value ? parseInt(e.target.value, 10) : null })} />
+                    <label>{t('sshIdentity')}</label>
+                    <input type="text" placeholder="~/.ssh/id_ed25519" value={ssh.identity || ''} onChange={(e) => setSsh({ identity: e.target.value || null })} />
+                    <label>{t('sshJumpHost')}</label>
+                    <input type="text" placeholder="bastion.example.com" value={ssh.jump_host || ''} onChange={(e) => setSsh({ jump_host: e.target.value || null })} />
+                  </div>
+                )
+              })()}
+              {formData.type === 'container' && (() => {
+                const container = formData.container || { mode: 'image', name: null, engine: null }
+                const setContainer = (changes) => setFormData({...formData, container: {...container, ...changes}})
+                const options = formData.options || { args: [], timeout_secs: 30, env_vars: null, terminal: false, hold: false, ports: [], volumes: [] }
+                const setOptions = (changes) => setFormData({...formData, options: {...options, ...changes}})
+                const lines = (value) => value.split('\n').map(l => l.trim()).filter(l => l)
+                return (
+                  <div className="form-group">
+                    <label>{t('containerMode')}</label>
+                    <select value={container.mode} onChange={(e) => setContainer({ mode: e.target.value })}>
+                      <option value="image">{t('containerImage')}</option>
+                      <option value="compose">{t('containerCompose')}</option>
+                    </select>
+                    <label>{t('containerName')}</label>
+                    <input type="text" placeholder={container.mode === 'compose' ? 'my-stack' : 'initium-postgres'} value={container.name || ''} onChange={(e) => setContainer({ name: e.target.value || null })} />
+                    <label>{t('containerEngine')}</label>
+                    <select value={container.engine || ''} onChange={(e) => setContainer({ engine: e.target.value || null })}>
+                      <option value="">{t('autoDetect')}</option>
+                      <option value="podman">Podman</option>
+                      <option value="docker">Docker</option>
+                    </select>
+                    {container.mode === 'image' && (<>
+                      <label>{t('containerPorts')}</label>
+                      <textarea rows={2} placeholder="5432:5432" value={(options.ports || []).join('\n')} onChange={(e) => setOptions({ ports: lines(e.target.value) })} />
+                      <label>{t('containerVolumes')}</label>
+                      <textarea rows={2} placeholder="/srv/pg:/var/lib/postgresql/data" value={(options.volumes || []).join('\n')} onChange={(e) => setOptions({ volumes: lines(e.target.value) })} />
+                      <label>{t('containerEnv')}</label>
+                      <textarea rows={2} placeholder="POSTGRES_PASSWORD=secret" value={(options.env_vars || []).map(([k, v]) => k + '=' + v).join('\n')} onChange={(e) => { const vars = lines(e.target.value).filter(l => l.includes('=')).map(l => [l.slice(0, l.indexOf('=')), l.slice(l.indexOf('=') + 1)]); setOptions({ env_vars: vars.length > 0 ? vars : null }) }} />
+                    </>)}
+                  </div>
+                )
+              })()}
+              {(formData.type === 'app' || formData.type === 'ssh') && (() => {
+                const options = formData.options || { args: [], timeout_secs: 30, env_vars: null, terminal: false, hold: false }
+                const setOptions = (changes) => setFormData({...formData, options: {...options, ...changes}})
+                return (
+                  <div className="form-group">
+                    {formData.type === 'app' && (
+                      <label><input type="checkbox" checked={options.terminal} onChange={(e) => setOptions({ terminal: e.target.checked })} /> {t('runInTerminal')}</label>
+                    )}
+                    {(options.terminal || formData.type === 'ssh') && (
+                      <label><input type="checkbox" checked={options.hold} onChange={(e) => setOptions({ hold: e.target.checked })} /> {t('holdTerminal')}</label>
+                    )}
+                    {formData.type === 'app' && (<>
+                      <label>{t('workingDir')}</label>
+                      <input type="text" value={options.working_dir || ''} onChange={(e) => setOptions({ working_dir: e.target.value || null })} />
+                    </>)}
+                  </div>
+                )
+              })()}
+              {formData.type === 'script' && (
+                <div className="form-group">
+                  <label>{t('scriptBody')}</label>
+                  <textarea rows={8} placeholder={'#!/bin/sh\necho "Hello"'} value={formData.script} onChange={(e) => setFormData({...formData, script: e.target.value})} required />
+                </div>
+              )}
+              <div className="form-group">
+                <label>{t('preconditions')}</label>
+                <textarea rows={3} placeholder={'[{"check": {"kind": "reachable", "address": "10.0.0.1:443"}, "message": "VPN not connected"}]'} value={formData.preconditions} onChange={(e) => setFormData({...formData, preconditions: e.target.value})} />
+              </div>
+              <div className="form-group">
+                <label>{t('parameters')}</label>
+                <textarea rows={3} placeholder={'[{"name": "key", "label": "Ticket", "kind": "text"}]'} value={formData.parameters} onChange={(e) => setFormData({...formData, parameters: e.target.value})} />
               </div>
+              {(formData.type === 'app' || formData.type === 'web') && (
+                <div className="form-group">
+                  <label>{t('fallbacks')}</label>
+                  <textarea rows={3} placeholder={'[{"type": "app", "target": "flatpak run com.visualstudio.code"}, {"type": "web", "target": "https://vscode.dev"}]'} value={formData.fallbacks} onChange={(e) => setFormData({...formData, fallbacks: e.target.value})} />
+                </div>
+              )}
               <div className="form-group">
                 <label>{t('icon')}</label>
                 <button type="button" onClick={async () => { const selected = await open({ directory: false, multiple: false, defaultPath: '/home/bernard/.config/initium/icons', filters: [{ name: 'Images', extensions: ['png', 'jpg', 'jpeg', 'svg', 'ico'] }] }); if (selected) { const base64 = await invoke("read_file_as_base64", { path: selected }); setFormData({...formData, icon: base64}); } }}>{t('chooseFile')}</button>
                 {formData.icon && (<div className="icon-preview"><img src={formData.icon} alt="Preview" /></div>)}
               </div>
+              <div className="form-group">
+                <label>{t('browsers')}</label>
+                <ul className="browser-list">
+                  {browsers.map(b => (
+                    <li key={b.id}>
+                      <strong>{b.name}</strong> ({b.source}){b.is_default ? ` — ${t('defaultBrowser')}` : ''}
+                      <div style={{ fontSize: '0.8em', color: '#94a3b8' }}>{[b.capabilities.profiles && t('browserProfile'), b.capabilities.private_mode && t('privateWindow'), b.capabilities.app_mode && t('appMode')].filter(Boolean).join(', ')}</div>
+                    </li>
+                  ))}
+                </ul>
+              </div>
+
+              <div className="form-group">
+                <label>{t('searchEngines')}</label>
+                <button type="button" className="btn-export" onClick={handleExportSearchEngines}>{t('export')}</button>
+                <button type="button" className="btn-import" onClick={handleImportSearchEngines}>{t('import')}</button>
+              </div>
+
               <div style={{ textAlign: 'center', fontSize: '0.75rem', color: '#64748b', marginBottom: '12px' }}>Initium v{settings?.version}</div>
               <div className="modal-actions">
                 <button type="submit" className="btn-submit">{selectedLauncher ? 'Update' : 'Create'} Launcher</button>
@@ -398,6 +898,36 @@ function App() {
                 </div>
               )}
 
+              <div className="form-group">
+                <label>{t('terminal')}</label>
+                <input type="text" placeholder={settings?.detected_terminal || 'xterm'} value={settings?.terminal || ''} onChange={(e) => setSettings({ ...settings, terminal: e.target.value || null })} />
+              </div>
+
+              <div className="form-group">
+                <label>{t('appImageDirs')}</label>
+                <textarea rows="3" placeholder="~/Applications" value={[].concat(settings?.appimage_dirs || []).join('\n')} onChange={(e) => setSettings({ ...settings, appimage_dirs: e.target.value })} />
+              </div>
+
+              <div className="form-group">
+                <label>{t('projectRoots')}</label>
+                <textarea rows="3" placeholder="~/src" value={[].concat(settings?.projects?.roots || []).join('\n')} onChange={(e) => setSettings({ ...settings, projects: { ...settings.projects, roots: e.target.value } })} />
+              </div>
+
+              <div className="form-group">
+                <label>{t('projectIgnore')}</label>
+                <textarea rows="2" value={[].concat(settings?.projects?.ignore || []).join('\n')} onChange={(e) => setSettings({ ...settings, projects: { ...settings.projects, ignore: e.target.value } })} />
+              </div>
+
+              <div className="form-group">
+                <label>{t('projectDepth')}</label>
+                <input type="number" min="1" max="10" value={settings?.projects?.max_depth ?? 3} onChange={(e) => setSettings({ ...settings, projects: { ...settings.projects, max_depth: e.target.value } })} />
+              </div>
+
+              <div className="form-group">
+                <label>{t('editor')}</label>
+                <input type="text" placeholder={settings?.detected_editor || 'code'} value={settings?.projects?.editor || ''} onChange={(e) => setSettings({ ...settings, projects: { ...settings.projects, editor: e.target.value || null } })} />
+              </div>
+
               <hr style={{ margin: '20px 0', borderColor: '#ddd' }} />
 
               <div className="form-group">
@@ -419,8 +949,113 @@ function App() {
           </div>
         </div>
       )}
+
+      {bookmarkImport && (
+        <div className="modal-overlay" onClick={() => setBookmarkImport(null)}>
+          <div className="modal" onClick={(e) => e.stopPropagation()} style={{ maxHeight: '90vh', overflowY: 'auto' }}>
+            <div className="modal-header">
+              <h2>{t('bookmarks')}</h2>
+              <button className="modal-close" onClick={() => setBookmarkImport(null)}>✕</button>
+            </div>
+            <div className="modal-form">
+              <div className="form-group">
+                <label>{t('bookmarkSource')}</label>
+                {bookmarkImport.sources.map(source => (
+                  <label key={source.path}>
+                    <input type="radio" name="bookmark-source" checked={bookmarkImport.path === source.path} onChange={() => chooseBookmarkSource(source.path, source.format)} /> {source.name}
+                  </label>
+                ))}
+                <button type="button" className="btn-import" onClick={chooseBookmarkFile}>{t('bookmarkFile')}</button>
+              </div>
+
+              {bookmarkImport.path && (
+                <div className="form-group">
+                  <label>{t('bookmarkFolders')}</label>
+                  {bookmarkImport.folders.length === 0 && <p>{t('noBookmarks')}</p>}
+                  {bookmarkImport.folders.map(folder => (
+                    <label key={folder.path}>
+                      <input type="checkbox" checked={bookmarkImport.selected.includes(folder.path)} onChange={() => toggleBookmarkFolder(folder.path)} /> {folder.path || t('noFolder')} ({folder.count})
+                    </label>
+                  ))}
+                </div>
+              )}
+
+              <div className="modal-actions">
+                <button type="button" className="btn-submit" disabled={!bookmarkImport.path || bookmarkImport.selected.length === 0} onClick={importSelectedBookmarks}>{t('import')}</button>
+                <button type="button" className="btn-export" onClick={handleExportBookmarks}>{t('exportBookmarks')}</button>
+                <button type="button" className="btn-cancel" onClick={() => setBookmarkImport(null)}>{t('close')}</button>
+              </div>
+            </div>
+          </div>
+        </div>
+      )}
+
+      {configImport && (
+        <div className="modal-overlay" onClick={() => setConfigImport(null)}>
+          <div className="modal" onClick={(e) => e.stopPropagation()} style={{ maxHeight: '90vh', overflowY: 'auto' }}>
+            <div className="modal-header">
+              <h2>{t('import')}</h2>
+              <button className="modal-close" onClick={() => setConfigImport(null)}>✕</button>
+            </div>
+            <div className="modal-form">
+              <div className="form-group">
+                <label>{t('importMode')}</label>
+                <select value={configImport.options.mode} onChange={(e) => previewConfigImport(configImport.json, { mode: e.target.value, conflict: e.target.value === 'add_only' && configImport.options.conflict === 'overwrite' ? 'skip' : configImport.options.conflict })}>
+                  <option value="replace">{t('importReplace')}</option>
+                  <option value="merge">{t('importMerge')}</option>
+                  <option value="add_only">{t('importAddOnly')}</option>
+                </select>
+              </div>
+
+              {configImport.options.mode !== 'replace' && (
+                <div className="form-group">
+                  <label>{t('importConflict')}</label>
+                  <select value={configImport.options.conflict} onChange={(e) => previewConfigImport(configImport.json, { ...configImport.options, conflict: e.target.value })}>
+                    <option value="skip">{t('conflictSkip')}</option>
+                    {configImport.options.mode !== 'add_only' && <option value="overwrite">{t('conflictOverwrite')}</option>}
+                    <option value="rename">{t('conflictRename')}</option>
+                  </select>
+                </div>
+              )}
+
+              {['added', 'changed', 'removed', 'skipped'].map(kind => configImport.diff[kind].length > 0 && (
+                <div className="form-group" key={kind}>
+                  <label>{t('diff_' + kind)} ({configImport.diff[kind].length})</label>
+                  <ul>
+                    {configImport.diff[kind].map(item => (
+                      <li key={item.id}>
+                        {item.name} ({item.renamed_from ? item.renamed_from + ' → ' : ''}{item.id}){item.fields.length > 0 && ': ' + item.fields.join(', ')}
+                      </li>
+                    ))}
+                  </ul>
+                </div>
+              ))}
+
+              {configImport.diff.settings.length > 0 && (
+                <div className="form-group">
+                  <label>{t('diff_settings')} ({configImport.diff.settings.length})</label>
+                  <ul>
+                    {configImport.diff.settings.map(setting => (
+                      <li key={setting.key}>
+                        {setting.key}: {JSON.stringify(setting.current)} → {JSON.stringify(setting.imported)}
+                      </li>
+                    ))}
+                  </ul>
+                </div>
+              )}
+
+              {configImport.diff.added.length + configImport.diff.changed.length + configImport.diff.removed.length + configImport.diff.settings.length === 0 && <p>{t('importNoChange')}</p>}
+
+              <div className="modal-actions">
+                <button type="button" className="btn-submit" onClick={applyConfigImport}>{t('import')}</button>
+                <button type="button" className="btn-cancel" onClick={() => setConfigImport(null)}>{t('cancel')}</button>
+              </div>
+            </div>
+          </div>
+        </div>
+      )}
     </div>
   )
 }
 
-export default App
\ No newline at end of file
+export default App
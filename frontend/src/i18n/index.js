@@ -31,6 +31,91 @@ const resources = {
       chooseFile: 'Choose File',
       application: 'Application',
       website: 'Website',
+      command: 'Command',
+      script: 'Script',
+      scriptBody: 'Script (shebang or interpreter in target)',
+      group: 'Group',
+      groupMembers: 'Members',
+      groupOrdered: 'One after another',
+      groupParallel: 'All at once',
+      groupStopOnFailure: 'Stop on failure',
+      chain: 'Chain',
+      chainSteps: 'Steps (JSON: start, wait, open)',
+      preconditions: 'Preconditions (JSON, optional)',
+      fallbacks: 'Fallback targets (JSON, optional)',
+      parameters: 'Parameters (JSON, optional)',
+      search: 'Web search (%s in URL)',
+      ssh: 'SSH host',
+      sshUser: 'User',
+      sshPort: 'Port',
+      sshIdentity: 'Identity file',
+      sshJumpHost: 'Jump host',
+      container: 'Container',
+      containerMode: 'Runs',
+      containerImage: 'An image (target: image name)',
+      containerCompose: 'A compose project (target: project directory)',
+      containerName: 'Container or project name',
+      containerEngine: 'Engine',
+      autoDetect: 'Auto-detect',
+      containerPorts: 'Ports (one per line)',
+      containerVolumes: 'Volumes (one per line)',
+      containerEnv: 'Environment (KEY=value per line)',
+      containerStop: 'Stop',
+      containerLogs: 'Logs',
+      containerState_running: 'running',
+      containerState_stopped: 'stopped',
+      containerState_absent: 'not created',
+      importSsh: 'SSH hosts',
+      importApps: 'Flatpak/Snap',
+      noNewApps: 'No new Flatpak or Snap application found',
+      confirmAppsImport: 'Add a launcher for these installed applications?',
+      appsImported: '{{count}} launcher(s) added',
+      importAppImages: 'AppImages',
+      noNewAppImages: 'No new or updated AppImage found',
+      confirmAppImagesImport: 'Add a launcher for these AppImages?',
+      appImagesImported: '{{added}} AppImage(s) added, {{updated}} updated',
+      appImageDirs: 'Extra AppImage directories (one per line, ~/Applications and ~/.local/bin are always scanned)',
+      importSteam: 'Steam',
+      noNewSteamGames: 'No new Steam game found',
+      confirmSteamImport: 'Add a launcher for these Steam games?',
+      importProjects: 'Projects',
+      noNewProjects: 'No new or changed project found',
+      confirmProjectsImport: 'Add launchers for these git repositories?',
+      projectsImported: '{{added}} launcher(s) added, {{updated}} updated',
+      projectRoots: 'Project roots scanned for git repositories (one per line)',
+      projectIgnore: 'Ignored directories (one pattern per line)',
+      projectDepth: 'Maximum scan depth',
+      editor: 'Editor (auto-detected when empty)',
+      bookmarks: 'Bookmarks',
+      bookmarkSource: 'Browser profile',
+      bookmarkFile: 'Netscape HTML file…',
+      bookmarkFolders: 'Folders to import',
+      noBookmarks: 'No bookmark found',
+      noFolder: '(no folder)',
+      exportBookmarks: 'Export web launchers',
+      shortcuts: 'Shortcuts',
+      shortcutsImported: '{{added}} shortcut(s) added, {{skipped}} already present, {{failed}} unreadable',
+      workingDir: 'Working directory',
+      sshNoHosts: 'No host found in ~/.ssh/config',
+      confirmSshImport: 'Import these hosts from ~/.ssh/config? Hosts marked ✓ already have a launcher and are skipped.',
+      sshImported: '{{added}} SSH launcher(s) added, {{skipped}} already present',
+      searchEngines: 'Search engines',
+      searchPlaceholder: 'Search (gh rust, w paris...)',
+      extraUrls: 'Additional URLs (one per line)',
+      browser: 'Browser (system default if empty)',
+      browserProfile: 'Browser profile',
+      newWindow: 'New window',
+      privateWindow: 'Private window',
+      browsers: 'Installed browsers',
+      defaultBrowser: 'default',
+      appMode: 'App mode',
+      display: 'Display',
+      displayBrowser: 'In the browser',
+      displayEmbedded: 'In a dedicated Initium window',
+      runInTerminal: 'Run in a terminal',
+      holdTerminal: 'Keep the terminal open after exit',
+      terminal: 'Terminal emulator (auto-detected when empty)',
+      exitCode: 'Exit code',
       create: 'Create',
       update: 'Update',
       cancel: 'Cancel',
@@ -49,6 +134,20 @@ const resources = {
       settingsReset: 'Settings reset to default!',
       exportSuccess: 'Config exported successfully!',
       importSuccess: 'Config imported successfully!',
+      importMode: 'Import mode',
+      importReplace: 'Replace current configuration',
+      importMerge: 'Merge launchers and settings',
+      importAddOnly: 'Only add new launchers',
+      importConflict: 'When a launcher already exists',
+      conflictSkip: 'Keep the current one',
+      conflictOverwrite: 'Overwrite it',
+      conflictRename: 'Add a renamed copy',
+      diff_added: 'Added',
+      diff_changed: 'Changed',
+      diff_removed: 'Removed',
+      diff_skipped: 'Skipped',
+      diff_settings: 'Settings',
+      importNoChange: 'This import changes nothing',
     }
   },
   fr: {
@@ -80,6 +179,91 @@ const resources = {
       chooseFile: 'Choisir un fichier',
       application: 'Application',
       website: 'Site web',
+      command: 'Commande',
+      script: 'Script',
+      scriptBody: 'Script (shebang ou interpréteur dans la cible)',
+      group: 'Groupe',
+      groupMembers: 'Membres',
+      groupOrdered: 'L\'un après l\'autre',
+      groupParallel: 'Tous en même temps',
+      groupStopOnFailure: 'Arrêter en cas d\'échec',
+      chain: 'Enchaînement',
+      chainSteps: 'Étapes (JSON : start, wait, open)',
+      preconditions: 'Préconditions (JSON, facultatif)',
+      fallbacks: 'Cibles de repli (JSON, facultatif)',
+      parameters: 'Paramètres (JSON, facultatif)',
+      search: 'Recherche web (%s dans l\'URL)',
+      ssh: 'Hôte SSH',
+      sshUser: 'Utilisateur',
+      sshPort: 'Port',
+      sshIdentity: 'Fichier d\'identité',
+      sshJumpHost: 'Hôte de rebond',
+      container: 'Conteneur',
+      containerMode: 'Exécute',
+      containerImage: 'Une image (cible : nom de l\'image)',
+      containerCompose: 'Un projet compose (cible : dossier du projet)',
+      containerName: 'Nom du conteneur ou du projet',
+      containerEngine: 'Moteur',
+      autoDetect: 'Détection automatique',
+      containerPorts: 'Ports (un par ligne)',
+      containerVolumes: 'Volumes (un par ligne)',
+      containerEnv: 'Environnement (CLÉ=valeur par ligne)',
+      containerStop: 'Arrêter',
+      containerLogs: 'Journaux',
+      containerState_running: 'en cours',
+      containerState_stopped: 'arrêté',
+      containerState_absent: 'non créé',
+      importSsh: 'Hôtes SSH',
+      importApps: 'Flatpak/Snap',
+      noNewApps: 'Aucune nouvelle application Flatpak ou Snap trouvée',
+      confirmAppsImport: 'Ajouter un lanceur pour ces applications installées ?',
+      appsImported: '{{count}} lanceur(s) ajouté(s)',
+      importAppImages: 'AppImages',
+      noNewAppImages: 'Aucune AppImage nouvelle ou mise à jour trouvée',
+      confirmAppImagesImport: 'Ajouter un lanceur pour ces AppImages ?',
+      appImagesImported: '{{added}} AppImage(s) ajoutée(s), {{updated}} mise(s) à jour',
+      appImageDirs: 'Répertoires AppImage supplémentaires (un par ligne, ~/Applications et ~/.local/bin sont toujours analysés)',
+      importSteam: 'Steam',
+      noNewSteamGames: 'Aucun nouveau jeu Steam trouvé',
+      confirmSteamImport: 'Ajouter un lanceur pour ces jeux Steam ?',
+      importProjects: 'Projets',
+      noNewProjects: 'Aucun projet nouveau ou modifié trouvé',
+      confirmProjectsImport: 'Ajouter des lanceurs pour ces dépôts git ?',
+      projectsImported: '{{added}} lanceur(s) ajouté(s), {{updated}} mis à jour',
+      projectRoots: 'Racines de projets analysées pour les dépôts git (une par ligne)',
+      projectIgnore: 'Répertoires ignorés (un motif par ligne)',
+      projectDepth: 'Profondeur maximale d’analyse',
+      editor: 'Éditeur (détecté automatiquement si vide)',
+      bookmarks: 'Favoris',
+      bookmarkSource: 'Profil de navigateur',
+      bookmarkFile: 'Fichier HTML Netscape…',
+      bookmarkFolders: 'Dossiers à importer',
+      noBookmarks: 'Aucun favori trouvé',
+      noFolder: '(aucun dossier)',
+      exportBookmarks: 'Exporter les lanceurs web',
+      shortcuts: 'Raccourcis',
+      shortcutsImported: '{{added}} raccourci(s) ajouté(s), {{skipped}} déjà présent(s), {{failed}} illisible(s)',
+      workingDir: 'Répertoire de travail',
+      sshNoHosts: 'Aucun hôte trouvé dans ~/.ssh/config',
+      confirmSshImport: 'Importer ces hôtes depuis ~/.ssh/config ? Les hôtes marqués ✓ ont déjà un lanceur et sont ignorés.',
+      sshImported: '{{added}} lanceur(s) SSH ajouté(s), {{skipped}} déjà présent(s)',
+      searchEngines: 'Moteurs de recherche',
+      searchPlaceholder: 'Rechercher (gh rust, w paris...)',
+      extraUrls: 'URL supplémentaires (une par ligne)',
+      browser: 'Navigateur (par défaut du système si vide)',
+      browserProfile: 'Profil du navigateur',
+      newWindow: 'Nouvelle fenêtre',
+      privateWindow: 'Fenêtre privée',
+      browsers: 'Navigateurs installés',
+      defaultBrowser: 'par défaut',
+      appMode: 'Mode application',
+      display: 'Affichage',
+      displayBrowser: 'Dans le navigateur',
+      displayEmbedded: 'Dans une fenêtre Initium dédiée',
+      runInTerminal: 'Exécuter dans un terminal',
+      holdTerminal: 'Garder le terminal ouvert après la sortie',
+      terminal: 'Émulateur de terminal (détecté automatiquement si vide)',
+      exitCode: 'Code de sortie',
       create: 'Créer',
       update: 'Mettre à jour',
       cancel: 'Annuler',
@@ -98,6 +282,20 @@ const resources = {
       settingsReset: 'Paramètres réinitialisés !',
       exportSuccess: 'Configuration exportée avec succès !',
       importSuccess: 'Configuration importée avec succès !',
+      importMode: 'Mode d\'import',
+      importReplace: 'Remplacer la configuration actuelle',
+      importMerge: 'Fusionner lanceurs et paramètres',
+      importAddOnly: 'Ajouter uniquement les nouveaux lanceurs',
+      importConflict: 'Quand un lanceur existe déjà',
+      conflictSkip: 'Garder l\'actuel',
+      conflictOverwrite: 'L\'écraser',
+      conflictRename: 'Ajouter une copie renommée',
+      diff_added: 'Ajoutés',
+      diff_changed: 'Modifiés',
+      diff_removed: 'Supprimés',
+      diff_skipped: 'Ignorés',
+      diff_settings: 'Paramètres',
+      importNoChange: 'Cet import ne change rien',
     }
   },
   es: {
@@ -129,6 +327,91 @@ const resources = {
       chooseFile: 'Elegir archivo',
       application: 'Aplicación',
       website: 'Sitio web',
+      command: 'Comando',
+      script: 'Script',
+      scriptBody: 'Script (shebang o intérprete en el destino)',
+      group: 'Grupo',
+      groupMembers: 'Miembros',
+      groupOrdered: 'Uno tras otro',
+      groupParallel: 'Todos a la vez',
+      groupStopOnFailure: 'Detener en caso de error',
+      chain: 'Cadena',
+      chainSteps: 'Pasos (JSON: start, wait, open)',
+      preconditions: 'Condiciones previas (JSON, opcional)',
+      fallbacks: 'Destinos alternativos (JSON, opcional)',
+      parameters: 'Parámetros (JSON, opcional)',
+      search: 'Búsqueda web (%s en la URL)',
+      ssh: 'Host SSH',
+      sshUser: 'Usuario',
+      sshPort: 'Puerto',
+      sshIdentity: 'Archivo de identidad',
+      sshJumpHost: 'Host de salto',
+      container: 'Contenedor',
+      containerMode: 'Ejecuta',
+      containerImage: 'Una imagen (destino: nombre de la imagen)',
+      containerCompose: 'Un proyecto compose (destino: carpeta del proyecto)',
+      containerName: 'Nombre del contenedor o del proyecto',
+      containerEngine: 'Motor',
+      autoDetect: 'Detección automática',
+      containerPorts: 'Puertos (uno por línea)',
+      containerVolumes: 'Volúmenes (uno por línea)',
+      containerEnv: 'Entorno (CLAVE=valor por línea)',
+      containerStop: 'Detener',
+      containerLogs: 'Registros',
+      containerState_running: 'en ejecución',
+      containerState_stopped: 'detenido',
+      containerState_absent: 'no creado',
+      importSsh: 'Hosts SSH',
+      importApps: 'Flatpak/Snap',
+      noNewApps: 'No se encontró ninguna aplicación Flatpak o Snap nueva',
+      confirmAppsImport: '¿Añadir un lanzador para estas aplicaciones instaladas?',
+      appsImported: '{{count}} lanzador(es) añadido(s)',
+      importAppImages: 'AppImages',
+      noNewAppImages: 'No se encontró ninguna AppImage nueva o actualizada',
+      confirmAppImagesImport: '¿Añadir un lanzador para estas AppImages?',
+      appImagesImported: '{{added}} AppImage(s) añadida(s), {{updated}} actualizada(s)',
+      appImageDirs: 'Directorios AppImage adicionales (uno por línea, ~/Applications y ~/.local/bin siempre se analizan)',
+      importSteam: 'Steam',
+      noNewSteamGames: 'No se encontró ningún juego de Steam nuevo',
+      confirmSteamImport: '¿Añadir un lanzador para estos juegos de Steam?',
+      importProjects: 'Proyectos',
+      noNewProjects: 'No se encontró ningún proyecto nuevo o modificado',
+      confirmProjectsImport: '¿Añadir lanzadores para estos repositorios git?',
+      projectsImported: '{{added}} lanzador(es) añadido(s), {{updated}} actualizado(s)',
+      projectRoots: 'Raíces de proyectos analizadas en busca de repositorios git (una por línea)',
+      projectIgnore: 'Directorios ignorados (un patrón por línea)',
+      projectDepth: 'Profundidad máxima de análisis',
+      editor: 'Editor (detectado automáticamente si está vacío)',
+      bookmarks: 'Marcadores',
+      bookmarkSource: 'Perfil del navegador',
+      bookmarkFile: 'Archivo HTML Netscape…',
+      bookmarkFolders: 'Carpetas a importar',
+      noBookmarks: 'No se encontró ningún marcador',
+      noFolder: '(sin carpeta)',
+      exportBookmarks: 'Exportar los lanzadores web',
+      shortcuts: 'Accesos directos',
+      shortcutsImported: '{{added}} acceso(s) directo(s) añadido(s), {{skipped}} ya presente(s), {{failed}} ilegible(s)',
+      workingDir: 'Directorio de trabajo',
+      sshNoHosts: 'No se encontró ningún host en ~/.ssh/config',
+      confirmSshImport: '¿Importar estos hosts desde ~/.ssh/config? Los hosts marcados con ✓ ya tienen un lanzador y se omiten.',
+      sshImported: '{{added}} lanzador(es) SSH añadido(s), {{skipped}} ya presente(s)',
+      searchEngines: 'Motores de búsqueda',
+      searchPlaceholder: 'Buscar (gh rust, w paris...)',
+      extraUrls: 'URL adicionales (una por línea)',
+      browser: 'Navegador (predeterminado si está vacío)',
+      browserProfile: 'Perfil del navegador',
+      newWindow: 'Nueva ventana',
+      privateWindow: 'Ventana privada',
+      browsers: 'Navegadores instalados',
+      defaultBrowser: 'predeterminado',
+      appMode: 'Modo aplicación',
+      display: 'Visualización',
+      displayBrowser: 'En el navegador',
+      displayEmbedded: 'En una ventana de Initium dedicada',
+      runInTerminal: 'Ejecutar en una terminal',
+      holdTerminal: 'Mantener la terminal abierta al salir',
+      terminal: 'Emulador de terminal (detectado automáticamente si está vacío)',
+      exitCode: 'Código de salida',
       create: 'Crear',
       update: 'Actualizar',
       cancel: 'Cancelar',
@@ -147,6 +430,20 @@ const resources = {
       settingsReset: '¡Ajustes restablecidos!',
       exportSuccess: '¡Configuración exportada con éxito!',
       importSuccess: '¡Configuración importada con éxito!',
+      importMode: 'Modo de importación',
+      importReplace: 'Reemplazar la configuración actual',
+      importMerge: 'Combinar lanzadores y ajustes',
+      importAddOnly: 'Solo añadir lanzadores nuevos',
+      importConflict: 'Cuando un lanzador ya existe',
+      conflictSkip: 'Mantener el actual',
+      conflictOverwrite: 'Sobrescribirlo',
+      conflictRename: 'Añadir una copia renombrada',
+      diff_added: 'Añadidos',
+      diff_changed: 'Modificados',
+      diff_removed: 'Eliminados',
+      diff_skipped: 'Omitidos',
+      diff_settings: 'Ajustes',
+      importNoChange: 'Esta importación no cambia nada',
     }
   }
 }
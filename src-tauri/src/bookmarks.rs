@@ -0,0 +1,612 @@
+//! Bookmarks Module
+//!
+//! Imports browser bookmarks as web launchers and exports the web launchers
+//! as a bookmark file any browser can import.
+//! Features:
+//! - Netscape bookmark HTML, Chromium `Bookmarks` JSON and Firefox `places.sqlite`
+//! - Firefox databases read from a copy, so a running browser's lock does not matter
+//! - Bookmark folders mapped to launcher categories, imported folder by folder
+//! - Web launchers exported to Netscape HTML, one folder per category
+
+use crate::launcher::{generate_unique_id, LaunchType, Launcher};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Separator of nested folder names in a category
+pub const FOLDER_SEPARATOR: &str = " / ";
+
+/// Bookmark file format
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BookmarkFormat {
+    /// Netscape bookmark HTML, exported by every browser
+    Html,
+    /// Chromium-family `Bookmarks` JSON file
+    Chromium,
+    /// Firefox `places.sqlite` database
+    Firefox,
+}
+
+/// Bookmark file of an installed browser profile
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BookmarkSource {
+    /// Browser and profile (`Firefox (default-release)`)
+    pub name: String,
+    pub format: BookmarkFormat,
+    pub path: String,
+}
+
+/// Bookmark read from a browser
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Bookmark {
+    pub title: String,
+    pub url: String,
+    /// Folder path, outermost first
+    pub folder: Vec<String>,
+}
+
+impl Bookmark {
+    /// Category of the launcher of this bookmark, `None` outside of any folder
+    pub fn category(&self) -> Option<String> {
+        (!self.folder.is_empty()).then(|| self.folder.join(FOLDER_SEPARATOR))
+    }
+}
+
+/// Whether a bookmark URL can be opened by a web launcher
+fn is_launchable(url: &str) -> bool {
+    let url = url.trim();
+    !url.is_empty() && !["javascript:", "place:", "data:"].iter().any(|scheme| url.starts_with(scheme))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';').filter(|end| *end <= 10) else {
+            decoded.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let character = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match character {
+            Some(c) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn encode_entities(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Value of an attribute in the inside of a tag (`A HREF="..." ICON="..."`)
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let pattern = format!("{}=\"", name.to_ascii_lowercase());
+    let mut search = 0;
+    while let Some(found) = lower[search..].find(&pattern) {
+        let start = search + found;
+        // Match whole attribute names only (`HREF`, not `SHORTCUTHREF`)
+        if start == 0 || lower.as_bytes()[start - 1].is_ascii_whitespace() {
+            let value_start = start + pattern.len();
+            let value_end = tag[value_start..].find('"')? + value_start;
+            return Some(decode_entities(&tag[value_start..value_end]));
+        }
+        search = start + pattern.len();
+    }
+    None
+}
+
+/// Parse a Netscape bookmark HTML file
+pub fn parse_netscape_html(content: &str) -> Vec<Bookmark> {
+    let mut bookmarks = Vec::new();
+    let mut folders: Vec<String> = Vec::new();
+    // Folder named by the last <H3>, entered by the next <DL>
+    let mut pending: Option<String> = None;
+    // Whether each open <DL> entered a folder
+    let mut lists: Vec<bool> = Vec::new();
+    let mut rest = content;
+    while let Some(open) = rest.find('<') {
+        rest = &rest[open + 1..];
+        let Some(close) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..close];
+        rest = &rest[close + 1..];
+        let name = tag
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        let text_until = |rest: &str, end: &str| -> String {
+            let lower = rest.to_ascii_lowercase();
+            let text = lower.find(end).map(|i| &rest[..i]).unwrap_or(rest);
+            decode_entities(text.trim())
+        };
+        match name.as_str() {
+            "h3" => pending = Some(text_until(rest, "</h3")),
+            "dl" => match pending.take() {
+                Some(folder) => {
+                    folders.push(folder);
+                    lists.push(true);
+                }
+                None => lists.push(false),
+            },
+            "/dl" => {
+                let entered = lists.pop().unwrap_or(false);
+                folders.truncate(folders.len() - usize::from(entered));
+            }
+            "a" => {
+                let Some(url) = attribute(tag, "href").filter(|u| is_launchable(u)) else {
+                    continue;
+                };
+                let title = text_until(rest, "</a");
+                bookmarks.push(Bookmark {
+                    title: if title.is_empty() { url.clone() } else { title },
+                    url,
+                    folder: folders.clone(),
+                });
+            }
+            _ => {}
+        }
+    }
+    bookmarks
+}
+
+fn collect_chromium(node: &serde_json::Value, folder: &mut Vec<String>, bookmarks: &mut Vec<Bookmark>) {
+    let name = node.get("name").and_then(|n| n.as_str()).unwrap_or("").to_string();
+    match node.get("type").and_then(|t| t.as_str()) {
+        Some("url") => {
+            let url = node.get("url").and_then(|u| u.as_str()).unwrap_or("");
+            if is_launchable(url) {
+                bookmarks.push(Bookmark {
+                    title: if name.is_empty() { url.to_string() } else { name },
+                    url: url.to_string(),
+                    folder: folder.clone(),
+                });
+            }
+        }
+        Some("folder") => {
+            folder.push(name);
+            for child in node.get("children").and_then(|c| c.as_array()).into_iter().flatten() {
+                collect_chromium(child, folder, bookmarks);
+            }
+            folder.pop();
+        }
+        _ => {}
+    }
+}
+
+/// Parse a Chromium `Bookmarks` JSON file (Chrome, Chromium, Brave, Edge, Vivaldi)
+pub fn parse_chromium_json(content: &str) -> Result<Vec<Bookmark>, String> {
+    let document: serde_json::Value =
+        serde_json::from_str(content).map_err(|e| format!("Failed to parse bookmarks: {}", e))?;
+    let roots = document
+        .get("roots")
+        .and_then(|r| r.as_object())
+        .ok_or("Failed to parse bookmarks: no roots")?;
+    let mut bookmarks = Vec::new();
+    // Fixed order, the JSON object order is not meaningful
+    for key in ["bookmark_bar", "other", "synced"] {
+        if let Some(root) = roots.get(key) {
+            collect_chromium(root, &mut Vec::new(), &mut bookmarks);
+        }
+    }
+    Ok(bookmarks)
+}
+
+/// Names of the Firefox root folders, by guid
+const FIREFOX_ROOTS: [(&str, &str); 4] = [
+    ("menu________", "Bookmarks Menu"),
+    ("toolbar_____", "Bookmarks Toolbar"),
+    ("unfiled_____", "Other Bookmarks"),
+    ("mobile______", "Mobile Bookmarks"),
+];
+
+/// Read the bookmarks of a Firefox `places.sqlite` database
+///
+/// The database (and its write-ahead log) is copied first: Firefox keeps it
+/// locked while running.
+pub fn read_firefox_places(path: &Path) -> Result<Vec<Bookmark>, String> {
+    let copy_dir = tempfile::tempdir().map_err(|e| format!("Failed to create temporary directory: {}", e))?;
+    let copy = copy_dir.path().join("places.sqlite");
+    std::fs::copy(path, &copy).map_err(|e| format!("Failed to copy {}: {}", path.display(), e))?;
+    let wal = PathBuf::from(format!("{}-wal", path.display()));
+    if wal.is_file() {
+        std::fs::copy(&wal, copy_dir.path().join("places.sqlite-wal"))
+            .map_err(|e| format!("Failed to copy {}: {}", wal.display(), e))?;
+    }
+    query_firefox_places(&copy)
+}
+
+fn query_firefox_places(path: &Path) -> Result<Vec<Bookmark>, String> {
+    let connection =
+        rusqlite::Connection::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let mut statement = connection
+        .prepare(
+            "SELECT b.id, b.type, b.parent, IFNULL(b.title, ''), IFNULL(b.guid, ''), IFNULL(p.url, '')
+             FROM moz_bookmarks b LEFT JOIN moz_places p ON p.id = b.fk
+             ORDER BY b.parent, b.position",
+        )
+        .map_err(|e| format!("Failed to read bookmarks: {}", e))?;
+    struct Row {
+        id: i64,
+        kind: i64,
+        parent: i64,
+        title: String,
+        guid: String,
+        url: String,
+    }
+    let rows: Vec<Row> = statement
+        .query_map([], |row| {
+            Ok(Row {
+                id: row.get(0)?,
+                kind: row.get(1)?,
+                parent: row.get(2)?,
+                title: row.get(3)?,
+                guid: row.get(4)?,
+                url: row.get(5)?,
+            })
+        })
+        .and_then(|rows| rows.collect())
+        .map_err(|e| format!("Failed to read bookmarks: {}", e))?;
+
+    // Folder path of a folder id, `None` outside of the bookmark roots (tags)
+    let folder_path = |mut id: i64| -> Option<Vec<String>> {
+        let mut path = Vec::new();
+        loop {
+            let folder = rows.iter().find(|r| r.id == id)?;
+            if let Some((_, name)) = FIREFOX_ROOTS.iter().find(|(guid, _)| *guid == folder.guid) {
+                path.push(name.to_string());
+                path.reverse();
+                return Some(path);
+            }
+            path.push(folder.title.clone());
+            id = folder.parent;
+        }
+    };
+
+    let mut bookmarks = Vec::new();
+    // Type 1 is a bookmark, 2 a folder, 3 a separator
+    for row in rows.iter().filter(|r| r.kind == 1 && is_launchable(&r.url)) {
+        let Some(folder) = folder_path(row.parent) else {
+            continue;
+        };
+        bookmarks.push(Bookmark {
+            title: if row.title.is_empty() { row.url.clone() } else { row.title.clone() },
+            url: row.url.clone(),
+            folder,
+        });
+    }
+    Ok(bookmarks)
+}
+
+/// Read the bookmarks of a file in the given format
+pub fn read_bookmarks(path: &Path, format: BookmarkFormat) -> Result<Vec<Bookmark>, String> {
+    let read = || std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e));
+    match format {
+        BookmarkFormat::Html => Ok(parse_netscape_html(&read()?)),
+        BookmarkFormat::Chromium => parse_chromium_json(&read()?),
+        BookmarkFormat::Firefox => read_firefox_places(path),
+    }
+}
+
+/// Configuration directories of the Chromium-family browsers, relative to the config directory
+const CHROMIUM_BROWSERS: [(&str, &str); 5] = [
+    ("Google Chrome", "google-chrome"),
+    ("Chromium", "chromium"),
+    ("Brave", "BraveSoftware/Brave-Browser"),
+    ("Microsoft Edge", "microsoft-edge"),
+    ("Vivaldi", "vivaldi"),
+];
+
+/// Bookmark files of the browser profiles found under `config_dir` and `home`
+pub fn find_sources_in(config_dir: &Path, home: &Path) -> Vec<BookmarkSource> {
+    let mut sources = Vec::new();
+    for (browser, dir) in CHROMIUM_BROWSERS {
+        let Ok(read_dir) = std::fs::read_dir(config_dir.join(dir)) else {
+            continue;
+        };
+        let mut profiles: Vec<PathBuf> = read_dir.flatten().map(|e| e.path()).collect();
+        profiles.sort();
+        for profile in profiles {
+            let bookmarks = profile.join("Bookmarks");
+            if bookmarks.is_file() {
+                let profile_name = profile.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                sources.push(BookmarkSource {
+                    name: format!("{} ({})", browser, profile_name),
+                    format: BookmarkFormat::Chromium,
+                    path: bookmarks.to_string_lossy().to_string(),
+                });
+            }
+        }
+    }
+    if let Ok(read_dir) = std::fs::read_dir(home.join(".mozilla/firefox")) {
+        let mut profiles: Vec<PathBuf> = read_dir.flatten().map(|e| e.path()).collect();
+        profiles.sort();
+        for profile in profiles {
+            let places = profile.join("places.sqlite");
+            if places.is_file() {
+                // Profile directories are named `<salt>.<name>`
+                let dir_name = profile.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                let profile_name = dir_name.split_once('.').map(|(_, name)| name.to_string()).unwrap_or(dir_name);
+                sources.push(BookmarkSource {
+                    name: format!("Firefox ({})", profile_name),
+                    format: BookmarkFormat::Firefox,
+                    path: places.to_string_lossy().to_string(),
+                });
+            }
+        }
+    }
+    sources
+}
+
+/// Bookmark files of the installed browser profiles
+pub fn find_sources() -> Vec<BookmarkSource> {
+    let home = dirs::home_dir().unwrap_or_default();
+    find_sources_in(&dirs::config_dir().unwrap_or_else(|| home.join(".config")), &home)
+}
+
+/// Folder of a bookmark list and its number of bookmarks
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BookmarkFolder {
+    /// Folder path joined with [`FOLDER_SEPARATOR`], empty outside of any folder
+    pub path: String,
+    pub count: usize,
+}
+
+/// Folders of a bookmark list, in order of appearance
+pub fn folders(bookmarks: &[Bookmark]) -> Vec<BookmarkFolder> {
+    let mut folders: Vec<BookmarkFolder> = Vec::new();
+    for bookmark in bookmarks {
+        let path = bookmark.category().unwrap_or_default();
+        match folders.iter_mut().find(|f| f.path == path) {
+            Some(folder) => folder.count += 1,
+            None => folders.push(BookmarkFolder { path, count: 1 }),
+        }
+    }
+    folders
+}
+
+/// Add a web launcher per bookmark of the selected folders whose URL has no launcher yet
+///
+/// Returns the number of launchers added.
+pub fn merge_bookmarks(launchers: &mut Vec<Launcher>, bookmarks: &[Bookmark], selected: &[String]) -> usize {
+    let mut added = 0;
+    for bookmark in bookmarks {
+        if !selected.contains(&bookmark.category().unwrap_or_default()) {
+            continue;
+        }
+        if launchers
+            .iter()
+            .any(|l| l.launch_type == LaunchType::Web && l.target.trim() == bookmark.url.trim())
+        {
+            continue;
+        }
+        let existing_ids: Vec<String> = launchers.iter().map(|l| l.id.clone()).collect();
+        let id = generate_unique_id(&bookmark.title, &existing_ids);
+        let mut launcher = Launcher::new(id, bookmark.title.clone(), LaunchType::Web, bookmark.url.clone());
+        launcher.category = bookmark.category();
+        launchers.push(launcher);
+        added += 1;
+    }
+    added
+}
+
+/// Whether a launcher is a single http(s) page, usable as a browser bookmark
+///
+/// Other schemes (`steam://`...), unresolved `{{param}}` targets and launchers
+/// opening several URLs have no bookmark equivalent.
+fn is_exportable(launcher: &Launcher) -> bool {
+    let target = launcher.target.trim();
+    launcher.launch_type == LaunchType::Web
+        && (target.starts_with("http://") || target.starts_with("https://"))
+        && !target.contains("{{")
+        && launcher.web.as_ref().map_or(0, |web| web.urls.len()) == 0
+}
+
+/// Netscape bookmark HTML of the web launchers, one folder per category
+pub fn export_netscape_html(launchers: &[Launcher]) -> String {
+    let web: Vec<&Launcher> = launchers.iter().filter(|l| is_exportable(l)).collect();
+    let mut categories: Vec<Option<&str>> = Vec::new();
+    for launcher in &web {
+        let category = launcher.category.as_deref().filter(|c| !c.trim().is_empty());
+        if !categories.contains(&category) {
+            categories.push(category);
+        }
+    }
+
+    let mut html = String::from(
+        "<!DOCTYPE NETSCAPE-Bookmark-file-1>\n\
+         <META HTTP-EQUIV=\"Content-Type\" CONTENT=\"text/html; charset=UTF-8\">\n\
+         <TITLE>Bookmarks</TITLE>\n\
+         <H1>Bookmarks</H1>\n\
+         <DL><p>\n",
+    );
+    for category in categories {
+        let indent = if category.is_some() { "        " } else { "    " };
+        if let Some(name) = category {
+            html.push_str(&format!("    <DT><H3>{}</H3>\n    <DL><p>\n", encode_entities(name)));
+        }
+        for launcher in web
+            .iter()
+            .filter(|l| l.category.as_deref().filter(|c| !c.trim().is_empty()) == category)
+        {
+            // Only embedded icons are valid outside of Initium
+            let icon = launcher
+                .icon
+                .as_deref()
+                .filter(|i| i.starts_with("data:"))
+                .map(|i| format!(" ICON=\"{}\"", encode_entities(i)))
+                .unwrap_or_default();
+            html.push_str(&format!(
+                "{}<DT><A HREF=\"{}\"{}>{}</A>\n",
+                indent,
+                encode_entities(&launcher.target),
+                icon,
+                encode_entities(&launcher.name)
+            ));
+        }
+        if category.is_some() {
+            html.push_str("    </DL><p>\n");
+        }
+    }
+    html.push_str("</DL><p>\n");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::browser::WebOptions;
+
+    const NETSCAPE_HTML: &str = r#"<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+<DL><p>
+    <DT><H3 ADD_DATE="1" PERSONAL_TOOLBAR_FOLDER="true">Toolbar</H3>
+    <DL><p>
+        <DT><A HREF="https://docs.rs/" ADD_DATE="1">Docs.rs</A>
+        <DT><H3>Dev &amp; Ops</H3>
+        <DL><p>
+            <DT><A HREF="https://grafana.local/?a=1&amp;b=2">Grafana</A>
+            <DT><A HREF="javascript:alert(1)">Bookmarklet</A>
+        </DL><p>
+    </DL><p>
+    <DT><A HREF="https://example.com/"></A>
+</DL><p>
+"#;
+
+    #[test]
+    fn test_parse_netscape_html() {
+        let bookmarks = parse_netscape_html(NETSCAPE_HTML);
+        assert_eq!(bookmarks.len(), 3);
+        assert_eq!(bookmarks[0].title, "Docs.rs");
+        assert_eq!(bookmarks[0].category().as_deref(), Some("Toolbar"));
+        assert_eq!(bookmarks[1].url, "https://grafana.local/?a=1&b=2");
+        assert_eq!(bookmarks[1].category().as_deref(), Some("Toolbar / Dev & Ops"));
+        assert_eq!(bookmarks[2].title, "https://example.com/");
+        assert_eq!(bookmarks[2].category(), None);
+    }
+
+    #[test]
+    fn test_parse_chromium_json() {
+        let json = r#"{"roots": {
+            "bookmark_bar": {"type": "folder", "name": "Bookmarks bar", "children": [
+                {"type": "url", "name": "Rust", "url": "https://www.rust-lang.org/"},
+                {"type": "folder", "name": "Work", "children": [
+                    {"type": "url", "name": "CI", "url": "https://ci.local/"}
+                ]}
+            ]},
+            "other": {"type": "folder", "name": "Other bookmarks", "children": []}
+        }, "version": 1}"#;
+        let bookmarks = parse_chromium_json(json).unwrap();
+        assert_eq!(bookmarks.len(), 2);
+        assert_eq!(bookmarks[0].category().as_deref(), Some("Bookmarks bar"));
+        assert_eq!(bookmarks[1].folder, vec!["Bookmarks bar", "Work"]);
+        assert!(parse_chromium_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_read_firefox_places() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("places.sqlite");
+        let connection = rusqlite::Connection::open(&path).unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE moz_places (id INTEGER PRIMARY KEY, url TEXT, title TEXT);
+                 CREATE TABLE moz_bookmarks (id INTEGER PRIMARY KEY, type INTEGER, fk INTEGER,
+                     parent INTEGER, position INTEGER, title TEXT, guid TEXT);
+                 INSERT INTO moz_places VALUES (1, 'https://mozilla.org/', 'Mozilla'),
+                     (2, 'place:sort=8', NULL), (3, 'https://tagged.example/', NULL);
+                 INSERT INTO moz_bookmarks VALUES
+                     (1, 2, NULL, 0, 0, '', 'root________'),
+                     (2, 2, NULL, 1, 0, 'menu', 'menu________'),
+                     (3, 2, NULL, 1, 1, 'toolbar', 'toolbar_____'),
+                     (4, 2, NULL, 1, 2, 'tags', 'tagsfolder__'),
+                     (10, 2, NULL, 3, 0, 'News', 'folder000001'),
+                     (11, 1, 1, 10, 0, 'Mozilla home', 'bookmark0001'),
+                     (12, 1, 2, 2, 0, 'Recent', 'bookmark0002'),
+                     (13, 2, NULL, 4, 0, 'sometag', 'tag000000001'),
+                     (14, 1, 3, 13, 0, NULL, 'bookmark0003');",
+            )
+            .unwrap();
+        drop(connection);
+
+        let bookmarks = read_firefox_places(&path).unwrap();
+        assert_eq!(bookmarks.len(), 1);
+        assert_eq!(bookmarks[0].title, "Mozilla home");
+        assert_eq!(bookmarks[0].folder, vec!["Bookmarks Toolbar", "News"]);
+    }
+
+    #[test]
+    fn test_merge_selected_folders() {
+        let bookmarks = parse_netscape_html(NETSCAPE_HTML);
+        let folders = folders(&bookmarks);
+        assert_eq!(folders.len(), 3);
+        assert_eq!(folders[0], BookmarkFolder { path: "Toolbar".to_string(), count: 1 });
+
+        let mut launchers = Vec::new();
+        let selected = vec!["Toolbar / Dev & Ops".to_string(), String::new()];
+        assert_eq!(merge_bookmarks(&mut launchers, &bookmarks, &selected), 2);
+        assert_eq!(launchers[0].name, "Grafana");
+        assert_eq!(launchers[0].category.as_deref(), Some("Toolbar / Dev & Ops"));
+        assert_eq!(merge_bookmarks(&mut launchers, &bookmarks, &selected), 0);
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let mut docs = Launcher::new(
+            "docs".to_string(),
+            "Docs <Rust>".to_string(),
+            LaunchType::Web,
+            "https://docs.rs/?q=a&b".to_string(),
+        );
+        docs.category = Some("Dev".to_string());
+        docs.icon = Some("data:image/png;base64,AAAA".to_string());
+        let mail = Launcher::new("mail".to_string(), "Mail".to_string(), LaunchType::Web, "https://mail.local/".to_string());
+        let app = Launcher::new("gimp".to_string(), "GIMP".to_string(), LaunchType::App, "gimp".to_string());
+        let steam = Launcher::new("game".to_string(), "Game".to_string(), LaunchType::Web, "steam://rungameid/1".to_string());
+        let search = Launcher::new("search".to_string(), "Search".to_string(), LaunchType::Web, "https://s.local/?q={{q}}".to_string());
+        let mut tabs = Launcher::new("tabs".to_string(), "Tabs".to_string(), LaunchType::Web, "https://a.local".to_string());
+        tabs.web = Some(WebOptions {
+            urls: vec!["https://b.local".to_string()],
+            ..WebOptions::default()
+        });
+
+        let html = export_netscape_html(&[docs, mail, app, steam, search, tabs]);
+        assert!(html.starts_with("<!DOCTYPE NETSCAPE-Bookmark-file-1>"));
+        assert!(html.contains("ICON=\"data:image/png;base64,AAAA\""));
+        let bookmarks = parse_netscape_html(&html);
+        assert_eq!(bookmarks.len(), 2);
+        assert_eq!(bookmarks[0].title, "Docs <Rust>");
+        assert_eq!(bookmarks[0].url, "https://docs.rs/?q=a&b");
+        assert_eq!(bookmarks[0].category().as_deref(), Some("Dev"));
+        assert_eq!(bookmarks[1].category(), None);
+    }
+}
@@ -0,0 +1,314 @@
+//! Browser Module
+//!
+//! Opens web launchers in a chosen browser instead of the system default.
+//! Features:
+//! - Several URLs per launcher
+//! - Browser profile (Firefox `-P`, Chromium `--profile-directory`)
+//! - New window and private window flags
+//! - Embedded display in a dedicated Initium window
+
+use crate::fallback::{spawn_checked, split_command_line};
+use crate::launcher::{execute_url, LaunchOptions, Launcher};
+use serde::{Deserialize, Serialize};
+
+/// Command-line conventions of a browser
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BrowserFamily {
+    /// Firefox and its forks
+    Firefox,
+    /// Chromium-based browsers
+    Chromium,
+    /// Unknown browser, only URLs are passed
+    Other,
+}
+
+impl BrowserFamily {
+    /// Guess the family from a browser command or desktop id
+    ///
+    /// Only the executable name (or the app id of `flatpak run`) is looked at,
+    /// split into words on `.`, `-` and `_`.
+    pub fn detect(command: &str) -> Self {
+        const FIREFOX: [&str; 5] = ["firefox", "librewolf", "waterfox", "floorp", "zen"];
+        const CHROMIUM: [&str; 9] = ["chromium", "chrome", "brave", "vivaldi", "edge", "msedge", "opera", "thorium", "yandex"];
+        let name = executable_name(command).to_lowercase();
+        let words: Vec<&str> = name.split(['.', '-', '_']).collect();
+        if words.iter().any(|word| FIREFOX.contains(word)) {
+            BrowserFamily::Firefox
+        } else if words.iter().any(|word| CHROMIUM.contains(word)) {
+            BrowserFamily::Chromium
+        } else {
+            BrowserFamily::Other
+        }
+    }
+
+    /// Argument lists opening `urls`, one per browser process
+    ///
+    /// Firefox opens the URLs following `--private-window` in a normal window,
+    /// so each URL of a private Firefox launcher gets its own process.
+    pub fn invocations(&self, options: &WebOptions, urls: &[String]) -> Vec<Vec<String>> {
+        if *self == BrowserFamily::Firefox && options.private_window && urls.len() > 1 {
+            return urls
+                .iter()
+                .map(|url| self.arguments(options, std::slice::from_ref(url)))
+                .collect();
+        }
+        vec![self.arguments(options, urls)]
+    }
+
+    /// Arguments opening `urls` with the given options
+    pub fn arguments(&self, options: &WebOptions, urls: &[String]) -> Vec<String> {
+        let mut args = Vec::new();
+        match self {
+            BrowserFamily::Firefox => {
+                if let Some(profile) = &options.profile {
+                    args.push("-P".to_string());
+                    args.push(profile.clone());
+                }
+                if options.private_window {
+                    // --private-window takes a single URL, see `invocations`
+                    args.push("--private-window".to_string());
+                    args.extend(urls.first().cloned());
+                    return args;
+                }
+                if options.new_window {
+                    args.push("--new-window".to_string());
+                }
+            }
+            BrowserFamily::Chromium => {
+                if let Some(profile) = &options.profile {
+                    args.push(format!("--profile-directory={}", profile));
+                }
+                if options.new_window {
+                    args.push("--new-window".to_string());
+                }
+                if options.private_window {
+                    args.push("--incognito".to_string());
+                }
+            }
+            BrowserFamily::Other => {}
+        }
+        args.extend(urls.iter().cloned());
+        args
+    }
+}
+
+/// Where a web launcher is displayed
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum WebDisplay {
+    /// External browser
+    #[default]
+    Browser,
+    /// Dedicated Initium webview window
+    Embedded,
+}
+
+/// Executable file name of a command, or the app id of a `flatpak run` command
+///
+/// A leading `env` and its `VAR=value` words, as in snap desktop entries, are skipped.
+fn executable_name(command: &str) -> String {
+    let mut words = split_command_line(command);
+    if words.first().is_some_and(|w| w == "env" || w.ends_with("/env")) {
+        let program = words
+            .iter()
+            .skip(1)
+            .position(|w| !w.contains('='))
+            .map_or(words.len(), |i| i + 1);
+        words.drain(..program);
+    }
+    let Some(program) = words.first() else {
+        return String::new();
+    };
+    let name = std::path::Path::new(program)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = name
+        .strip_suffix(".desktop")
+        .or_else(|| name.strip_suffix(".exe"))
+        .unwrap_or(&name)
+        .to_string();
+    if name == "flatpak" {
+        if let Some(app_id) = words
+            .iter()
+            .skip_while(|w| *w != "run")
+            .skip(1)
+            .find(|w| !w.starts_with('-'))
+        {
+            return app_id.clone();
+        }
+    }
+    name
+}
+
+/// Browser options of a web launcher
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct WebOptions {
+    /// URLs opened after the launcher target
+    #[serde(default)]
+    pub urls: Vec<String>,
+    /// Browser command (`firefox`, `flatpak run org.chromium.Chromium`), system default if unset
+    #[serde(default)]
+    pub browser: Option<String>,
+    /// Browser profile name (Firefox) or directory (Chromium)
+    #[serde(default)]
+    pub profile: Option<String>,
+    /// Open in a new window
+    #[serde(default)]
+    pub new_window: bool,
+    /// Open in a private window
+    #[serde(default)]
+    pub private_window: bool,
+    /// External browser or embedded window
+    #[serde(default)]
+    pub display: WebDisplay,
+}
+
+impl WebOptions {
+    /// Program and argument lists opening `urls` in the selected browser, one per process
+    pub fn browser_commands(&self, urls: &[String]) -> Result<Vec<(String, Vec<String>)>, String> {
+        let browser = self
+            .browser
+            .as_deref()
+            .filter(|b| !b.trim().is_empty())
+            .ok_or("No browser selected")?;
+        let mut words = split_command_line(browser).into_iter();
+        let program = words.next().ok_or("No browser selected")?;
+        let prefix: Vec<String> = words.collect();
+        Ok(BrowserFamily::detect(browser)
+            .invocations(self, urls)
+            .into_iter()
+            .map(|args| {
+                let mut full = prefix.clone();
+                full.extend(args);
+                (program.clone(), full)
+            })
+            .collect())
+    }
+}
+
+/// Open every URL of a web launcher, in its browser when one is selected
+pub async fn open_web_launcher(launcher: &Launcher, options: &LaunchOptions) -> Result<(), String> {
+    let web = launcher.web.clone().unwrap_or_default();
+    if web.display == WebDisplay::Embedded {
+        // Embedded windows need the Tauri application, e.g. from dmenu or the TUI
+        log::info!("Launcher '{}' opened in the browser outside the Initium window", launcher.name);
+    }
+    let urls: Vec<String> = std::iter::once(launcher.target.clone())
+        .chain(web.urls.iter().cloned())
+        .filter(|url| !url.is_empty())
+        .collect();
+
+    if web.browser.as_deref().is_some_and(|b| !b.trim().is_empty()) {
+        for (program, args) in web.browser_commands(&urls)? {
+            log::info!("Opening {} with {}", args.join(" "), program);
+            spawn_checked(&program, &args, options).await?;
+        }
+        return Ok(());
+    }
+
+    if web.profile.is_some() || web.private_window || web.new_window {
+        log::warn!(
+            "Launcher '{}': window and profile options need a selected browser",
+            launcher.name
+        );
+    }
+    for url in &urls {
+        execute_url(url, options).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls() -> Vec<String> {
+        vec!["https://a.example".to_string(), "https://b.example".to_string()]
+    }
+
+    #[test]
+    fn test_detect_family() {
+        assert_eq!(BrowserFamily::detect("firefox"), BrowserFamily::Firefox);
+        assert_eq!(BrowserFamily::detect("flatpak run org.mozilla.firefox"), BrowserFamily::Firefox);
+        assert_eq!(BrowserFamily::detect("/usr/bin/google-chrome-stable"), BrowserFamily::Chromium);
+        assert_eq!(BrowserFamily::detect("brave-browser"), BrowserFamily::Chromium);
+        assert_eq!(BrowserFamily::detect("epiphany"), BrowserFamily::Other);
+        assert_eq!(
+            BrowserFamily::detect("/usr/bin/flatpak run --branch=stable --command=zen io.github.zen_browser.zen"),
+            BrowserFamily::Firefox
+        );
+        assert_eq!(BrowserFamily::detect("microsoft-edge-stable"), BrowserFamily::Chromium);
+        assert_eq!(BrowserFamily::detect("org.chromium.Chromium.desktop"), BrowserFamily::Chromium);
+        assert_eq!(BrowserFamily::detect("zenity --info"), BrowserFamily::Other);
+        assert_eq!(
+            BrowserFamily::detect(
+                "env BAMF_DESKTOP_FILE_HINT=/var/lib/snapd/desktop/applications/firefox_firefox.desktop /snap/bin/firefox %u"
+            ),
+            BrowserFamily::Firefox
+        );
+        assert_eq!(BrowserFamily::detect("/usr/bin/env GDK_BACKEND=x11 chromium"), BrowserFamily::Chromium);
+        assert_eq!(BrowserFamily::detect("knowledge-base /opt/chromedriver"), BrowserFamily::Other);
+        assert_eq!(BrowserFamily::detect("/opt/chromebook-tools/sync firefox"), BrowserFamily::Other);
+    }
+
+    #[test]
+    fn test_firefox_arguments() {
+        let options = WebOptions {
+            browser: Some("firefox".to_string()),
+            profile: Some("work".to_string()),
+            new_window: true,
+            ..WebOptions::default()
+        };
+        let commands = options.browser_commands(&urls()).unwrap();
+        assert_eq!(commands.len(), 1);
+        let (program, args) = &commands[0];
+        assert_eq!(program, "firefox");
+        assert_eq!(args, &vec!["-P", "work", "--new-window", "https://a.example", "https://b.example"]);
+
+        let private = WebOptions {
+            private_window: true,
+            ..options
+        };
+        let commands = private.browser_commands(&urls()).unwrap();
+        let args: Vec<&Vec<String>> = commands.iter().map(|(_, args)| args).collect();
+        assert_eq!(
+            args,
+            vec![
+                &vec!["-P", "work", "--private-window", "https://a.example"],
+                &vec!["-P", "work", "--private-window", "https://b.example"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chromium_arguments() {
+        let options = WebOptions {
+            browser: Some("flatpak run org.chromium.Chromium".to_string()),
+            profile: Some("Profile 1".to_string()),
+            private_window: true,
+            ..WebOptions::default()
+        };
+        let commands = options.browser_commands(&urls()).unwrap();
+        assert_eq!(commands.len(), 1);
+        let (program, args) = &commands[0];
+        assert_eq!(program, "flatpak");
+        assert_eq!(
+            args,
+            &vec![
+                "run",
+                "org.chromium.Chromium",
+                "--profile-directory=Profile 1",
+                "--incognito",
+                "https://a.example",
+                "https://b.example"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_browser_required() {
+        assert!(WebOptions::default().browser_commands(&urls()).is_err());
+    }
+}
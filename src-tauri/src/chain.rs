@@ -0,0 +1,203 @@
+//! Launch Chain Module
+//!
+//! Chain launchers run steps one after another, e.g. start a dev server,
+//! wait for its port, then open its URL.
+//! Features:
+//! - Start (`execute_app`) and open (`execute_url`) steps
+//! - Wait steps gated by readiness probes, each with its own timeout
+//! - Stops at the first failed step, later steps are reported as skipped
+
+use crate::launcher::{execute_app, execute_url, LaunchOptions, LaunchReport, Launcher};
+use crate::readiness::{wait_until_ready, WaitFor};
+use serde::{Deserialize, Serialize};
+
+/// Step of a chain launcher
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "step", rename_all = "snake_case")]
+pub enum ChainStep {
+    /// Start an application or service
+    Start {
+        target: String,
+        #[serde(default)]
+        args: Vec<String>,
+    },
+    /// Open a URL in the default browser
+    Open { url: String },
+    /// Wait until a readiness probe succeeds
+    Wait(WaitFor),
+}
+
+impl ChainStep {
+    /// Short description used in launch reports
+    pub fn describe(&self) -> String {
+        match self {
+            ChainStep::Start { target, .. } => format!("Start {}", target),
+            ChainStep::Open { url } => format!("Open {}", url),
+            ChainStep::Wait(wait) => format!("Wait for {}", wait.probe),
+        }
+    }
+
+    /// Run the step with the options of the chain launcher
+    pub async fn run(&self, options: &LaunchOptions) -> Result<(), String> {
+        match self {
+            ChainStep::Start { target, args } => {
+                let mut options = options.clone();
+                if !args.is_empty() {
+                    options.args = args.clone();
+                }
+                execute_app(target, &options).await
+            }
+            ChainStep::Open { url } => execute_url(url, options).await,
+            ChainStep::Wait(wait) => wait_until_ready(wait).await,
+        }
+    }
+}
+
+fn step_report(launcher: &Launcher, index: usize, step: &ChainStep, error: Option<String>) -> LaunchReport {
+    LaunchReport {
+        id: format!("{}#{}", launcher.id, index + 1),
+        name: step.describe(),
+        success: error.is_none(),
+        error,
+        output: None,
+        members: Vec::new(),
+    }
+}
+
+/// Execute the steps of a chain launcher in order
+pub(crate) async fn execute_chain(launcher: &Launcher) -> LaunchReport {
+    let Some(steps) = launcher.chain.as_deref().filter(|s| !s.is_empty()) else {
+        return LaunchReport::failure(launcher, format!("Chain '{}' has no steps", launcher.name));
+    };
+    let options = launcher.options.clone().unwrap_or_default();
+
+    log::info!("Executing chain '{}' ({} steps)", launcher.name, steps.len());
+
+    let mut members = Vec::new();
+    let mut failure = None;
+    for (index, step) in steps.iter().enumerate() {
+        if failure.is_some() {
+            members.push(step_report(
+                launcher,
+                index,
+                step,
+                Some("Skipped after previous failure".to_string()),
+            ));
+            continue;
+        }
+        let result = step.run(&options).await;
+        if let Err(e) = &result {
+            log::error!("Chain '{}' step {} failed: {}", launcher.name, index + 1, e);
+            failure = Some(format!("Step {} ({}) failed: {}", index + 1, step.describe(), e));
+        }
+        members.push(step_report(launcher, index, step, result.err()));
+    }
+
+    LaunchReport {
+        id: launcher.id.clone(),
+        name: launcher.name.clone(),
+        success: failure.is_none(),
+        error: failure,
+        output: None,
+        members,
+    }
+}
+
+/// Check that the steps of every chain launcher can run
+pub fn validate_chains(launchers: &[Launcher]) -> Result<(), String> {
+    for launcher in launchers {
+        for (index, step) in launcher.chain.iter().flatten().enumerate() {
+            if let ChainStep::Wait(wait) = step {
+                wait.probe
+                    .validate()
+                    .map_err(|e| format!("Chain '{}' step {}: {}", launcher.name, index + 1, e))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launcher::LaunchType;
+    use crate::readiness::ReadinessProbe;
+
+    fn chain(steps: Vec<ChainStep>) -> Launcher {
+        let mut launcher = Launcher::new("dev".to_string(), "Dev".to_string(), LaunchType::Chain, String::new());
+        launcher.chain = Some(steps);
+        launcher
+    }
+
+    fn wait_tcp(address: String) -> ChainStep {
+        ChainStep::Wait(WaitFor {
+            probe: ReadinessProbe::Tcp { address },
+            timeout_secs: 1,
+            interval_ms: 20,
+        })
+    }
+
+    #[test]
+    fn test_chain_step_deserialize() {
+        let json = r#"[
+            {"step": "start", "target": "npm", "args": ["run", "dev"]},
+            {"step": "wait", "probe": {"kind": "http", "url": "http://localhost:5173"}, "timeout_secs": 60},
+            {"step": "open", "url": "http://localhost:5173"}
+        ]"#;
+        let steps: Vec<ChainStep> = serde_json::from_str(json).unwrap();
+        assert_eq!(steps.len(), 3);
+        match &steps[1] {
+            ChainStep::Wait(wait) => assert_eq!(wait.timeout_secs, 60),
+            other => panic!("unexpected step: {:?}", other),
+        }
+        assert_eq!(steps[2].describe(), "Open http://localhost:5173");
+    }
+
+    #[test]
+    fn test_validate_chains() {
+        let mut launchers = vec![chain(vec![ChainStep::Wait(WaitFor::new(ReadinessProbe::Http {
+            url: "http://localhost:5173".to_string(),
+        }))])];
+        assert!(validate_chains(&launchers).is_ok());
+        launchers[0].chain = Some(vec![ChainStep::Wait(WaitFor::new(ReadinessProbe::Http {
+            url: "https://localhost:5173".to_string(),
+        }))]);
+        assert!(validate_chains(&launchers).unwrap_err().contains("step 1"));
+    }
+
+    #[tokio::test]
+    async fn test_chain_without_steps() {
+        let report = execute_chain(&chain(Vec::new())).await;
+        assert!(report.error.unwrap().contains("no steps"));
+    }
+
+    #[tokio::test]
+    async fn test_chain_waits_for_listener() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let report = execute_chain(&chain(vec![wait_tcp(address)])).await;
+        assert!(report.success, "{:?}", report);
+        assert_eq!(report.members[0].id, "dev#1");
+    }
+
+    #[tokio::test]
+    async fn test_chain_stops_at_failed_wait() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        drop(listener);
+
+        let launcher = chain(vec![
+            wait_tcp(address),
+            ChainStep::Open {
+                url: "http://localhost".to_string(),
+            },
+        ]);
+        let report = execute_chain(&launcher).await;
+        assert!(!report.success);
+        assert!(report.error.unwrap().starts_with("Step 1 (Wait for TCP"));
+        assert_eq!(
+            report.members[1].error.as_deref(),
+            Some("Skipped after previous failure")
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Command History Module
+//!
+//! Keeps the latest results of command launchers, per launcher
+//! Stored in the settings directory, separately from config.json
+
+use crate::config_manager::ConfigManager;
+use crate::launcher::LaunchReport;
+use crate::system::command_runner::CommandOutput;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Number of results kept for each launcher
+pub const MAX_ENTRIES_PER_LAUNCHER: usize = 20;
+
+/// One command launcher execution
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommandRecord {
+    /// Execution start, in seconds since the Unix epoch
+    pub timestamp: u64,
+    /// Captured command output
+    pub output: CommandOutput,
+}
+
+/// Result history of command launchers, keyed by launcher id
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommandHistory {
+    #[serde(default)]
+    launchers: HashMap<String, Vec<CommandRecord>>,
+}
+
+impl CommandHistory {
+    /// Get history file path
+    pub fn get_history_path() -> PathBuf {
+        ConfigManager::get_settings_dir_path().join("command_history.json")
+    }
+
+    /// Load history from a file, empty if the file does not exist
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(CommandHistory::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read command history: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse command history: {}", e))
+    }
+
+    /// Save history to a file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self)
+            .map_err(|e| format!("Failed to serialize command history: {}", e))?;
+        std::fs::write(path, json).map_err(|e| format!("Failed to save command history: {}", e))
+    }
+
+    /// Record a command output, dropping the oldest results over the limit
+    pub fn record(&mut self, launcher_id: &str, timestamp: u64, output: CommandOutput) {
+        let entries = self.launchers.entry(launcher_id.to_string()).or_default();
+        entries.push(CommandRecord { timestamp, output });
+        if entries.len() > MAX_ENTRIES_PER_LAUNCHER {
+            let excess = entries.len() - MAX_ENTRIES_PER_LAUNCHER;
+            entries.drain(..excess);
+        }
+    }
+
+    /// Record every captured output of a launch report, including group members
+    pub fn record_report(&mut self, report: &LaunchReport, timestamp: u64) {
+        if let Some(output) = &report.output {
+            self.record(&report.id, timestamp, output.clone());
+        }
+        for member in &report.members {
+            self.record_report(member, timestamp);
+        }
+    }
+
+    /// Results of a launcher, oldest first
+    pub fn entries(&self, launcher_id: &str) -> &[CommandRecord] {
+        self.launchers
+            .get(launcher_id)
+            .map(|e| e.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Forget the results of a launcher
+    pub fn remove_launcher(&mut self, launcher_id: &str) {
+        self.launchers.remove(launcher_id);
+    }
+}
+
+/// Current time in seconds since the Unix epoch
+pub fn unix_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output(status_code: i32) -> CommandOutput {
+        CommandOutput {
+            status_code,
+            stdout: "out".to_string(),
+            stderr: String::new(),
+            success: status_code == 0,
+            duration_ms: 5,
+        }
+    }
+
+    #[test]
+    fn test_record_and_entries() {
+        let mut history = CommandHistory::default();
+        history.record("backup", 1, output(0));
+        history.record("backup", 2, output(1));
+        assert_eq!(history.entries("backup").len(), 2);
+        assert_eq!(history.entries("backup")[1].output.status_code, 1);
+        assert!(history.entries("other").is_empty());
+    }
+
+    #[test]
+    fn test_record_limit() {
+        let mut history = CommandHistory::default();
+        for i in 0..(MAX_ENTRIES_PER_LAUNCHER as u64 + 5) {
+            history.record("backup", i, output(0));
+        }
+        let entries = history.entries("backup");
+        assert_eq!(entries.len(), MAX_ENTRIES_PER_LAUNCHER);
+        assert_eq!(entries[0].timestamp, 5);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let path = dir.path().join("command_history.json");
+        assert!(CommandHistory::load(&path).unwrap().entries("backup").is_empty());
+
+        let mut history = CommandHistory::default();
+        history.record("backup", 42, output(0));
+        history.save(&path).expect("Failed to save");
+
+        let loaded = CommandHistory::load(&path).expect("Failed to load");
+        assert_eq!(loaded.entries("backup"), history.entries("backup"));
+    }
+
+    #[test]
+    fn test_record_report_members() {
+        let report = LaunchReport {
+            id: "morning".to_string(),
+            name: "Morning".to_string(),
+            success: true,
+            error: None,
+            output: None,
+            members: vec![LaunchReport {
+                id: "backup".to_string(),
+                name: "Backup".to_string(),
+                success: true,
+                error: None,
+                output: Some(output(0)),
+                members: Vec::new(),
+            }],
+        };
+        let mut history = CommandHistory::default();
+        history.record_report(&report, 7);
+        assert!(history.entries("morning").is_empty());
+        assert_eq!(history.entries("backup")[0].timestamp, 7);
+    }
+
+    #[test]
+    fn test_record_failed_command() {
+        let launcher = crate::launcher::Launcher::new(
+            "backup".to_string(),
+            "Backup".to_string(),
+            crate::launcher::LaunchType::Command,
+            "rsync -a src dst".to_string(),
+        );
+        let report = LaunchReport::from_result(&launcher, Err("Command timed out after 30s".to_string()));
+        assert!(!report.success);
+        let mut history = CommandHistory::default();
+        history.record_report(&report, 3);
+        let entry = &history.entries("backup")[0];
+        assert!(!entry.output.success);
+        assert_eq!(entry.output.stderr, "Command timed out after 30s");
+    }
+
+    #[test]
+    fn test_remove_launcher() {
+        let mut history = CommandHistory::default();
+        history.record("backup", 1, output(0));
+        history.remove_launcher("backup");
+        assert!(history.entries("backup").is_empty());
+    }
+}
@@ -1,6 +1,7 @@
 //! Module de gestion de configuration
 //! Charge et sauvegarde la configuration depuis config.json
 use crate::launcher::Launcher;
+use crate::project::ProjectSettings;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
@@ -14,6 +15,32 @@ pub struct Config {
     pub background: Option<String>,
     #[serde(default)]
     pub language: String,
+    /// Terminal emulator for terminal launchers, auto-detected when unset
+    #[serde(default)]
+    pub terminal: Option<String>,
+    /// Directories scanned for AppImages, besides ~/Applications and ~/.local/bin
+    #[serde(default)]
+    pub appimage_dirs: Vec<String>,
+    /// Roots, limits and editor of git project discovery
+    #[serde(default)]
+    pub projects: ProjectSettings,
+}
+
+impl Default for Config {
+    /// Empty configuration with the default settings
+    fn default() -> Self {
+        Config {
+            version: "0.1.0".to_string(),
+            theme: "light".to_string(),
+            autostart: false,
+            launchers: Vec::new(),
+            background: None,
+            language: "en".to_string(),
+            terminal: None,
+            appimage_dirs: Vec::new(),
+            projects: ProjectSettings::default(),
+        }
+    }
 }
 
 impl Config {
@@ -36,6 +63,10 @@ impl Config {
     pub fn remove_launcher(&mut self, id: &str) {
         self.launchers.retain(|l| l.id != id);
     }
+
+    pub fn find_launcher(&self, id: &str) -> Option<&Launcher> {
+        self.launchers.iter().find(|l| l.id == id)
+    }
 }
 
 #[cfg(test)]
@@ -45,34 +76,16 @@ mod tests {
 
     #[test]
     fn test_config_creation() {
-        let config = Config {
-            version: "0.1.0".to_string(),
-            theme: "light".to_string(),
-            autostart: false,
-            launchers: vec![],
-            background: None,
-            language: "en".to_string(),
-        };
+        let config = Config::default();
         assert_eq!(config.launchers.len(), 0);
     }
 
     #[test]
     fn test_add_launcher() {
-        let mut config = Config {
-            version: "0.1.0".to_string(),
-            theme: "light".to_string(),
-            autostart: false,
-            launchers: vec![],
-            background: None,
-            language: "en".to_string(),
-        };
+        let mut config = Config::default();
         let launcher = Launcher {
-            id: "test".to_string(),
-            name: "Test".to_string(),
-            launch_type: LaunchType::Web,
-            target: "https://example.com".to_string(),
             icon: Some("icon.png".to_string()),
-            options: None,
+            ..Launcher::new("test".to_string(), "Test".to_string(), LaunchType::Web, "https://example.com".to_string())
         };
         config.add_launcher(launcher);
         assert_eq!(config.launchers.len(), 1);
@@ -80,21 +93,10 @@ mod tests {
 
     #[test]
     fn test_remove_launcher() {
-        let mut config = Config {
-            version: "0.1.0".to_string(),
-            theme: "light".to_string(),
-            autostart: false,
-            launchers: vec![],
-            background: None,
-            language: "en".to_string(),
-        };
+        let mut config = Config::default();
         let launcher = Launcher {
-            id: "test".to_string(),
-            name: "Test".to_string(),
-            launch_type: LaunchType::Web,
-            target: "https://example.com".to_string(),
             icon: Some("icon.png".to_string()),
-            options: None,
+            ..Launcher::new("test".to_string(), "Test".to_string(), LaunchType::Web, "https://example.com".to_string())
         };
         config.add_launcher(launcher);
         assert_eq!(config.launchers.len(), 1);
@@ -102,16 +104,28 @@ mod tests {
         assert_eq!(config.launchers.len(), 0);
     }
 
+    #[test]
+    fn test_find_launcher() {
+        let mut config = Config::default();
+        config.add_launcher(Launcher::new(
+            "github".to_string(),
+            "GitHub".to_string(),
+            LaunchType::Web,
+            "https://github.com".to_string(),
+        ));
+        config.add_launcher(Launcher::new(
+            "gimp".to_string(),
+            "GIMP".to_string(),
+            LaunchType::App,
+            "gimp".to_string(),
+        ));
+        assert_eq!(config.find_launcher("gimp").unwrap().name, "GIMP");
+        assert!(config.find_launcher("firefox").is_none());
+    }
+
     #[test]
     fn test_save_and_load() {
-        let config = Config {
-            version: "0.1.0".to_string(),
-            theme: "light".to_string(),
-            autostart: false,
-            launchers: vec![],
-            background: None,
-            language: "en".to_string(),
-        };
+        let config = Config::default();
         let path = "test_config.json";
         config.save(path).expect("Failed to save");
         let loaded = Config::load(path).expect("Failed to load");
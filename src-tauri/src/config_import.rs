@@ -0,0 +1,389 @@
+//! Config Import Module
+//!
+//! Imports an exported configuration without wiping the current one, and
+//! describes what an import would do before it is applied.
+//! Features:
+//! - Modes: replace everything, merge launchers and set settings, add new launchers only
+//! - Duplicate ids and names skipped, overwritten, or renamed with `generate_unique_id`
+//! - Group members follow renamed launchers
+//! - Dry-run diff of added, changed, removed and skipped launchers and changed settings
+
+use crate::chain::validate_chains;
+use crate::config::Config;
+use crate::group::validate_groups;
+use crate::launcher::{generate_unique_id, Launcher};
+use crate::project::ProjectSettings;
+use serde::{Deserialize, Serialize};
+
+/// What an import does with the current configuration
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportMode {
+    /// The imported configuration replaces the current one
+    #[default]
+    Replace,
+    /// Imported launchers are merged, settings set by the import are applied
+    Merge,
+    /// Only launchers without a conflict are added, nothing else changes
+    AddOnly,
+}
+
+/// What happens to an imported launcher whose id or name already exists
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// Keep the current launcher
+    #[default]
+    Skip,
+    /// Replace the current launcher with the imported one
+    Overwrite,
+    /// Add the imported launcher under a new id and name
+    Rename,
+}
+
+/// Mode and conflict strategy of an import
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct ImportOptions {
+    #[serde(default)]
+    pub mode: ImportMode,
+    #[serde(default)]
+    pub conflict: ConflictStrategy,
+}
+
+/// Launcher affected by an import
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LauncherDiff {
+    pub id: String,
+    pub name: String,
+    /// Imported id, when the launcher was renamed
+    #[serde(default)]
+    pub renamed_from: Option<String>,
+    /// Changed fields of overwritten launchers
+    #[serde(default)]
+    pub fields: Vec<String>,
+}
+
+impl LauncherDiff {
+    fn of(launcher: &Launcher) -> Self {
+        LauncherDiff {
+            id: launcher.id.clone(),
+            name: launcher.name.clone(),
+            renamed_from: None,
+            fields: Vec::new(),
+        }
+    }
+}
+
+/// Setting changed by an import
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SettingDiff {
+    pub key: String,
+    pub current: serde_json::Value,
+    pub imported: serde_json::Value,
+}
+
+/// Changes an import makes to the configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ImportDiff {
+    pub added: Vec<LauncherDiff>,
+    pub changed: Vec<LauncherDiff>,
+    pub removed: Vec<LauncherDiff>,
+    /// Imported launchers left out because of a conflict
+    pub skipped: Vec<LauncherDiff>,
+    pub settings: Vec<SettingDiff>,
+}
+
+impl ImportDiff {
+    /// Whether the import changes nothing
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty() && self.settings.is_empty()
+    }
+}
+
+/// Top-level fields of two values that differ, `launchers` excluded
+fn changed_fields(current: &serde_json::Value, imported: &serde_json::Value) -> Vec<String> {
+    let (Some(current), Some(imported)) = (current.as_object(), imported.as_object()) else {
+        return Vec::new();
+    };
+    let mut keys: Vec<&String> = current.keys().chain(imported.keys()).filter(|k| *k != "launchers").collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter(|k| current.get(*k) != imported.get(*k))
+        .cloned()
+        .collect()
+}
+
+fn to_value<T: Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or_default()
+}
+
+/// Settings of the import to apply in merge mode: those it sets
+fn merge_settings(current: &Config, imported: &Config) -> Config {
+    let mut merged = current.clone();
+    if !imported.language.trim().is_empty() {
+        merged.language = imported.language.clone();
+    }
+    if imported.background.is_some() {
+        merged.background = imported.background.clone();
+    }
+    if imported.terminal.is_some() {
+        merged.terminal = imported.terminal.clone();
+    }
+    for dir in &imported.appimage_dirs {
+        if !merged.appimage_dirs.contains(dir) {
+            merged.appimage_dirs.push(dir.clone());
+        }
+    }
+    if imported.projects != ProjectSettings::default() {
+        merged.projects = imported.projects.clone();
+    }
+    merged
+}
+
+/// Name not used by any launcher, numbered like `Mail (2)`
+fn unique_name(name: &str, launchers: &[Launcher]) -> String {
+    let taken = |candidate: &str| launchers.iter().any(|l| l.name.eq_ignore_ascii_case(candidate));
+    if !taken(name) {
+        return name.to_string();
+    }
+    (2..)
+        .map(|i| format!("{} ({})", name, i))
+        .find(|candidate| !taken(candidate))
+        .unwrap_or_else(|| name.to_string())
+}
+
+/// Configuration after importing `imported` into `current`, and the changes made
+///
+/// Nothing is written: the caller saves the result, or shows the diff for a dry run.
+pub fn plan_import(current: &Config, imported: Config, options: ImportOptions) -> Result<(Config, ImportDiff), String> {
+    validate_groups(&imported.launchers).map_err(|e| format!("Import failed: {}", e))?;
+    validate_chains(&imported.launchers).map_err(|e| format!("Import failed: {}", e))?;
+    if options.mode == ImportMode::AddOnly && options.conflict == ConflictStrategy::Overwrite {
+        return Err("Add-only imports cannot overwrite launchers".to_string());
+    }
+
+    let mut diff = ImportDiff::default();
+    let mut result = match options.mode {
+        ImportMode::Replace => imported.clone(),
+        ImportMode::Merge => merge_settings(current, &imported),
+        ImportMode::AddOnly => current.clone(),
+    };
+    for key in changed_fields(&to_value(current), &to_value(&result)) {
+        diff.settings.push(SettingDiff {
+            current: to_value(current).get(&key).cloned().unwrap_or_default(),
+            imported: to_value(&result).get(&key).cloned().unwrap_or_default(),
+            key,
+        });
+    }
+
+    if options.mode == ImportMode::Replace {
+        for launcher in &imported.launchers {
+            match current.launchers.iter().find(|l| l.id == launcher.id) {
+                None => diff.added.push(LauncherDiff::of(launcher)),
+                Some(existing) if existing != launcher => diff.changed.push(LauncherDiff {
+                    fields: changed_fields(&to_value(existing), &to_value(launcher)),
+                    ..LauncherDiff::of(launcher)
+                }),
+                Some(_) => {}
+            }
+        }
+        for launcher in &current.launchers {
+            if !imported.launchers.iter().any(|l| l.id == launcher.id) {
+                diff.removed.push(LauncherDiff::of(launcher));
+            }
+        }
+        return Ok((result, diff));
+    }
+
+    result.launchers = current.launchers.clone();
+    // Final id of imported ids that changed, applied to the imported group members
+    let mut renames: Vec<(String, String)> = Vec::new();
+    let mut imported_ids: Vec<String> = Vec::new();
+    for launcher in imported.launchers {
+        let conflict = result
+            .launchers
+            .iter()
+            .position(|l| l.id == launcher.id)
+            .or_else(|| result.launchers.iter().position(|l| l.name.eq_ignore_ascii_case(&launcher.name)));
+        let Some(index) = conflict else {
+            diff.added.push(LauncherDiff::of(&launcher));
+            imported_ids.push(launcher.id.clone());
+            result.launchers.push(launcher);
+            continue;
+        };
+        let existing_id = result.launchers[index].id.clone();
+        if existing_id != launcher.id {
+            renames.push((launcher.id.clone(), existing_id.clone()));
+        }
+        if result.launchers[index] == launcher {
+            continue;
+        }
+        match options.conflict {
+            // The current launcher stands for the imported one
+            ConflictStrategy::Skip => diff.skipped.push(LauncherDiff::of(&launcher)),
+            // The current id is kept, so current groups still find the launcher
+            ConflictStrategy::Overwrite => {
+                let imported_id = launcher.id.clone();
+                let mut replacement = launcher;
+                replacement.id = existing_id;
+                diff.changed.push(LauncherDiff {
+                    renamed_from: (imported_id != replacement.id).then_some(imported_id),
+                    fields: changed_fields(&to_value(&result.launchers[index]), &to_value(&replacement)),
+                    ..LauncherDiff::of(&replacement)
+                });
+                imported_ids.push(replacement.id.clone());
+                result.launchers[index] = replacement;
+            }
+            ConflictStrategy::Rename => {
+                renames.retain(|(from, _)| *from != launcher.id);
+                let ids: Vec<String> = result.launchers.iter().map(|l| l.id.clone()).collect();
+                let imported_id = launcher.id.clone();
+                let mut renamed = launcher;
+                if ids.contains(&renamed.id) {
+                    renamed.id = generate_unique_id(&renamed.id, &ids);
+                }
+                renamed.name = unique_name(&renamed.name, &result.launchers);
+                if renamed.id != imported_id {
+                    renames.push((imported_id.clone(), renamed.id.clone()));
+                }
+                diff.added.push(LauncherDiff {
+                    renamed_from: Some(imported_id),
+                    ..LauncherDiff::of(&renamed)
+                });
+                imported_ids.push(renamed.id.clone());
+                result.launchers.push(renamed);
+            }
+        }
+    }
+
+    // Imported groups point at the launchers their members became
+    for launcher in result.launchers.iter_mut().filter(|l| imported_ids.contains(&l.id)) {
+        if let Some(group) = launcher.group.as_mut() {
+            for member in &mut group.members {
+                if let Some((_, id)) = renames.iter().find(|(from, _)| *from == member.id) {
+                    member.id = id.clone();
+                }
+            }
+        }
+    }
+    validate_groups(&result.launchers).map_err(|e| format!("Import failed: {}", e))?;
+    Ok((result, diff))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::group::GroupOptions;
+    use crate::launcher::LaunchType;
+
+    fn web(id: &str, name: &str, url: &str) -> Launcher {
+        Launcher::new(id.to_string(), name.to_string(), LaunchType::Web, url.to_string())
+    }
+
+    fn config(launchers: Vec<Launcher>) -> Config {
+        Config {
+            version: "1.0".to_string(),
+            theme: "dark".to_string(),
+            launchers,
+            background: Some("#123456".to_string()),
+            language: "fr".to_string(),
+            ..Config::default()
+        }
+    }
+
+    fn ids(config: &Config) -> Vec<&str> {
+        config.launchers.iter().map(|l| l.id.as_str()).collect()
+    }
+
+    fn current() -> Config {
+        config(vec![web("mail", "Mail", "https://mail.local"), web("wiki", "Wiki", "https://wiki.local")])
+    }
+
+    fn imported() -> Config {
+        let mut imported = config(vec![
+            web("mail", "Mail", "https://mail.example.com"),
+            web("docs", "Wiki", "https://docs.local"),
+            web("ci", "CI", "https://ci.local"),
+        ]);
+        imported.background = None;
+        imported.language = "en".to_string();
+        imported
+    }
+
+    #[test]
+    fn test_replace_diff() {
+        let (result, diff) = plan_import(&current(), imported(), ImportOptions::default()).unwrap();
+        assert_eq!(ids(&result), vec!["mail", "docs", "ci"]);
+        assert_eq!(result.background, None);
+        assert_eq!(diff.added.len(), 2);
+        assert_eq!(diff.changed[0].id, "mail");
+        assert_eq!(diff.changed[0].fields, vec!["target"]);
+        assert_eq!(diff.removed[0].id, "wiki");
+        let keys: Vec<&str> = diff.settings.iter().map(|s| s.key.as_str()).collect();
+        assert_eq!(keys, vec!["background", "language"]);
+    }
+
+    #[test]
+    fn test_merge_keeps_current_settings_and_skips_conflicts() {
+        let options = ImportOptions {
+            mode: ImportMode::Merge,
+            conflict: ConflictStrategy::Skip,
+        };
+        let (result, diff) = plan_import(&current(), imported(), options).unwrap();
+        assert_eq!(ids(&result), vec!["mail", "wiki", "ci"]);
+        assert_eq!(result.launchers[0].target, "https://mail.local");
+        // The import has no background: the current one stays
+        assert_eq!(result.background.as_deref(), Some("#123456"));
+        assert_eq!(result.language, "en");
+        assert_eq!(diff.skipped.len(), 2);
+        assert!(diff.removed.is_empty());
+    }
+
+    #[test]
+    fn test_merge_overwrite() {
+        let options = ImportOptions {
+            mode: ImportMode::Merge,
+            conflict: ConflictStrategy::Overwrite,
+        };
+        let (result, diff) = plan_import(&current(), imported(), options).unwrap();
+        assert_eq!(ids(&result), vec!["mail", "wiki", "ci"]);
+        assert_eq!(result.launchers[0].target, "https://mail.example.com");
+        assert_eq!(result.launchers[1].target, "https://docs.local");
+        assert_eq!(diff.changed.len(), 2);
+        assert_eq!(diff.changed[1].renamed_from.as_deref(), Some("docs"));
+        assert_eq!(diff.changed[1].fields, vec!["target"]);
+    }
+
+    #[test]
+    fn test_add_only_rename_follows_group_members() {
+        let mut imported = imported();
+        let mut group = Launcher::new("morning".to_string(), "Morning".to_string(), LaunchType::Group, String::new());
+        group.group = Some(GroupOptions::ordered(&["mail", "ci"]));
+        imported.launchers.push(group);
+        let options = ImportOptions {
+            mode: ImportMode::AddOnly,
+            conflict: ConflictStrategy::Rename,
+        };
+        let (result, diff) = plan_import(&current(), imported, options).unwrap();
+        assert_eq!(ids(&result), vec!["mail", "wiki", "mail-2", "docs", "ci", "morning"]);
+        assert_eq!(result.launchers[2].name, "Mail (2)");
+        assert_eq!(result.launchers[3].name, "Wiki (2)");
+        assert_eq!(result.language, "fr");
+        assert!(diff.settings.is_empty());
+        assert_eq!(diff.added[0].renamed_from.as_deref(), Some("mail"));
+        let members: Vec<&str> = result.launchers[5].group.as_ref().unwrap().members.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(members, vec!["mail-2", "ci"]);
+    }
+
+    #[test]
+    fn test_add_only_refuses_overwrite() {
+        let options = ImportOptions {
+            mode: ImportMode::AddOnly,
+            conflict: ConflictStrategy::Overwrite,
+        };
+        assert!(plan_import(&current(), imported(), options).is_err());
+        let (_, diff) = plan_import(&current(), current(), ImportOptions { mode: ImportMode::Merge, ..ImportOptions::default() }).unwrap();
+        assert!(diff.is_empty());
+    }
+}
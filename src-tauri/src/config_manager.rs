@@ -3,8 +3,12 @@
 //! Handles persistent configuration with auto-save
 //! Supports platform-specific storage locations
 
+use crate::chain::validate_chains;
 use crate::config::Config;
+use crate::config_import::{plan_import, ImportDiff, ImportOptions};
+use crate::group::validate_groups;
 use crate::launcher::Launcher;
+use crate::project::ProjectSettings;
 use std::path::{Path, PathBuf};
 
 /// Configuration manager with auto-save
@@ -88,12 +92,8 @@ impl ConfigManager {
     /// Create default configuration with initial launcher
     fn default_config() -> Config {
         Config {
-            version: "0.1.0".to_string(),
-            theme: "light".to_string(),
-            autostart: false,
             launchers: vec![Self::create_default_launcher()],
-            background: None,
-            language: "en".to_string(),
+            ..Config::default()
         }
     }
 
@@ -125,11 +125,33 @@ impl ConfigManager {
     /// Add launcher with auto-save
     pub fn add_launcher(&mut self, launcher: Launcher) -> Result<(), String> {
         self.config.add_launcher(launcher);
+        if let Err(e) = validate_groups(&self.config.launchers).and_then(|_| validate_chains(&self.config.launchers)) {
+            self.config.launchers.pop();
+            return Err(e);
+        }
         self.save()?;
         log::info!("Launcher added and config saved");
         Ok(())
     }
 
+    /// Replace the launcher with the same id with auto-save
+    pub fn update_launcher(&mut self, launcher: Launcher) -> Result<(), String> {
+        let index = self
+            .config
+            .launchers
+            .iter()
+            .position(|l| l.id == launcher.id)
+            .ok_or_else(|| format!("Launcher not found: {}", launcher.id))?;
+        let previous = std::mem::replace(&mut self.config.launchers[index], launcher);
+        if let Err(e) = validate_groups(&self.config.launchers).and_then(|_| validate_chains(&self.config.launchers)) {
+            self.config.launchers[index] = previous;
+            return Err(e);
+        }
+        self.save()?;
+        log::info!("Launcher updated and config saved");
+        Ok(())
+    }
+
     /// Remove launcher with auto-save
     pub fn remove_launcher(&mut self, id: &str) -> Result<(), String> {
         self.config.remove_launcher(id);
@@ -166,10 +188,35 @@ impl ConfigManager {
         Ok(())
     }
 
+    /// Set terminal emulator with auto-save, `None` to auto-detect
+    pub fn set_terminal(&mut self, terminal: Option<String>) -> Result<(), String> {
+        self.config.terminal = terminal.filter(|t| !t.trim().is_empty());
+        self.save()?;
+        log::info!("Terminal setting updated");
+        Ok(())
+    }
+
+    /// Set the extra AppImage directories with auto-save
+    pub fn set_appimage_dirs(&mut self, dirs: Vec<String>) -> Result<(), String> {
+        self.config.appimage_dirs = dirs.into_iter().filter(|d| !d.trim().is_empty()).collect();
+        self.save()?;
+        log::info!("AppImage directories updated");
+        Ok(())
+    }
+
+    /// Set the project discovery settings with auto-save
+    pub fn set_project_settings(&mut self, settings: ProjectSettings) -> Result<(), String> {
+        self.config.projects = settings;
+        self.save()?;
+        log::info!("Project discovery settings updated");
+        Ok(())
+    }
+
     /// Reset all settings to default values
     pub fn reset_settings(&mut self) -> Result<(), String> {
         self.config.language = "en".to_string();
         self.config.background = None;
+        self.config.terminal = None;
         self.save()?;
         log::info!("Settings reset to default values");
         Ok(())
@@ -204,19 +251,35 @@ impl ConfigManager {
             .map_err(|e| format!("Export failed: {}", e))
     }
 
-    pub fn import_from_json(json: &str) -> Result<Self, String> {
-        let config: Config = serde_json::from_str(json)
+    /// Changes importing `json` would make, nothing is saved
+    pub fn preview_import(&self, json: &str, options: ImportOptions) -> Result<ImportDiff, String> {
+        let imported: Config = serde_json::from_str(json)
+            .map_err(|e| format!("Import failed: {}", e))?;
+        plan_import(&self.config, imported, options).map(|(_, diff)| diff)
+    }
+
+    /// Import `json` into the current configuration with auto-save, returns the changes made
+    pub fn import_json(&mut self, json: &str, options: ImportOptions) -> Result<ImportDiff, String> {
+        let imported: Config = serde_json::from_str(json)
             .map_err(|e| format!("Import failed: {}", e))?;
-        Ok(ConfigManager {
-            config_path: Self::get_config_path(),
-            config,
-        })
+        let (config, diff) = plan_import(&self.config, imported, options)?;
+        self.config = config;
+        self.save()?;
+        log::info!(
+            "Config imported: {} added, {} changed, {} removed, {} skipped",
+            diff.added.len(),
+            diff.changed.len(),
+            diff.removed.len(),
+            diff.skipped.len()
+        );
+        Ok(diff)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::group::GroupOptions;
     use crate::launcher::LaunchType;
     use std::sync::Mutex;
 
@@ -294,12 +357,8 @@ mod tests {
         let mut manager = ConfigManager::load_or_default().expect("Failed to load config");
 
         let launcher = Launcher {
-            id: "test_add_12345".to_string(),
-            name: "Test".to_string(),
-            launch_type: LaunchType::Web,
-            target: "https://example.com".to_string(),
             icon: Some("icon.png".to_string()),
-            options: None,
+            ..Launcher::new("test_add_12345".to_string(), "Test".to_string(), LaunchType::Web, "https://example.com".to_string())
         };
 
         manager.add_launcher(launcher).expect("Failed to add launcher");
@@ -315,14 +374,7 @@ mod tests {
 
         let mut manager = ConfigManager::load_or_default().expect("Failed to load config");
 
-        let launcher = Launcher {
-            id: "test_remove_12345".to_string(),
-            name: "Test".to_string(),
-            launch_type: LaunchType::App,
-            target: "sh".to_string(),
-            icon: None,
-            options: None,
-        };
+        let launcher = Launcher::new("test_remove_12345".to_string(), "Test".to_string(), LaunchType::App, "sh".to_string());
 
         manager.add_launcher(launcher).expect("Failed to add launcher");
         assert!(manager.config().launchers.iter().any(|l| l.id == "test_remove_12345"));
@@ -396,14 +448,7 @@ mod tests {
         
         let mut manager = ConfigManager::load_or_default().expect("Failed to load");
         
-        let launcher = Launcher {
-            id: "export_test".to_string(),
-            name: "Export Test".to_string(),
-            launch_type: LaunchType::App,
-            target: "/bin/app".to_string(),
-            icon: None,
-            options: None,
-        };
+        let launcher = Launcher::new("export_test".to_string(), "Export Test".to_string(), LaunchType::App, "/bin/app".to_string());
         
         manager.add_launcher(launcher).expect("Failed to add");
         manager.save().expect("Failed to save");
@@ -415,27 +460,74 @@ mod tests {
         cleanup_test_config();
     }
 
+
     #[test]
-    fn test_import_from_json() {
-        let json = r#"{
-            "version": "0.1.0",
-            "theme": "light",
-            "autostart": false,
-            "launchers": [
-                {
-                    "id": "import_test",
-                    "name": "Import Test",
-                    "type": "app",
-                    "target": "/bin/app",
-                    "icon": null,
-                    "options": null
-                }
-            ]
-        }"#;
-    
-        let manager = ConfigManager::import_from_json(json).expect("Failed to import");
-        assert_eq!(manager.config().launchers.len(), 1);
-        assert_eq!(manager.config().launchers[0].id, "import_test");
+    fn test_add_launcher_refuses_group_cycle() {
+        let _guard = TEST_LOCK.lock().unwrap();
+        cleanup_test_config();
+
+        let mut manager = ConfigManager::load_or_default().expect("Failed to load");
+        let mut first = Launcher::new(
+            "group_a".to_string(),
+            "Group A".to_string(),
+            LaunchType::Group,
+            String::new(),
+        );
+        first.group = Some(GroupOptions::ordered(&["group_b"]));
+        let mut second = Launcher::new(
+            "group_b".to_string(),
+            "Group B".to_string(),
+            LaunchType::Group,
+            String::new(),
+        );
+        second.group = Some(GroupOptions::ordered(&["group_a"]));
+
+        manager.add_launcher(first).expect("Failed to add first group");
+        let result = manager.add_launcher(second);
+        assert!(result.unwrap_err().contains("cycle"));
+        assert!(!manager.config().launchers.iter().any(|l| l.id == "group_b"));
+
+        // A cycle already on disk must not block unrelated settings
+        let mut second = manager.config().launchers[0].clone();
+        second.id = "group_b".to_string();
+        second.group = Some(GroupOptions::ordered(&["group_a"]));
+        manager.config_mut().launchers.push(second);
+        manager.set_language("fr".to_string()).expect("Failed to save language");
+
+        cleanup_test_config();
+    }
+
+    #[test]
+    fn test_update_launcher_keeps_position() {
+        let _guard = TEST_LOCK.lock().unwrap();
+        cleanup_test_config();
+
+        let mut manager = ConfigManager::load_or_default().expect("Failed to load");
+        let mut launcher = Launcher::new(
+            "update_test".to_string(),
+            "Update Test".to_string(),
+            LaunchType::App,
+            "/bin/old".to_string(),
+        );
+        manager.add_launcher(launcher.clone()).expect("Failed to add");
+        manager.add_launcher(Launcher::new(
+            "after".to_string(),
+            "After".to_string(),
+            LaunchType::App,
+            "/bin/after".to_string(),
+        )).expect("Failed to add");
+
+        launcher.target = "/bin/new".to_string();
+        manager.update_launcher(launcher).expect("Failed to update");
+        let reloaded = ConfigManager::load_or_default().expect("Failed to reload");
+        let ids: Vec<&str> = reloaded.config().launchers.iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, vec!["rhone_digital", "update_test", "after"]);
+        assert_eq!(reloaded.config().launchers[1].target, "/bin/new");
+
+        let missing = Launcher::new("missing".to_string(), "Missing".to_string(), LaunchType::App, String::new());
+        assert!(manager.update_launcher(missing).is_err());
+
+        cleanup_test_config();
     }
 
     #[test]
@@ -457,13 +549,16 @@ mod tests {
 
         let mut manager = ConfigManager::load_or_default().expect("Failed to load");
         manager.set_language("fr".to_string()).expect("Failed to set language");
+        manager.set_terminal(Some("kitty".to_string())).expect("Failed to set terminal");
         
         assert_eq!(manager.get_language(), "fr");
+        assert_eq!(manager.config().terminal, Some("kitty".to_string()));
         
         manager.reset_settings().expect("Failed to reset");
         
         assert_eq!(manager.get_language(), "en");
         assert!(manager.config().background.is_none());
+        assert!(manager.config().terminal.is_none());
 
         cleanup_test_config();
     }
@@ -485,4 +580,39 @@ mod tests {
 
         cleanup_test_config();
     }
+
+    #[test]
+    fn test_import_json_merge_keeps_settings() {
+        use crate::config_import::ImportMode;
+        let _guard = TEST_LOCK.lock().unwrap();
+        cleanup_test_config();
+
+        let mut manager = ConfigManager::load_or_default().expect("Failed to load");
+        manager.save_all_settings("fr".to_string(), Some("gradient2".to_string())).expect("Failed to save all");
+        let launchers = manager.config().launchers.len();
+        let json = r#"{
+            "version": "0.1.0",
+            "theme": "light",
+            "autostart": false,
+            "launchers": [
+                { "id": "import_test", "name": "Import Test", "type": "app", "target": "/bin/app" }
+            ]
+        }"#;
+        let options = ImportOptions {
+            mode: ImportMode::Merge,
+            ..ImportOptions::default()
+        };
+
+        let preview = manager.preview_import(json, options).expect("Failed to preview");
+        assert_eq!(preview.added.len(), 1);
+        assert_eq!(manager.config().launchers.len(), launchers);
+
+        let diff = manager.import_json(json, options).expect("Failed to import");
+        assert_eq!(diff, preview);
+        let reloaded = ConfigManager::load_or_default().expect("Failed to reload");
+        assert_eq!(reloaded.config().launchers.len(), launchers + 1);
+        assert_eq!(reloaded.config().background, Some("gradient2".to_string()));
+
+        cleanup_test_config();
+    }
 }
\ No newline at end of file
@@ -0,0 +1,521 @@
+//! Container Module
+//!
+//! Container launchers run an image or a compose project with podman or docker.
+//! Features:
+//! - Podman or docker detected on PATH, podman first
+//! - Image launchers: ports, volumes and environment from the launch options
+//! - Compose launchers: `up`/`down` of a project directory
+//! - Status parsed from `ps` output, start/stop/logs actions
+
+use crate::launcher::{run_blocking, LaunchOptions, Launcher};
+use crate::system::command_runner::CommandOutput;
+use serde::{Deserialize, Serialize};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+/// Lines of logs returned by the logs action
+const LOG_TAIL_LINES: u32 = 200;
+
+/// Timeout of status queries
+const STATUS_TIMEOUT_SECS: u64 = 10;
+
+/// Minimum timeout of start actions, which may pull images first
+const START_TIMEOUT_SECS: u64 = 600;
+
+/// Container engine CLI
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EngineKind {
+    Podman,
+    Docker,
+}
+
+impl EngineKind {
+    /// Executable name
+    pub fn program(&self) -> &'static str {
+        match self {
+            EngineKind::Podman => "podman",
+            EngineKind::Docker => "docker",
+        }
+    }
+}
+
+/// Container engine found on PATH
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerEngine {
+    pub kind: EngineKind,
+    pub program: PathBuf,
+}
+
+/// Find a container engine in a PATH-like list of directories
+///
+/// Only `preferred` is looked for when set, otherwise podman then docker.
+pub fn detect_engine_in(search_path: &OsStr, preferred: Option<EngineKind>) -> Option<ContainerEngine> {
+    let kinds = match preferred {
+        Some(kind) => vec![kind],
+        None => vec![EngineKind::Podman, EngineKind::Docker],
+    };
+    kinds.into_iter().find_map(|kind| {
+        std::env::split_paths(search_path)
+            .map(|dir| dir.join(kind.program()))
+            .find(|path| path.is_file())
+            .map(|program| ContainerEngine { kind, program })
+    })
+}
+
+/// Find the container engine of the current PATH, or of the host when sandboxed
+pub fn detect_engine(preferred: Option<EngineKind>) -> Result<ContainerEngine, String> {
+    let found = if crate::sandbox::is_sandboxed() {
+        let kinds = match preferred {
+            Some(kind) => vec![kind],
+            None => vec![EngineKind::Podman, EngineKind::Docker],
+        };
+        let names: Vec<&str> = kinds.iter().map(|kind| kind.program()).collect();
+        crate::precondition::find_program(&names).and_then(|name| {
+            let kind = kinds.into_iter().find(|kind| kind.program() == name)?;
+            Some(ContainerEngine { kind, program: PathBuf::from(name) })
+        })
+    } else {
+        std::env::var_os("PATH").and_then(|path| detect_engine_in(&path, preferred))
+    };
+    found.ok_or_else(|| match preferred {
+            Some(kind) => format!("{} not found on PATH", kind.program()),
+            None => "Neither podman nor docker found on PATH".to_string(),
+        })
+}
+
+/// What a container launcher runs
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerMode {
+    /// Target is an image
+    #[default]
+    Image,
+    /// Target is a compose project directory
+    Compose,
+}
+
+/// Container options of a launcher
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ContainerOptions {
+    /// Image or compose project
+    #[serde(default)]
+    pub mode: ContainerMode,
+    /// Container or compose project name, derived from the launcher when unset
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Engine to use, auto-detected when unset
+    #[serde(default)]
+    pub engine: Option<EngineKind>,
+}
+
+/// Action on a container launcher
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerAction {
+    Start,
+    Stop,
+    Logs,
+}
+
+impl std::str::FromStr for ContainerAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "start" => Ok(ContainerAction::Start),
+            "stop" => Ok(ContainerAction::Stop),
+            "logs" => Ok(ContainerAction::Logs),
+            other => Err(format!("Unknown container action: {}", other)),
+        }
+    }
+}
+
+/// Container listed by `ps`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContainerInfo {
+    pub name: String,
+    /// Engine state (`running`, `exited`, `created`...)
+    pub state: String,
+}
+
+impl ContainerInfo {
+    pub fn is_running(&self) -> bool {
+        matches!(self.state.as_str(), "running" | "restarting")
+    }
+}
+
+/// Overall state of a container launcher
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerState {
+    /// At least one container running
+    Running,
+    /// Containers exist, none running
+    Stopped,
+    /// No container
+    Absent,
+}
+
+/// Status of a container launcher
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContainerStatus {
+    pub state: ContainerState,
+    pub containers: Vec<ContainerInfo>,
+}
+
+impl ContainerStatus {
+    fn from_containers(containers: Vec<ContainerInfo>) -> Self {
+        let state = if containers.iter().any(ContainerInfo::is_running) {
+            ContainerState::Running
+        } else if containers.is_empty() {
+            ContainerState::Absent
+        } else {
+            ContainerState::Stopped
+        };
+        ContainerStatus { state, containers }
+    }
+}
+
+/// `ps` format: name and state separated by a tab
+const PS_FORMAT: &str = "{{.Names}}\t{{.State}}";
+
+/// Parse `ps --format` output written with [`PS_FORMAT`]
+pub fn parse_ps(stdout: &str) -> Vec<ContainerInfo> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (name, state) = line.trim().split_once('\t')?;
+            Some(ContainerInfo {
+                name: name.trim().to_string(),
+                state: state.trim().to_lowercase(),
+            })
+        })
+        .filter(|c| !c.name.is_empty())
+        .collect()
+}
+
+/// Container or compose project name (lowercase letters, digits, `-` and `_`)
+fn sanitize_name(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect()
+}
+
+/// Container launcher resolved for an engine
+#[derive(Debug, Clone, PartialEq)]
+pub struct Container {
+    pub mode: ContainerMode,
+    /// Image or compose project directory
+    pub target: String,
+    /// Container or compose project name
+    pub name: String,
+}
+
+impl Container {
+    /// Describe the container of a launcher
+    pub fn for_launcher(launcher: &Launcher) -> Result<Self, String> {
+        let options = launcher.container.clone().unwrap_or_default();
+        let target = launcher.target.trim().to_string();
+        if target.is_empty() {
+            return Err(format!("Launcher '{}' has no image or compose directory", launcher.name));
+        }
+        let name = match (&options.name, options.mode) {
+            (Some(name), _) if !name.trim().is_empty() => sanitize_name(name.trim()),
+            (_, ContainerMode::Image) => format!("initium-{}", sanitize_name(&launcher.id)),
+            (_, ContainerMode::Compose) => {
+                let dir = Path::new(&target)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| launcher.id.clone());
+                sanitize_name(&dir)
+            }
+        };
+        Ok(Container {
+            mode: options.mode,
+            target,
+            name,
+        })
+    }
+
+    /// `run` arguments: detached, named, with ports, volumes and environment
+    pub fn run_arguments(&self, options: &LaunchOptions) -> Vec<String> {
+        let mut args = vec!["run".to_string(), "-d".to_string(), "--name".to_string(), self.name.clone()];
+        for port in &options.ports {
+            args.push("-p".to_string());
+            args.push(port.clone());
+        }
+        for volume in &options.volumes {
+            args.push("-v".to_string());
+            args.push(volume.clone());
+        }
+        for (key, value) in options.env_vars.iter().flatten() {
+            args.push("-e".to_string());
+            args.push(format!("{}={}", key, value));
+        }
+        args.push(self.target.clone());
+        args.extend(options.args.iter().cloned());
+        args
+    }
+
+    /// `compose` arguments for a project subcommand
+    pub fn compose_arguments(&self, subcommand: &[&str]) -> Vec<String> {
+        let mut args = vec![
+            "compose".to_string(),
+            "--project-directory".to_string(),
+            self.target.clone(),
+            "-p".to_string(),
+            self.name.clone(),
+        ];
+        args.extend(subcommand.iter().map(|s| s.to_string()));
+        args
+    }
+
+    /// `ps` arguments listing the containers of this launcher
+    pub fn ps_arguments(&self) -> Vec<String> {
+        let filter = match self.mode {
+            ContainerMode::Image => format!("name=^{}$", self.name),
+            ContainerMode::Compose => format!("label=com.docker.compose.project={}", self.name),
+        };
+        vec![
+            "ps".to_string(),
+            "-a".to_string(),
+            "--filter".to_string(),
+            filter,
+            "--format".to_string(),
+            PS_FORMAT.to_string(),
+        ]
+    }
+
+    /// Current status from `ps`
+    pub async fn status(&self, engine: &ContainerEngine) -> Result<ContainerStatus, String> {
+        let options = LaunchOptions {
+            timeout_secs: STATUS_TIMEOUT_SECS,
+            ..LaunchOptions::default()
+        };
+        let output = run_engine(engine, self.ps_arguments(), &options).await?;
+        if !output.success {
+            return Err(format!("Failed to list containers: {}", output.stderr.trim()));
+        }
+        let mut containers = parse_ps(&output.stdout);
+        if self.mode == ContainerMode::Image {
+            // Name filters are regular expressions on some engines, keep exact matches only
+            containers.retain(|c| c.name == self.name);
+        }
+        Ok(ContainerStatus::from_containers(containers))
+    }
+
+    /// Start, stop or show the logs of the container
+    ///
+    /// Starting a stopped image container restarts it instead of running a new one.
+    pub async fn perform(
+        &self,
+        engine: &ContainerEngine,
+        action: ContainerAction,
+        options: &LaunchOptions,
+    ) -> Result<CommandOutput, String> {
+        let tail = LOG_TAIL_LINES.to_string();
+        let args = match (self.mode, action) {
+            (ContainerMode::Image, ContainerAction::Start) => match self.status(engine).await?.state {
+                ContainerState::Absent => self.run_arguments(options),
+                _ => vec!["start".to_string(), self.name.clone()],
+            },
+            (ContainerMode::Image, ContainerAction::Stop) => vec!["stop".to_string(), self.name.clone()],
+            (ContainerMode::Image, ContainerAction::Logs) => {
+                vec!["logs".to_string(), "--tail".to_string(), tail, self.name.clone()]
+            }
+            (ContainerMode::Compose, ContainerAction::Start) => self.compose_arguments(&["up", "-d"]),
+            (ContainerMode::Compose, ContainerAction::Stop) => self.compose_arguments(&["down"]),
+            (ContainerMode::Compose, ContainerAction::Logs) => self.compose_arguments(&["logs", "--tail", &tail]),
+        };
+        let options = LaunchOptions {
+            timeout_secs: match action {
+                ContainerAction::Start => options.timeout_secs.max(START_TIMEOUT_SECS),
+                _ => options.timeout_secs,
+            },
+            // Image containers get the environment variables with `-e`, compose
+            // reads them from the engine process to interpolate the compose file
+            env_vars: match self.mode {
+                ContainerMode::Image => None,
+                ContainerMode::Compose => options.env_vars.clone(),
+            },
+            ..options.clone()
+        };
+        log::info!("Container {:?} of '{}' with {}", action, self.name, engine.kind.program());
+        run_engine(engine, args, &options).await
+    }
+}
+
+async fn run_engine(engine: &ContainerEngine, args: Vec<String>, options: &LaunchOptions) -> Result<CommandOutput, String> {
+    run_blocking(engine.program.to_string_lossy().to_string(), args, options).await
+}
+
+/// Run an action on a container launcher with the detected engine
+pub async fn execute_action(
+    launcher: &Launcher,
+    action: ContainerAction,
+    options: &LaunchOptions,
+) -> Result<CommandOutput, String> {
+    let container = Container::for_launcher(launcher)?;
+    let engine = detect_engine(launcher.container.as_ref().and_then(|c| c.engine))?;
+    container.perform(&engine, action, options).await
+}
+
+/// Status of a container launcher with the detected engine
+pub async fn launcher_status(launcher: &Launcher) -> Result<ContainerStatus, String> {
+    let container = Container::for_launcher(launcher)?;
+    let engine = detect_engine(launcher.container.as_ref().and_then(|c| c.engine))?;
+    container.status(&engine).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launcher::LaunchType;
+
+    fn launcher(mode: ContainerMode, target: &str) -> Launcher {
+        let mut launcher = Launcher::new(
+            "my db".to_string(),
+            "My DB".to_string(),
+            LaunchType::Container,
+            target.to_string(),
+        );
+        launcher.container = Some(ContainerOptions {
+            mode,
+            ..ContainerOptions::default()
+        });
+        launcher
+    }
+
+    #[test]
+    fn test_parse_ps() {
+        let containers = parse_ps("web-1\trunning\ndb-1\tExited\n\nbroken line\n");
+        assert_eq!(containers.len(), 2);
+        assert!(containers[0].is_running());
+        assert_eq!(containers[1].state, "exited");
+        assert_eq!(ContainerStatus::from_containers(containers).state, ContainerState::Running);
+        assert_eq!(ContainerStatus::from_containers(Vec::new()).state, ContainerState::Absent);
+    }
+
+    #[test]
+    fn test_run_arguments() {
+        let container = Container::for_launcher(&launcher(ContainerMode::Image, "postgres:16")).unwrap();
+        assert_eq!(container.name, "initium-my-db");
+        let options = LaunchOptions {
+            ports: vec!["5432:5432".to_string()],
+            volumes: vec!["/srv/pg:/var/lib/postgresql/data".to_string()],
+            env_vars: Some(vec![("POSTGRES_PASSWORD".to_string(), "secret".to_string())]),
+            ..LaunchOptions::default()
+        };
+        assert_eq!(
+            container.run_arguments(&options),
+            vec![
+                "run", "-d", "--name", "initium-my-db",
+                "-p", "5432:5432",
+                "-v", "/srv/pg:/var/lib/postgresql/data",
+                "-e", "POSTGRES_PASSWORD=secret",
+                "postgres:16",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compose_arguments() {
+        let container = Container::for_launcher(&launcher(ContainerMode::Compose, "/home/me/My Stack")).unwrap();
+        assert_eq!(container.name, "my-stack");
+        assert_eq!(
+            container.compose_arguments(&["up", "-d"]),
+            vec!["compose", "--project-directory", "/home/me/My Stack", "-p", "my-stack", "up", "-d"]
+        );
+        assert_eq!(container.ps_arguments()[3], "label=com.docker.compose.project=my-stack");
+    }
+
+    #[cfg(unix)]
+    mod fake_cli {
+        use super::*;
+        use std::os::unix::fs::PermissionsExt;
+
+        /// Fake engine logging its arguments and listing `ps_output` for `ps`
+        fn fake_engine(dir: &Path, name: &str, ps_output: &str) {
+            let script = format!(
+                "#!/bin/sh\necho \"$@${{TAG:+ TAG=$TAG}}\" >> \"{}\"\nif [ \"$1\" = ps ]; then printf '{}'; fi\n",
+                dir.join("calls.log").display(),
+                ps_output
+            );
+            let path = dir.join(name);
+            std::fs::write(&path, script).unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        fn calls(dir: &Path) -> Vec<String> {
+            std::fs::read_to_string(dir.join("calls.log"))
+                .unwrap_or_default()
+                .lines()
+                .map(String::from)
+                .collect()
+        }
+
+        #[test]
+        fn test_detect_engine_prefers_podman() {
+            let dir = tempfile::tempdir().unwrap();
+            fake_engine(dir.path(), "docker", "");
+            let path = dir.path().as_os_str();
+            assert_eq!(detect_engine_in(path, None).unwrap().kind, EngineKind::Docker);
+            assert!(detect_engine_in(path, Some(EngineKind::Podman)).is_none());
+
+            fake_engine(dir.path(), "podman", "");
+            assert_eq!(detect_engine_in(path, None).unwrap().kind, EngineKind::Podman);
+        }
+
+        #[tokio::test]
+        async fn test_start_runs_then_restarts() {
+            let dir = tempfile::tempdir().unwrap();
+            fake_engine(dir.path(), "podman", "");
+            let engine = detect_engine_in(dir.path().as_os_str(), None).unwrap();
+            let container = Container::for_launcher(&launcher(ContainerMode::Image, "nginx")).unwrap();
+            let options = LaunchOptions::default();
+
+            let output = container.perform(&engine, ContainerAction::Start, &options).await.unwrap();
+            assert!(output.success);
+            assert_eq!(container.status(&engine).await.unwrap().state, ContainerState::Absent);
+            let log = calls(dir.path());
+            assert!(log[1].starts_with("run -d --name initium-my-db nginx"), "{:?}", log);
+
+            // An existing container is started again
+            fake_engine(dir.path(), "podman", "initium-my-db\\texited\\ninitium-my-db-2\\trunning\\n");
+            let status = container.status(&engine).await.unwrap();
+            assert_eq!(status.state, ContainerState::Stopped);
+            container.perform(&engine, ContainerAction::Start, &options).await.unwrap();
+            container.perform(&engine, ContainerAction::Stop, &options).await.unwrap();
+            let log = calls(dir.path());
+            assert_eq!(log[log.len() - 2], "start initium-my-db");
+            assert_eq!(log[log.len() - 1], "stop initium-my-db");
+        }
+
+        #[tokio::test]
+        async fn test_compose_actions() {
+            let dir = tempfile::tempdir().unwrap();
+            fake_engine(dir.path(), "docker", "stack-web-1\\trunning\\n");
+            let engine = detect_engine_in(dir.path().as_os_str(), None).unwrap();
+            let container = Container::for_launcher(&launcher(ContainerMode::Compose, "/srv/stack")).unwrap();
+            let options = LaunchOptions {
+                env_vars: Some(vec![("TAG".to_string(), "1.2".to_string())]),
+                ..LaunchOptions::default()
+            };
+
+            container.perform(&engine, ContainerAction::Start, &options).await.unwrap();
+            container.perform(&engine, ContainerAction::Logs, &options).await.unwrap();
+            container.perform(&engine, ContainerAction::Stop, &options).await.unwrap();
+            assert_eq!(container.status(&engine).await.unwrap().state, ContainerState::Running);
+            assert_eq!(
+                calls(dir.path())[..3],
+                [
+                    "compose --project-directory /srv/stack -p stack up -d TAG=1.2",
+                    "compose --project-directory /srv/stack -p stack logs --tail 200 TAG=1.2",
+                    "compose --project-directory /srv/stack -p stack down TAG=1.2",
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Fallback Target Module
+//!
+//! Lets a launcher try alternative targets in order, e.g. the `code` binary,
+//! then the `com.visualstudio.code` Flatpak, then vscode.dev in the browser.
+//! Features:
+//! - Targets resolved on PATH before spawning
+//! - Spawn errors and immediate non-zero exits reported as failures
+//! - Targets started with the terminal, directory and browser options of the launcher
+//! - First target that starts wins
+
+use crate::launcher::{LaunchOptions, LaunchType, Launcher};
+use crate::precondition::find_program;
+use crate::sandbox::HostCommand;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Time during which an exit with a non-zero status counts as a failed start
+pub const START_GRACE_MS: u64 = 500;
+
+/// Alternative target of a launcher
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FallbackTarget {
+    /// `app` or `web`
+    #[serde(rename = "type")]
+    pub launch_type: LaunchType,
+    /// Command line or URL
+    pub target: String,
+}
+
+/// Split a command line into words, honouring single and double quotes
+pub fn split_command_line(line: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote = None;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some('"'), '\\') => word.extend(chars.next()),
+            (Some(_), c) => word.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (None, '\\') => {
+                word.extend(chars.next());
+                in_word = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            (None, c) => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+/// Spawn a program and watch it during the grace period
+///
+/// Fails when the program cannot be spawned or exits with a non-zero
+/// status before `START_GRACE_MS`; a program still running is detached.
+pub async fn spawn_checked(program: &str, args: &[String], options: &LaunchOptions) -> Result<(), String> {
+    log::info!("Spawning: {} {:?}", program, args);
+
+    let env_vars = options.env_vars.clone().unwrap_or_default();
+    let mut cmd = HostCommand::new(program, args, &env_vars).command();
+    cmd.stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to spawn {}: {}", program, e))?;
+
+    let started = Instant::now();
+    while started.elapsed() < Duration::from_millis(START_GRACE_MS) {
+        match child.try_wait() {
+            Ok(Some(status)) if status.success() => return Ok(()),
+            Ok(Some(status)) => {
+                return Err(format!(
+                    "{} exited with status {}",
+                    program,
+                    status.code().unwrap_or(-1)
+                ))
+            }
+            Ok(None) => tokio::time::sleep(Duration::from_millis(25)).await,
+            Err(e) => return Err(format!("Failed to wait for {}: {}", program, e)),
+        }
+    }
+
+    // Reap the process when it exits to avoid zombies
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+/// Resolve and start one target of `launcher`
+///
+/// The target runs like the launcher itself would, with its terminal,
+/// directory and browser options; only the extra URLs of the primary target are dropped.
+pub async fn start_target(launcher: &Launcher, target: &FallbackTarget, options: &LaunchOptions) -> Result<(), String> {
+    match target.launch_type {
+        LaunchType::Web => {}
+        LaunchType::App => {
+            let program = split_command_line(&target.target)
+                .into_iter()
+                .next()
+                .ok_or("Empty target")?;
+            if find_program(&[program.as_str()]).is_none() {
+                return Err(format!("{} not found", program));
+            }
+        }
+        ref other => {
+            return Err(format!(
+                "{} targets cannot be used as fallbacks",
+                other.as_str()
+            ))
+        }
+    }
+
+    let mut candidate = launcher.clone();
+    candidate.fallbacks = None;
+    if candidate.launch_type != target.launch_type || candidate.target != target.target {
+        if let Some(web) = candidate.web.as_mut() {
+            web.urls.clear();
+        }
+    }
+    candidate.launch_type = target.launch_type.clone();
+    candidate.target = target.target.clone();
+    candidate.start(options).await
+}
+
+/// Start the primary target of a launcher, then its fallbacks, until one starts
+pub async fn execute_with_fallbacks(launcher: &Launcher, options: &LaunchOptions) -> Result<(), String> {
+    let primary = FallbackTarget {
+        launch_type: launcher.launch_type.clone(),
+        target: launcher.target.clone(),
+    };
+    let fallbacks = launcher.fallbacks.iter().flatten();
+
+    let mut failures = Vec::new();
+    for target in std::iter::once(&primary).chain(fallbacks) {
+        match start_target(launcher, target, options).await {
+            Ok(()) => {
+                log::info!("Launcher '{}' started with {}", launcher.name, target.target);
+                return Ok(());
+            }
+            Err(e) => {
+                log::warn!("Launcher '{}' target {} failed: {}", launcher.name, target.target, e);
+                failures.push(format!("{}: {}", target.target, e));
+            }
+        }
+    }
+    Err(format!("No target could be started ({})", failures.join("; ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(target: &str) -> FallbackTarget {
+        FallbackTarget {
+            launch_type: LaunchType::App,
+            target: target.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_split_command_line() {
+        assert_eq!(
+            split_command_line(r#"flatpak run  com.visualstudio.code"#),
+            vec!["flatpak", "run", "com.visualstudio.code"]
+        );
+        assert_eq!(
+            split_command_line(r#"'/opt/My App/app' --title "a \"b\"" c\ d ''"#),
+            vec!["/opt/My App/app", "--title", "a \"b\"", "c d", ""]
+        );
+    }
+
+    fn launcher() -> Launcher {
+        Launcher::new("editor".to_string(), "Editor".to_string(), LaunchType::App, String::new())
+    }
+
+    #[tokio::test]
+    async fn test_unresolved_target() {
+        let error = start_target(&launcher(), &app("initium-missing-binary --new-window"), &LaunchOptions::default())
+            .await
+            .unwrap_err();
+        assert_eq!(error, "initium-missing-binary not found");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_immediate_failure_is_reported() {
+        let error = start_target(&launcher(), &app("sh -c 'exit 4'"), &LaunchOptions::default())
+            .await
+            .unwrap_err();
+        assert!(error.ends_with("exited with status 4"), "{}", error);
+        assert!(start_target(&launcher(), &app("sleep 2"), &LaunchOptions::default()).await.is_ok());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_first_working_target_wins() {
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("started");
+        let mut launcher = Launcher::new(
+            "editor".to_string(),
+            "Editor".to_string(),
+            LaunchType::App,
+            "initium-missing-binary".to_string(),
+        );
+        launcher.fallbacks = Some(vec![
+            app("false"),
+            app(&format!("touch '{}'", marker.display())),
+            app("initium-never-reached"),
+        ]);
+
+        assert!(launcher.execute().await.is_ok());
+        assert!(marker.exists());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_fallback_uses_working_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut launcher = launcher();
+        launcher.target = "initium-missing-binary".to_string();
+        launcher.fallbacks = Some(vec![app("touch started")]);
+        launcher.options = Some(LaunchOptions {
+            working_dir: Some(dir.path().to_string_lossy().to_string()),
+            ..LaunchOptions::default()
+        });
+
+        assert!(launcher.execute().await.is_ok());
+        std::thread::sleep(Duration::from_millis(200));
+        assert!(dir.path().join("started").exists());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_all_targets_failing() {
+        let mut launcher = Launcher::new(
+            "editor".to_string(),
+            "Editor".to_string(),
+            LaunchType::App,
+            "initium-missing-binary".to_string(),
+        );
+        launcher.fallbacks = Some(vec![app("false")]);
+        let error = launcher.execute().await.unwrap_err();
+        assert!(error.starts_with("No target could be started (initium-missing-binary: "), "{}", error);
+        assert!(error.contains("false: "), "{}", error);
+    }
+}
@@ -0,0 +1,295 @@
+//! Group Launcher Module
+//!
+//! Group launchers open a whole workspace by executing other launchers.
+//! Features:
+//! - Ordered or parallel execution
+//! - Per-member start delay
+//! - Stop on first failure or continue (ordered mode)
+//! - Cycle detection when the configuration is saved
+
+use crate::launcher::{LaunchReport, LaunchType, Launcher};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Poll;
+use std::time::Duration;
+
+/// Execution mode of a group
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupMode {
+    /// Members run one after another
+    #[default]
+    Ordered,
+    /// Members start together
+    Parallel,
+}
+
+/// Member of a group launcher
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GroupMember {
+    /// Id of the member launcher
+    pub id: String,
+    /// Delay before starting this member, in milliseconds
+    #[serde(default)]
+    pub delay_ms: u64,
+}
+
+/// Group launcher options
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct GroupOptions {
+    /// Member launchers, in execution order
+    #[serde(default)]
+    pub members: Vec<GroupMember>,
+    /// Ordered or parallel execution
+    #[serde(default)]
+    pub mode: GroupMode,
+    /// Stop at the first failed member (ordered mode only)
+    #[serde(default)]
+    pub stop_on_failure: bool,
+}
+
+impl GroupOptions {
+    /// Ordered group of launcher ids without delays
+    pub fn ordered(ids: &[&str]) -> Self {
+        GroupOptions {
+            members: ids
+                .iter()
+                .map(|id| GroupMember {
+                    id: id.to_string(),
+                    delay_ms: 0,
+                })
+                .collect(),
+            mode: GroupMode::Ordered,
+            stop_on_failure: false,
+        }
+    }
+}
+
+type ReportFuture<'a> = Pin<Box<dyn Future<Output = LaunchReport> + Send + 'a>>;
+
+/// Execute the members of a group launcher
+///
+/// `stack` holds the ids of the groups being executed, to refuse cycles.
+pub(crate) async fn execute_group(
+    group: &Launcher,
+    launchers: &[Launcher],
+    stack: Vec<String>,
+) -> LaunchReport {
+    let Some(options) = &group.group else {
+        return LaunchReport::failure(group, format!("Group '{}' has no members", group.name));
+    };
+    if stack.contains(&group.id) {
+        return LaunchReport::failure(group, format!("Group cycle detected at '{}'", group.id));
+    }
+    let mut stack = stack;
+    stack.push(group.id.clone());
+
+    log::info!(
+        "Executing group '{}' ({} members, {:?})",
+        group.name,
+        options.members.len(),
+        options.mode
+    );
+
+    let members = match options.mode {
+        GroupMode::Ordered => {
+            let mut reports = Vec::new();
+            let mut failed = false;
+            for member in &options.members {
+                if failed && options.stop_on_failure {
+                    reports.push(LaunchReport::skipped(&member.id, "Skipped after previous failure"));
+                    continue;
+                }
+                let report = execute_member(member, launchers, stack.clone()).await;
+                failed |= !report.success;
+                reports.push(report);
+            }
+            reports
+        }
+        GroupMode::Parallel => {
+            let futures = options
+                .members
+                .iter()
+                .map(|member| execute_member(member, launchers, stack.clone()))
+                .collect();
+            join_all(futures).await
+        }
+    };
+
+    LaunchReport::group(group, members)
+}
+
+fn execute_member<'a>(
+    member: &'a GroupMember,
+    launchers: &'a [Launcher],
+    stack: Vec<String>,
+) -> ReportFuture<'a> {
+    Box::pin(async move {
+        if member.delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(member.delay_ms)).await;
+        }
+        match launchers.iter().find(|l| l.id == member.id) {
+            Some(launcher) => launcher.execute_tracked(launchers, stack).await,
+            None => LaunchReport::skipped(&member.id, &format!("Launcher not found: {}", member.id)),
+        }
+    })
+}
+
+/// Poll all member executions concurrently, keeping their order
+async fn join_all(mut futures: Vec<ReportFuture<'_>>) -> Vec<LaunchReport> {
+    let mut results: Vec<Option<LaunchReport>> = vec![None; futures.len()];
+    std::future::poll_fn(|cx| {
+        let mut pending = false;
+        for (future, result) in futures.iter_mut().zip(results.iter_mut()) {
+            if result.is_none() {
+                match future.as_mut().poll(cx) {
+                    Poll::Ready(report) => *result = Some(report),
+                    Poll::Pending => pending = true,
+                }
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    })
+    .await;
+    results.into_iter().flatten().collect()
+}
+
+/// Find a cycle between group launchers, returned as the ids along the cycle
+pub fn find_group_cycle(launchers: &[Launcher]) -> Option<Vec<String>> {
+    fn visit(
+        launcher: &Launcher,
+        launchers: &[Launcher],
+        path: &mut Vec<String>,
+        done: &mut Vec<String>,
+    ) -> Option<Vec<String>> {
+        if let Some(start) = path.iter().position(|id| *id == launcher.id) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(launcher.id.clone());
+            return Some(cycle);
+        }
+        if done.contains(&launcher.id) {
+            return None;
+        }
+        path.push(launcher.id.clone());
+        let members = launcher.group.iter().flat_map(|g| g.members.iter());
+        for member in members {
+            if let Some(next) = launchers.iter().find(|l| l.id == member.id) {
+                if let Some(cycle) = visit(next, launchers, path, done) {
+                    return Some(cycle);
+                }
+            }
+        }
+        path.pop();
+        done.push(launcher.id.clone());
+        None
+    }
+
+    let mut done = Vec::new();
+    launchers
+        .iter()
+        .filter(|l| l.launch_type == LaunchType::Group)
+        .find_map(|l| visit(l, launchers, &mut Vec::new(), &mut done))
+}
+
+/// Refuse configurations containing group cycles
+pub fn validate_groups(launchers: &[Launcher]) -> Result<(), String> {
+    match find_group_cycle(launchers) {
+        Some(cycle) => Err(format!("Group cycle detected: {}", cycle.join(" -> "))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(id: &str, options: GroupOptions) -> Launcher {
+        let mut launcher = Launcher::new(id.to_string(), id.to_string(), LaunchType::Group, String::new());
+        launcher.group = Some(options);
+        launcher
+    }
+
+    fn command(id: &str, command: &str) -> Launcher {
+        Launcher::new(id.to_string(), id.to_string(), LaunchType::Command, command.to_string())
+    }
+
+    #[test]
+    fn test_find_group_cycle() {
+        let launchers = vec![
+            group("a", GroupOptions::ordered(&["b", "mail"])),
+            group("b", GroupOptions::ordered(&["c"])),
+            group("c", GroupOptions::ordered(&["a"])),
+            command("mail", "true"),
+        ];
+        assert_eq!(find_group_cycle(&launchers), Some(vec!["a".into(), "b".into(), "c".into(), "a".into()]));
+        assert!(validate_groups(&launchers).unwrap_err().contains("a -> b -> c -> a"));
+    }
+
+    #[test]
+    fn test_self_reference_is_a_cycle() {
+        let launchers = vec![group("a", GroupOptions::ordered(&["a"]))];
+        assert!(validate_groups(&launchers).is_err());
+    }
+
+    #[test]
+    fn test_shared_members_are_not_a_cycle() {
+        let launchers = vec![
+            group("a", GroupOptions::ordered(&["b", "c"])),
+            group("b", GroupOptions::ordered(&["c"])),
+            group("c", GroupOptions::ordered(&["missing"])),
+        ];
+        assert!(validate_groups(&launchers).is_ok());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_ordered_group_reports_members() {
+        let launchers = vec![
+            group("morning", GroupOptions::ordered(&["ok", "fail", "missing"])),
+            command("ok", "echo ok"),
+            command("fail", "exit 2"),
+        ];
+        let report = launchers[0].execute_in(&launchers).await;
+        assert!(!report.success);
+        assert_eq!(report.members.len(), 3);
+        assert!(report.members[0].success);
+        assert!(!report.members[1].success);
+        assert!(report.members[2].error.as_deref().unwrap().contains("not found"));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_stop_on_failure_skips_remaining() {
+        let mut options = GroupOptions::ordered(&["fail", "ok"]);
+        options.stop_on_failure = true;
+        let launchers = vec![group("g", options), command("ok", "true"), command("fail", "false")];
+        let report = launchers[0].execute_in(&launchers).await;
+        assert_eq!(report.members[1].error.as_deref(), Some("Skipped after previous failure"));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_parallel_group_runs_concurrently() {
+        let mut options = GroupOptions::ordered(&["slow1", "slow2"]);
+        options.mode = GroupMode::Parallel;
+        options.members[1].delay_ms = 50;
+        let launchers = vec![group("g", options), command("slow1", "sleep 0.5"), command("slow2", "sleep 0.5")];
+        let started = std::time::Instant::now();
+        let report = launchers[0].execute_in(&launchers).await;
+        assert!(report.success, "{:?}", report);
+        assert_eq!(report.members[0].id, "slow1");
+        assert!(started.elapsed() < Duration::from_millis(950));
+    }
+
+    #[tokio::test]
+    async fn test_runtime_cycle_guard() {
+        let launchers = vec![group("a", GroupOptions::ordered(&["a"]))];
+        let report = launchers[0].execute_in(&launchers).await;
+        assert!(report.members[0].error.as_deref().unwrap().contains("cycle"));
+    }
+}
@@ -2,7 +2,19 @@
 //!
 //! Handles launching applications and opening URLs.
 //! Features:
-//! - Support for web URLs and applications
+//! - Support for web URLs, applications, short-lived commands and inline scripts
+//! - Group launchers executing other launchers, with per-member results
+//! - Chain launchers waiting for services to be ready between steps
+//! - Preconditions checked before spawning
+//! - Fallback targets tried in order when the primary target does not start
+//! - Parameters prompted at launch time and substituted as `{{name}}`
+//! - Web search launchers with keyword shortcuts
+//! - Web launchers opening several URLs in a selected browser and profile
+//! - CLI applications run in a terminal emulator
+//! - SSH sessions opened in a terminal
+//! - Container images and compose projects run with podman or docker
+//! - Flatpak and Snap applications started by id
+//! - Spawns sent to the host when Initium runs sandboxed
 //! - Cross-platform execution (Linux, Windows, macOS)
 //! - Command-line arguments support
 //! - Environment variables support
@@ -10,7 +22,19 @@
 //! - Comprehensive error handling and logging
 //! - Uses shell execution for compatibility with Tauri
 
+use crate::browser::WebOptions;
+use crate::chain::ChainStep;
+use crate::container::ContainerOptions;
+use crate::fallback::FallbackTarget;
+use crate::group::GroupOptions;
+use crate::parameter::Parameter;
+use crate::precondition::Precondition;
+use crate::project::ProjectLink;
+use crate::ssh::SshOptions;
+use crate::system::command_runner::{platform_runner, CommandOutput, OSCommandRunner};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::pin::Pin;
 
 /// Type of launcher target
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -20,10 +44,68 @@ pub enum LaunchType {
     Web,
     /// Application launcher
     App,
+    /// Short-lived command whose output is captured
+    Command,
+    /// Inline script stored in the config, output captured
+    Script,
+    /// Group of other launchers
+    Group,
+    /// Steps gated by readiness probes
+    Chain,
+    /// Web search, target is a URL template with `%s`
+    Search,
+    /// SSH session in a terminal, target is the host
+    Ssh,
+    /// Container image or compose project directory
+    Container,
+    /// Flatpak application, target is the application id
+    Flatpak,
+    /// Snap application, target is the snap command
+    Snap,
+}
+
+impl LaunchType {
+    /// Name used in the configuration and by the frontend
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LaunchType::Web => "web",
+            LaunchType::App => "app",
+            LaunchType::Command => "command",
+            LaunchType::Script => "script",
+            LaunchType::Group => "group",
+            LaunchType::Chain => "chain",
+            LaunchType::Search => "search",
+            LaunchType::Ssh => "ssh",
+            LaunchType::Container => "container",
+            LaunchType::Flatpak => "flatpak",
+            LaunchType::Snap => "snap",
+        }
+    }
+}
+
+impl std::str::FromStr for LaunchType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "web" => Ok(LaunchType::Web),
+            "app" => Ok(LaunchType::App),
+            "command" => Ok(LaunchType::Command),
+            "script" => Ok(LaunchType::Script),
+            "group" => Ok(LaunchType::Group),
+            "chain" => Ok(LaunchType::Chain),
+            "search" => Ok(LaunchType::Search),
+            "ssh" => Ok(LaunchType::Ssh),
+            "container" => Ok(LaunchType::Container),
+            "flatpak" => Ok(LaunchType::Flatpak),
+            "snap" => Ok(LaunchType::Snap),
+            other => Err(format!("Unknown launcher type: {}", other)),
+        }
+    }
 }
 
 /// Launch options with arguments, timeout, and environment variables
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct LaunchOptions {
     /// Command-line arguments
     #[serde(default)]
@@ -34,6 +116,21 @@ pub struct LaunchOptions {
     /// Optional environment variables
     #[serde(default)]
     pub env_vars: Option<Vec<(String, String)>>,
+    /// Run the application in the configured terminal emulator
+    #[serde(default)]
+    pub terminal: bool,
+    /// Keep the terminal open after the command exits
+    #[serde(default)]
+    pub hold: bool,
+    /// Published ports of container launchers (`8080:80`)
+    #[serde(default)]
+    pub ports: Vec<String>,
+    /// Mounted volumes of container launchers (`/srv/data:/data`)
+    #[serde(default)]
+    pub volumes: Vec<String>,
+    /// Directory the application starts in
+    #[serde(default)]
+    pub working_dir: Option<String>,
 }
 
 fn default_timeout() -> u64 {
@@ -46,12 +143,127 @@ impl Default for LaunchOptions {
             args: Vec::new(),
             timeout_secs: 30,
             env_vars: None,
+            terminal: false,
+            hold: false,
+            ports: Vec::new(),
+            volumes: Vec::new(),
+            working_dir: None,
+        }
+    }
+}
+
+/// Result of a launcher execution, with per-member results for groups
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LaunchReport {
+    /// Launcher id
+    pub id: String,
+    /// Launcher display name
+    pub name: String,
+    /// Whether the launch (and every group member) succeeded
+    pub success: bool,
+    /// Failure description
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Captured output of command and script launchers
+    #[serde(default)]
+    pub output: Option<CommandOutput>,
+    /// Member results of group launchers, in group order
+    #[serde(default)]
+    pub members: Vec<LaunchReport>,
+}
+
+impl LaunchReport {
+    /// Build the report of a single launcher execution
+    ///
+    /// Command and script launchers that could not run (timeout, refused launch)
+    /// get a failed output holding the error, so their history keeps a trace.
+    pub fn from_result(launcher: &Launcher, result: Result<Option<CommandOutput>, String>) -> Self {
+        let (success, error, output) = match result {
+            Err(e) if matches!(launcher.launch_type, LaunchType::Command | LaunchType::Script) => {
+                let output = CommandOutput {
+                    status_code: -1,
+                    stdout: String::new(),
+                    stderr: e.clone(),
+                    success: false,
+                    duration_ms: 0,
+                };
+                (false, Some(e), Some(output))
+            }
+            Ok(Some(output)) if !output.success => {
+                let error = format!(
+                    "Command failed with status {}: {}",
+                    output.status_code,
+                    output.stderr.trim()
+                );
+                (false, Some(error), Some(output))
+            }
+            Ok(output) => (true, None, output),
+            Err(e) => (false, Some(e), None),
+        };
+        LaunchReport {
+            id: launcher.id.clone(),
+            name: launcher.name.clone(),
+            success,
+            error,
+            output,
+            members: Vec::new(),
+        }
+    }
+
+    /// Report of a launcher that failed before running
+    pub fn failure(launcher: &Launcher, error: String) -> Self {
+        LaunchReport::from_result(launcher, Err(error))
+    }
+
+    /// Report of a group member that was not run
+    pub fn skipped(id: &str, reason: &str) -> Self {
+        LaunchReport {
+            id: id.to_string(),
+            name: id.to_string(),
+            success: false,
+            error: Some(reason.to_string()),
+            output: None,
+            members: Vec::new(),
+        }
+    }
+
+    /// Report of a group, successful when every member succeeded
+    pub fn group(launcher: &Launcher, members: Vec<LaunchReport>) -> Self {
+        let failed: Vec<&str> = members
+            .iter()
+            .filter(|m| !m.success)
+            .map(|m| m.name.as_str())
+            .collect();
+        let error = (!failed.is_empty()).then(|| {
+            format!(
+                "{} of {} members failed: {}",
+                failed.len(),
+                members.len(),
+                failed.join(", ")
+            )
+        });
+        LaunchReport {
+            id: launcher.id.clone(),
+            name: launcher.name.clone(),
+            success: failed.is_empty(),
+            error,
+            output: None,
+            members,
+        }
+    }
+
+    /// Ok when successful, the failure description otherwise
+    pub fn result(&self) -> Result<(), String> {
+        match &self.error {
+            Some(error) if !self.success => Err(error.clone()),
+            _ if !self.success => Err(format!("Launcher '{}' failed", self.name)),
+            _ => Ok(()),
         }
     }
 }
 
 /// Launcher configuration item
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Launcher {
     /// Unique identifier
     pub id: String,
@@ -60,11 +272,47 @@ pub struct Launcher {
     /// Type of launcher
     #[serde(rename = "type")]
     pub launch_type: LaunchType,
-    /// Target URL or application path
+    /// Target URL or application path (interpreter for script launchers)
     pub target: String,
+    /// Inline script body for script launchers
+    #[serde(default)]
+    pub script: Option<String>,
     /// Optional icon path
     #[serde(default)]
     pub icon: Option<String>,
+    /// Optional category used to group launchers
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Members and mode of group launchers
+    #[serde(default)]
+    pub group: Option<GroupOptions>,
+    /// Steps of chain launchers
+    #[serde(default)]
+    pub chain: Option<Vec<ChainStep>>,
+    /// Checks evaluated before the launch
+    #[serde(default)]
+    pub preconditions: Option<Vec<Precondition>>,
+    /// Alternative targets tried when the primary target does not start
+    #[serde(default)]
+    pub fallbacks: Option<Vec<FallbackTarget>>,
+    /// Parameters substituted into the target and arguments
+    #[serde(default)]
+    pub parameters: Option<Vec<Parameter>>,
+    /// Extra URLs and browser selection of web launchers
+    #[serde(default)]
+    pub web: Option<WebOptions>,
+    /// Connection options of SSH launchers
+    #[serde(default)]
+    pub ssh: Option<SshOptions>,
+    /// Mode, name and engine of container launchers
+    #[serde(default)]
+    pub container: Option<ContainerOptions>,
+    /// Desktop file id embedded in the AppImage of the target, to follow newer versions
+    #[serde(default)]
+    pub appimage: Option<String>,
+    /// Repository and action of launchers generated by project discovery
+    #[serde(default)]
+    pub project: Option<ProjectLink>,
     /// Launch options (arguments, timeout, env vars)
     #[serde(default)]
     pub options: Option<LaunchOptions>,
@@ -78,7 +326,19 @@ impl Launcher {
             name,
             launch_type,
             target,
+            script: None,
             icon: None,
+            category: None,
+            group: None,
+            chain: None,
+            preconditions: None,
+            fallbacks: None,
+            parameters: None,
+            web: None,
+            ssh: None,
+            container: None,
+            appimage: None,
+            project: None,
             options: None,
         }
     }
@@ -92,58 +352,276 @@ impl Launcher {
         options: LaunchOptions,
     ) -> Self {
         Launcher {
-            id,
-            name,
-            launch_type,
-            target,
-            icon: None,
             options: Some(options),
+            ..Launcher::new(id, name, launch_type, target)
         }
     }
 
+    /// Web launcher displayed in a dedicated Initium window
+    pub fn is_embedded(&self) -> bool {
+        self.launch_type == LaunchType::Web
+            && self
+                .web
+                .as_ref()
+                .is_some_and(|w| w.display == crate::browser::WebDisplay::Embedded)
+    }
+
+    /// Icon usable outside the webview (file path or icon name, not a data URL)
+    pub fn icon_name(&self) -> Option<&str> {
+        self.icon
+            .as_deref()
+            .filter(|icon| !icon.is_empty() && !icon.starts_with("data:"))
+    }
+
+    /// Check whether every search term appears in the name, id or target (case-insensitive)
+    pub fn matches_terms(&self, terms: &[&str]) -> bool {
+        let haystack = format!("{} {} {}", self.name, self.id, self.target).to_lowercase();
+        terms
+            .iter()
+            .all(|term| haystack.contains(&term.to_lowercase()))
+    }
+
     /// Execute the launcher
+    ///
+    /// Command launchers fail when the command exits with a non-zero status.
+    /// Group members are not resolved; use `execute_in` for groups.
     pub async fn execute(&self) -> Result<(), String> {
+        self.execute_in(&[]).await.result()
+    }
+
+    /// Execute the launcher, resolving group members among `launchers`
+    pub async fn execute_in(&self, launchers: &[Launcher]) -> LaunchReport {
+        self.execute_tracked(launchers, Vec::new()).await
+    }
+
+    /// Execute the launcher; `stack` holds the ids of the enclosing groups
+    ///
+    /// Entry points that can ask for parameter values substitute them first with
+    /// `with_parameters`; otherwise the defaults are used and a required parameter
+    /// without default refuses the launch.
+    pub(crate) fn execute_tracked<'a>(
+        &'a self,
+        launchers: &'a [Launcher],
+        stack: Vec<String>,
+    ) -> Pin<Box<dyn Future<Output = LaunchReport> + Send + 'a>> {
+        Box::pin(async move {
+            if self.parameters.is_some() {
+                return match self.with_parameters(&Default::default()) {
+                    Ok(launcher) => launcher.execute_tracked(launchers, stack).await,
+                    Err(e) => {
+                        log::error!("Launcher '{}' not started, its parameters need values: {}", self.name, e);
+                        LaunchReport::failure(self, e)
+                    }
+                };
+            }
+            if let Some(preconditions) = &self.preconditions {
+                if let Err(e) = crate::precondition::check_all(preconditions).await {
+                    log::error!("Launcher '{}' not started: {}", self.name, e);
+                    return LaunchReport::failure(self, e);
+                }
+            }
+            match self.launch_type {
+                LaunchType::Group => crate::group::execute_group(self, launchers, stack).await,
+                LaunchType::Chain => crate::chain::execute_chain(self).await,
+                _ => LaunchReport::from_result(self, self.execute_with_output().await),
+            }
+        })
+    }
+
+    /// Execute the launcher, returning the captured output of command launchers
+    pub async fn execute_with_output(&self) -> Result<Option<CommandOutput>, String> {
         let options = self.options.clone().unwrap_or_default();
         match self.launch_type {
-            LaunchType::Web => execute_url(&self.target, &options).await,
-            LaunchType::App => execute_app(&self.target, &options).await,
+            LaunchType::Group | LaunchType::Chain => Err(format!(
+                "Launcher '{}' must be executed with execute_in",
+                self.name
+            )),
+            LaunchType::Web | LaunchType::App if self.fallbacks.as_ref().is_some_and(|f| !f.is_empty()) => {
+                crate::fallback::execute_with_fallbacks(self, &options).await.map(|_| None)
+            }
+            LaunchType::Web | LaunchType::App => self.start(&options).await.map(|_| None),
+            // The terms come with the query, see `search::execute_search`
+            LaunchType::Search => Err(format!(
+                "Search launcher '{}' needs search terms; open it through search_web with a query",
+                self.name
+            )),
+            LaunchType::Container => {
+                crate::container::execute_action(self, crate::container::ContainerAction::Start, &options)
+                    .await
+                    .map(Some)
+            }
+            LaunchType::Flatpak | LaunchType::Snap => {
+                crate::package::execute_package(self, &options).await.map(|_| None)
+            }
+            LaunchType::Ssh => crate::ssh::open_ssh_session(self, &options).await.map(|_| None),
+            LaunchType::Command => execute_command(&self.target, &options).await.map(Some),
+            LaunchType::Script => {
+                let script = self
+                    .script
+                    .as_deref()
+                    .ok_or_else(|| format!("Launcher '{}' has no script", self.name))?;
+                execute_script(script, &self.target, &options).await.map(Some)
+            }
+        }
+    }
+
+    /// Start a web or application launcher with its browser, terminal and directory options
+    pub(crate) async fn start(&self, options: &LaunchOptions) -> Result<(), String> {
+        match self.launch_type {
+            LaunchType::Web if self.web.is_some() => crate::browser::open_web_launcher(self, options).await,
+            LaunchType::Web => execute_url(&self.target, options).await,
+            LaunchType::App if options.terminal => crate::terminal::execute_in_terminal(&self.target, options).await,
+            LaunchType::App => execute_app(&self.target, options).await,
+            ref other => Err(format!("{} launchers cannot be started directly", other.as_str())),
         }
     }
 }
 
-/// Open a URL with platform-specific implementation (Linux)
-#[cfg(target_os = "linux")]
-pub async fn execute_url(url: &str, options: &LaunchOptions) -> Result<(), String> {
-    log::info!("Opening URL (Linux): {}", url);
+/// Run a short-lived command through the platform command runner and capture its output
+///
+/// On Unix the command line runs through `sh -c`, with the launcher arguments
+/// available as `$1`, `$2`, ...
+pub async fn execute_command(command: &str, options: &LaunchOptions) -> Result<CommandOutput, String> {
+    log::info!("Running command: {}", command);
+
+    #[cfg(target_os = "windows")]
+    let (program, args) = (command.to_string(), options.args.clone());
+    #[cfg(not(target_os = "windows"))]
+    let (program, args) = {
+        let mut args = vec!["-c".to_string(), command.to_string(), "sh".to_string()];
+        args.extend(options.args.iter().cloned());
+        ("sh".to_string(), args)
+    };
+
+    run_blocking(program, args, options).await
+}
+
+/// Default interpreter of scripts without shebang or explicit interpreter
+#[cfg(target_os = "windows")]
+const DEFAULT_SCRIPT_INTERPRETER: &str = "powershell -NoProfile -ExecutionPolicy Bypass -File";
+#[cfg(not(target_os = "windows"))]
+const DEFAULT_SCRIPT_INTERPRETER: &str = "sh";
+
+/// Resolve the interpreter command of an inline script
+///
+/// Priority: explicit interpreter, then the script shebang, then the platform default.
+pub fn script_interpreter(script: &str, interpreter: &str) -> Vec<String> {
+    let shebang = script
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("#!"))
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+    let command = if !interpreter.trim().is_empty() {
+        interpreter
+    } else {
+        shebang.unwrap_or(DEFAULT_SCRIPT_INTERPRETER)
+    };
+    command.split_whitespace().map(String::from).collect()
+}
 
-    let mut cmd_line = format!("xdg-open '{}'", url);
-    for arg in &options.args {
-        cmd_line.push(' ');
-        cmd_line.push_str(&format!("'{}'", arg));
+/// Run an inline script through the platform command runner and capture its output
+///
+/// The interpreter reads the script from its stdin through `/dev/stdin`, followed by the
+/// launcher arguments, so no script file is left where other users or the host could miss it.
+#[cfg(not(target_os = "windows"))]
+pub async fn execute_script(
+    script: &str,
+    interpreter: &str,
+    options: &LaunchOptions,
+) -> Result<CommandOutput, String> {
+    let mut command = script_interpreter(script, interpreter);
+    if command.is_empty() {
+        return Err("Script interpreter is empty".to_string());
     }
+    let program = command.remove(0);
 
-    log::info!("Spawning: sh -c '{}'", cmd_line);
+    log::info!("Running script with {}", program);
 
-    let mut cmd = std::process::Command::new("sh");
-    cmd.arg("-c").arg(&cmd_line);
+    let mut args = command;
+    args.push("/dev/stdin".to_string());
+    args.extend(options.args.iter().cloned());
 
-    if let Some(env_vars) = &options.env_vars {
-        for (key, value) in env_vars {
-            cmd.env(key, value);
-        }
+    run_blocking_with_input(program, args, Some(script.to_string()), options).await
+}
+
+/// Run an inline script through the platform command runner and capture its output
+///
+/// cmd and PowerShell only run script files: the script is written to a private
+/// temporary file passed to the interpreter, followed by the launcher arguments.
+#[cfg(target_os = "windows")]
+pub async fn execute_script(
+    script: &str,
+    interpreter: &str,
+    options: &LaunchOptions,
+) -> Result<CommandOutput, String> {
+    let mut command = script_interpreter(script, interpreter);
+    if command.is_empty() {
+        return Err("Script interpreter is empty".to_string());
     }
+    let program = command.remove(0);
 
-    match cmd.spawn() {
-        Ok(_) => {
-            log::info!("URL launcher spawned successfully");
-            Ok(())
-        }
-        Err(e) => {
-            let msg = format!("Failed to spawn launcher: {}", e);
-            log::error!("{}", msg);
-            Err(msg)
+    let extension = if program.to_lowercase().starts_with("powershell") { ".ps1" } else { ".cmd" };
+    let script_file = tempfile::Builder::new()
+        .prefix("initium-script-")
+        .suffix(extension)
+        .tempfile()
+        .map_err(|e| format!("Failed to create script file: {}", e))?;
+    std::fs::write(script_file.path(), script)
+        .map_err(|e| format!("Failed to write script file: {}", e))?;
+
+    log::info!("Running script with {}: {}", program, script_file.path().display());
+
+    let mut args = command;
+    args.push(script_file.path().to_string_lossy().to_string());
+    args.extend(options.args.iter().cloned());
+
+    run_blocking(program, args, options).await
+}
+
+/// Run a program through the platform command runner on a blocking thread
+pub(crate) async fn run_blocking(
+    program: String,
+    args: Vec<String>,
+    options: &LaunchOptions,
+) -> Result<CommandOutput, String> {
+    run_blocking_with_input(program, args, None, options).await
+}
+
+/// Run a program on a blocking thread, writing `input` to its stdin when set
+async fn run_blocking_with_input(
+    program: String,
+    args: Vec<String>,
+    input: Option<String>,
+    options: &LaunchOptions,
+) -> Result<CommandOutput, String> {
+    let env_vars = options.env_vars.clone().unwrap_or_default();
+    let timeout_secs = options.timeout_secs;
+
+    let host = crate::sandbox::HostCommand::new(&program, &args, &env_vars);
+    tokio::task::spawn_blocking(move || {
+        let args: Vec<&str> = host.args.iter().map(String::as_str).collect();
+        match input {
+            Some(input) => platform_runner().execute_with_input(&host.program, &args, &host.env_vars, &input, timeout_secs),
+            None => platform_runner().execute_with_env(&host.program, &args, &host.env_vars, timeout_secs),
         }
+    })
+    .await
+    .map_err(|e| format!("Command task failed: {}", e))?
+}
+
+/// Open a URL with platform-specific implementation (Linux)
+#[cfg(target_os = "linux")]
+pub async fn execute_url(url: &str, options: &LaunchOptions) -> Result<(), String> {
+    log::info!("Opening URL (Linux): {}", url);
+
+    if crate::sandbox::is_sandboxed() {
+        return crate::sandbox::open_uri(url).await;
     }
+
+    let mut args = vec![url.to_string()];
+    args.extend(options.args.iter().cloned());
+    crate::fallback::spawn_checked("xdg-open", &args, options).await
 }
 
 /// Open a URL with platform-specific implementation (Windows)
@@ -182,81 +660,40 @@ pub async fn execute_url(url: &str, options: &LaunchOptions) -> Result<(), Strin
 pub async fn execute_url(url: &str, options: &LaunchOptions) -> Result<(), String> {
     log::info!("Opening URL (macOS): {}", url);
 
-    let mut cmd_line = format!("open '{}'", url);
-    for arg in &options.args {
-        cmd_line.push(' ');
-        cmd_line.push_str(&format!("'{}'", arg));
-    }
-
-    log::info!("Spawning: sh -c '{}'", cmd_line);
-
-    let mut cmd = std::process::Command::new("sh");
-    cmd.arg("-c").arg(&cmd_line);
-
-    if let Some(env_vars) = &options.env_vars {
-        for (key, value) in env_vars {
-            cmd.env(key, value);
-        }
-    }
-
-    match cmd.spawn() {
-        Ok(_) => {
-            log::info!("URL launcher spawned successfully");
-            Ok(())
-        }
-        Err(e) => {
-            let msg = format!("Failed to spawn launcher: {}", e);
-            log::error!("{}", msg);
-            Err(msg)
-        }
-    }
+    let mut args = vec![url.to_string()];
+    args.extend(options.args.iter().cloned());
+    crate::fallback::spawn_checked("open", &args, options).await
 }
 
 /// Execute an application with platform-specific implementation (Linux)
+///
+/// The command line runs through `bash -c`; a command exiting with an error
+/// right away, e.g. a missing binary, is reported as a failed launch.
 #[cfg(target_os = "linux")]
 pub async fn execute_app(path: &str, options: &LaunchOptions) -> Result<(), String> {
     log::info!("Executing app (Linux): {}", path);
 
-    // Construire la commande complète avec nohup et & pour détacher
-    let mut cmd_line = format!("nohup {} > /dev/null 2>&1 &", path);
-    for arg in &options.args {
-        cmd_line.insert_str(cmd_line.len() - 2, &format!(" '{}'", arg));
+    let mut cmd_line = crate::terminal::command_line(path, &options.args);
+    if let Some(dir) = &options.working_dir {
+        cmd_line.insert_str(0, &format!("cd {} && ", crate::parameter::shell_quote(dir)));
     }
 
     log::info!("Spawning: bash -c '{}'", cmd_line);
 
-    let mut cmd = std::process::Command::new("bash");
-    cmd.arg("-c").arg(&cmd_line);
-
-    // Passer le PATH et autres variables d'environnement système
-    if let Ok(path_env) = std::env::var("PATH") {
-        cmd.env("PATH", path_env);
-    }
-    if let Ok(home) = std::env::var("HOME") {
-        cmd.env("HOME", home);
-    }
-    if let Ok(user) = std::env::var("USER") {
-        cmd.env("USER", user);
-    }
-
-    // Ajouter les LaunchOptions env vars
-    if let Some(env_vars) = &options.env_vars {
-        for (key, value) in env_vars {
-            cmd.env(key, value);
-        }
-    }
-
-    match cmd.spawn() {
-        Ok(_) => {
-            log::info!("App launcher spawned successfully");
-            Ok(())
-        }
-        Err(e) => {
-            let msg = format!("Failed to spawn launcher: {}", e);
+    // Sur l'hôte via flatpak-spawn quand Initium est sandboxé, avec les LaunchOptions env vars
+    crate::fallback::spawn_checked("bash", &["-c".to_string(), cmd_line], options)
+        .await
+        .map_err(|e| {
+            let msg = format!("Failed to start {}: {}", path, e);
             log::error!("{}", msg);
-            Err(msg)
-        }
-    }
+            msg
+        })
+}
+
+/// Quote a value as a PowerShell single-quoted string
+#[cfg(target_os = "windows")]
+fn powershell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
 }
 
 /// Execute an application with platform-specific implementation (Windows)
@@ -266,10 +703,23 @@ pub async fn execute_app(path: &str, options: &LaunchOptions) -> Result<(), Stri
 
     log::info!("Spawning: powershell Start-Process '{}'", path);
 
+    let mut start_process = format!("Start-Process {}", powershell_quote(path));
+    if !options.args.is_empty() {
+        // Start-Process joins the arguments with spaces, each one is quoted for the program first
+        let args: Vec<String> = options
+            .args
+            .iter()
+            .map(|arg| powershell_quote(&crate::parameter::shell_quote(arg)))
+            .collect();
+        start_process.push_str(&format!(" -ArgumentList {}", args.join(",")));
+    }
+    if let Some(dir) = &options.working_dir {
+        start_process.push_str(&format!(" -WorkingDirectory {}", powershell_quote(dir)));
+    }
     let mut cmd = std::process::Command::new("powershell");
     cmd.arg("-WindowStyle").arg("Hidden")
        .arg("-Command")
-       .arg(format!("Start-Process '{}'", path));
+       .arg(start_process);
 
     // Passer les variables d'environnement système
     if let Ok(path_env) = std::env::var("PATH") {
@@ -299,49 +749,27 @@ pub async fn execute_app(path: &str, options: &LaunchOptions) -> Result<(), Stri
 }
 
 /// Execute an application with platform-specific implementation (macOS)
+///
+/// The command line runs through `bash -c`; a command exiting with an error
+/// right away, e.g. a missing binary, is reported as a failed launch.
 #[cfg(target_os = "macos")]
 pub async fn execute_app(path: &str, options: &LaunchOptions) -> Result<(), String> {
     log::info!("Executing app (macOS): {}", path);
 
-    // Utiliser 'open' pour macOS avec détachement en arrière-plan
-    let mut cmd_line = format!("nohup {} > /dev/null 2>&1 &", path);
-    for arg in &options.args {
-        cmd_line.insert_str(cmd_line.len() - 2, &format!(" '{}'", arg));
+    let mut cmd_line = crate::terminal::command_line(path, &options.args);
+    if let Some(dir) = &options.working_dir {
+        cmd_line.insert_str(0, &format!("cd {} && ", crate::parameter::shell_quote(dir)));
     }
 
     log::info!("Spawning: bash -c '{}'", cmd_line);
 
-    let mut cmd = std::process::Command::new("bash");
-    cmd.arg("-c").arg(&cmd_line);
-
-    // Passer les variables d'environnement système
-    if let Ok(path_env) = std::env::var("PATH") {
-        cmd.env("PATH", path_env);
-    }
-    if let Ok(home) = std::env::var("HOME") {
-        cmd.env("HOME", home);
-    }
-    if let Ok(user) = std::env::var("USER") {
-        cmd.env("USER", user);
-    }
-
-    if let Some(env_vars) = &options.env_vars {
-        for (key, value) in env_vars {
-            cmd.env(key, value);
-        }
-    }
-
-    match cmd.spawn() {
-        Ok(_) => {
-            log::info!("App launcher spawned successfully");
-            Ok(())
-        }
-        Err(e) => {
-            let msg = format!("Failed to spawn launcher: {}", e);
+    crate::fallback::spawn_checked("bash", &["-c".to_string(), cmd_line], options)
+        .await
+        .map_err(|e| {
+            let msg = format!("Failed to start {}: {}", path, e);
             log::error!("{}", msg);
-            Err(msg)
-        }
-    }
+            msg
+        })
 }
 
 /// Generate a URL-friendly slug from a name
@@ -417,6 +845,11 @@ mod tests {
             args: vec!["arg1".to_string(), "arg2".to_string()],
             timeout_secs: 60,
             env_vars: Some(vec![("KEY".to_string(), "value".to_string())]),
+            terminal: false,
+            hold: false,
+            ports: Vec::new(),
+            volumes: Vec::new(),
+            working_dir: None,
         };
         let launcher = Launcher::with_options(
             "test".to_string(),
@@ -434,11 +867,37 @@ mod tests {
             args: vec!["-c".to_string(), "echo test".to_string()],
             timeout_secs: 5,
             env_vars: None,
+            terminal: false,
+            hold: false,
+            ports: Vec::new(),
+            volumes: Vec::new(),
+            working_dir: None,
         };
         let result = execute_app("sh", &options).await;
         assert!(result.is_ok(), "Failed: {:?}", result);
     }
 
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_execute_app_quotes_args() {
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("injected");
+        let options = LaunchOptions {
+            args: vec!["-c".to_string(), "true".to_string(), format!("'; touch {}; '", marker.display())],
+            ..LaunchOptions::default()
+        };
+        assert!(execute_app("sh", &options).await.is_ok());
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        assert!(!marker.exists());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_execute_app_reports_missing_binary() {
+        let result = execute_app("initium-missing-binary", &LaunchOptions::default()).await;
+        assert!(result.unwrap_err().contains("exited with status 127"));
+    }
+
     #[tokio::test]
     async fn test_launcher_execute_web() {
         let launcher = Launcher::new(
@@ -469,11 +928,176 @@ mod tests {
             args: vec!["-c".to_string(), "echo $TEST_VAR".to_string()],
             timeout_secs: 5,
             env_vars: Some(vec![("TEST_VAR".to_string(), "hello".to_string())]),
+            terminal: false,
+            hold: false,
+            ports: Vec::new(),
+            volumes: Vec::new(),
+            working_dir: None,
         };
         let result = execute_app("sh", &options).await;
         assert!(result.is_ok(), "Failed: {:?}", result);
     }
 
+    #[test]
+    fn test_icon_name_skips_data_urls() {
+        let mut launcher = Launcher::new(
+            "test".to_string(),
+            "Test".to_string(),
+            LaunchType::App,
+            "sh".to_string(),
+        );
+        launcher.icon = Some("data:image/png;base64,AAAA".to_string());
+        assert_eq!(launcher.icon_name(), None);
+        launcher.icon = Some("/usr/share/icons/test.png".to_string());
+        assert_eq!(launcher.icon_name(), Some("/usr/share/icons/test.png"));
+    }
+
+    #[test]
+    fn test_matches_terms() {
+        let launcher = Launcher::new(
+            "github".to_string(),
+            "GitHub".to_string(),
+            LaunchType::Web,
+            "https://github.com".to_string(),
+        );
+        assert!(launcher.matches_terms(&["git"]));
+        assert!(launcher.matches_terms(&["GIT", "hub"]));
+        assert!(launcher.matches_terms(&[]));
+        assert!(!launcher.matches_terms(&["git", "lab"]));
+    }
+
+    #[test]
+    fn test_launch_type_names() {
+        for launch_type in [
+            LaunchType::Web,
+            LaunchType::App,
+            LaunchType::Command,
+            LaunchType::Script,
+            LaunchType::Group,
+            LaunchType::Chain,
+            LaunchType::Search,
+        ] {
+            assert_eq!(launch_type.as_str().parse::<LaunchType>(), Ok(launch_type));
+        }
+        assert!("ftp".parse::<LaunchType>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_search_launcher_needs_terms() {
+        let launcher = Launcher::new(
+            "ddg".to_string(),
+            "DuckDuckGo".to_string(),
+            LaunchType::Search,
+            "https://duckduckgo.com/?q=%s".to_string(),
+        );
+        let error = launcher.execute().await.unwrap_err();
+        assert!(error.contains("search_web"));
+    }
+
+    #[tokio::test]
+    async fn test_command_launcher_captures_output() {
+        let options = LaunchOptions {
+            args: vec!["world".to_string()],
+            timeout_secs: 5,
+            env_vars: None,
+            terminal: false,
+            hold: false,
+            ports: Vec::new(),
+            volumes: Vec::new(),
+            working_dir: None,
+        };
+        let launcher = Launcher::with_options(
+            "hello".to_string(),
+            "Hello".to_string(),
+            LaunchType::Command,
+            "echo hello $1".to_string(),
+            options,
+        );
+        let output = launcher.execute_with_output().await.unwrap().unwrap();
+        assert!(output.success);
+        assert_eq!(output.stdout.trim(), "hello world");
+    }
+
+    #[tokio::test]
+    async fn test_command_launcher_failure() {
+        let launcher = Launcher::new(
+            "fail".to_string(),
+            "Fail".to_string(),
+            LaunchType::Command,
+            "echo broken >&2; exit 3".to_string(),
+        );
+        let output = launcher.execute_with_output().await.unwrap().unwrap();
+        assert_eq!(output.status_code, 3);
+        assert_eq!(output.stderr.trim(), "broken");
+        let error = launcher.execute().await.unwrap_err();
+        assert!(error.contains("status 3"));
+    }
+
+    #[test]
+    fn test_script_interpreter() {
+        assert_eq!(script_interpreter("#!/usr/bin/env python3\nprint(1)", ""), vec!["/usr/bin/env", "python3"]);
+        assert_eq!(script_interpreter("#!/bin/bash\necho hi", "zsh -e"), vec!["zsh", "-e"]);
+        #[cfg(not(target_os = "windows"))]
+        assert_eq!(script_interpreter("echo hi", ""), vec!["sh"]);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_script_launcher_with_env_and_args() {
+        let options = LaunchOptions {
+            args: vec!["arg1".to_string()],
+            timeout_secs: 5,
+            env_vars: Some(vec![("GREETING".to_string(), "hello".to_string())]),
+            terminal: false,
+            hold: false,
+            ports: Vec::new(),
+            volumes: Vec::new(),
+            working_dir: None,
+        };
+        let mut launcher = Launcher::with_options(
+            "script".to_string(),
+            "Script".to_string(),
+            LaunchType::Script,
+            String::new(),
+            options,
+        );
+        launcher.script = Some("#!/bin/sh\necho \"$GREETING $1\"\necho done\n".to_string());
+        let output = launcher.execute_with_output().await.unwrap().unwrap();
+        assert!(output.success, "{:?}", output);
+        assert_eq!(output.stdout, "hello arg1\ndone\n");
+    }
+
+    #[tokio::test]
+    async fn test_script_launcher_without_script() {
+        let launcher = Launcher::new(
+            "script".to_string(),
+            "Script".to_string(),
+            LaunchType::Script,
+            String::new(),
+        );
+        assert!(launcher.execute().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failed_precondition_prevents_launch() {
+        let mut launcher = Launcher::new(
+            "vpn-app".to_string(),
+            "VPN App".to_string(),
+            LaunchType::App,
+            "initium-missing-binary".to_string(),
+        );
+        launcher.preconditions = Some(vec![Precondition {
+            check: crate::precondition::PreconditionCheck::EnvVar {
+                name: "INITIUM_TEST_UNSET_VARIABLE".to_string(),
+            },
+            message: Some("VPN not configured".to_string()),
+        }]);
+        assert_eq!(
+            launcher.execute().await.unwrap_err(),
+            "VPN not configured (INITIUM_TEST_UNSET_VARIABLE is not set)"
+        );
+    }
+
     #[test]
     fn test_generate_slug_simple() {
         assert_eq!(generate_slug("YouTube"), "youtube");
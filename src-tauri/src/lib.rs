@@ -1,7 +1,26 @@
 // Initium - Library exports
 
+pub mod bookmarks;
+pub mod browser;
+pub mod chain;
+pub mod command_history;
 pub mod config;
+pub mod config_import;
 pub mod config_manager;
+pub mod container;
+pub mod fallback;
+pub mod group;
 pub mod launcher;
+pub mod package;
+pub mod parameter;
+pub mod precondition;
+pub mod project;
+pub mod readiness;
+pub mod sandbox;
+pub mod search;
+pub mod shortcut;
+pub mod ssh;
+pub mod steam;
 pub mod system;
+pub mod terminal;
 pub mod ui;
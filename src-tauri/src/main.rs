@@ -1,6 +1,30 @@
+use initium::bookmarks::{self, BookmarkFolder, BookmarkFormat, BookmarkSource};
+use initium::browser::WebOptions;
+use initium::chain::ChainStep;
+use initium::command_history::{unix_timestamp, CommandHistory, CommandRecord};
+use initium::config_import::{ImportDiff, ImportOptions};
 use initium::config_manager::ConfigManager;
-use initium::launcher::{Launcher, LaunchType, generate_unique_id};
+use initium::container::{self, ContainerAction, ContainerOptions, ContainerStatus};
+use initium::fallback::FallbackTarget;
+use initium::group::GroupOptions;
+use initium::launcher::{LaunchOptions, LaunchReport, Launcher, LaunchType, generate_unique_id};
+use initium::package::{self, InstalledApp};
+use initium::parameter::Parameter;
+use initium::precondition::Precondition;
+use initium::project::{self, ProjectSettings, ProjectSync};
+use initium::search::{self, SearchEngine, SearchEngines};
+use initium::shortcut::{self, ShortcutImport};
+use initium::ssh::{self, SshImport, SshOptions};
+use initium::steam;
+use initium::terminal;
+#[cfg(target_os = "linux")]
+use initium::system::appimage;
+use initium::system::command_runner::CommandOutput;
+use initium::ui::dmenu::{self, DmenuOptions};
+use initium::ui::webview_window::{self, EmbeddedWindow, WindowGeometry, WindowIcon, WindowStates};
 use serde_json::json;
+use std::collections::HashMap;
+use tauri::Manager;
 
 #[tauri::command]
 fn set_background(background: String) -> Result<(), String> {
@@ -34,6 +58,11 @@ fn get_settings() -> Result<serde_json::Value, String> {
         "language": manager.get_language(),
         "background": manager.config().background.clone(),
         "theme": manager.config().theme,
+        "terminal": manager.config().terminal.clone(),
+        "appimage_dirs": manager.config().appimage_dirs.clone(),
+        "detected_terminal": terminal::detect_terminal(),
+        "projects": manager.config().projects.clone(),
+        "detected_editor": project::detect_editor(),
         "config_dir": ConfigManager::get_config_dir_path().to_string_lossy().to_string(),
         "icons_dir": ConfigManager::get_icons_dir_path().to_string_lossy().to_string(),
         "settings_dir": ConfigManager::get_settings_dir_path().to_string_lossy().to_string(),
@@ -41,6 +70,12 @@ fn get_settings() -> Result<serde_json::Value, String> {
     }))
 }
 
+#[tauri::command]
+fn set_terminal(terminal: Option<String>) -> Result<(), String> {
+    let mut manager = ConfigManager::load_or_default()?;
+    manager.set_terminal(terminal)
+}
+
 #[tauri::command]
 fn reset_settings() -> Result<(), String> {
     let mut manager = ConfigManager::load_or_default()?;
@@ -103,15 +138,44 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             get_launchers,
             add_launcher_cmd,
+            update_launcher_cmd,
             remove_launcher_cmd,
             execute_launcher_cmd,
+            container_action_cmd,
+            get_container_status,
+            get_command_history,
+            get_launcher_parameters,
+            search_web,
+            get_browsers,
+            get_installed_apps,
+            get_steam_games,
+            import_steam_games,
+            get_appimages,
+            import_appimages,
+            set_appimage_dirs,
+            set_project_settings,
+            get_projects,
+            import_projects,
+            import_installed_apps,
+            get_ssh_hosts,
+            import_ssh_hosts,
+            get_search_engines,
+            export_search_engines,
+            import_search_engines,
             export_config,
             import_config,
+            preview_config_import,
+            get_bookmark_sources,
+            get_bookmark_folders,
+            import_bookmarks,
+            export_bookmarks,
+            import_shortcuts,
             set_background,
             get_background,
             set_language,
             get_language,
             get_settings,
+            set_terminal,
             reset_settings,
             save_all_settings,
             open_directory,
@@ -133,28 +197,46 @@ fn get_launchers() -> Result<Vec<serde_json::Value>, String> {
         serde_json::json!({
             "id": l.id,
             "name": l.name,
-            "launch_type": if l.launch_type == LaunchType::Web { "web" } else { "app" },
+            "launch_type": l.launch_type.as_str(),
             "target": l.target,
+            "script": l.script,
             "icon": l.icon,
+            "category": l.category,
+            "group": l.group,
+            "chain": l.chain,
+            "preconditions": l.preconditions,
+            "fallbacks": l.fallbacks,
+            "parameters": l.parameters,
+            "web": l.web,
+            "ssh": l.ssh,
+            "container": l.container,
+            "options": l.options,
         })
     }).collect())
 }
 
 /// Add a new launcher
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 fn add_launcher_cmd(
     name: String,
     launch_type: String,
     target: String,
     icon: Option<String>,
+    script: Option<String>,
+    group: Option<GroupOptions>,
+    chain: Option<Vec<ChainStep>>,
+    preconditions: Option<Vec<Precondition>>,
+    fallbacks: Option<Vec<FallbackTarget>>,
+    parameters: Option<Vec<Parameter>>,
+    web: Option<WebOptions>,
+    ssh: Option<SshOptions>,
+    container: Option<ContainerOptions>,
+    options: Option<LaunchOptions>,
 ) -> Result<(), String> {
     let mut manager = ConfigManager::load_or_default()?;
     
-    let ltype = if launch_type == "web" {
-        LaunchType::Web
-    } else {
-        LaunchType::App
-    };
+    let ltype: LaunchType = launch_type.parse()?;
     
     // Générer l'ID automatiquement
     let existing_ids: Vec<String> = manager.config()
@@ -165,31 +247,605 @@ fn add_launcher_cmd(
     let id = generate_unique_id(&name, &existing_ids);
     let mut launcher = Launcher::new(id, name, ltype, target);
     launcher.icon = icon;
+    launcher.script = script;
+    launcher.group = group;
+    launcher.chain = chain;
+    launcher.preconditions = preconditions;
+    launcher.fallbacks = fallbacks;
+    launcher.parameters = parameters;
+    launcher.web = web;
+    launcher.ssh = ssh;
+    launcher.container = container;
+    launcher.options = options;
     
     manager.add_launcher(launcher)?;
     manager.save()
 }
 
+/// Update a launcher in place, keeping its id
+///
+/// Category, AppImage and project links are not part of the launcher form and are kept.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+fn update_launcher_cmd(
+    id: String,
+    name: String,
+    launch_type: String,
+    target: String,
+    icon: Option<String>,
+    script: Option<String>,
+    group: Option<GroupOptions>,
+    chain: Option<Vec<ChainStep>>,
+    preconditions: Option<Vec<Precondition>>,
+    fallbacks: Option<Vec<FallbackTarget>>,
+    parameters: Option<Vec<Parameter>>,
+    web: Option<WebOptions>,
+    ssh: Option<SshOptions>,
+    container: Option<ContainerOptions>,
+    options: Option<LaunchOptions>,
+) -> Result<(), String> {
+    let mut manager = ConfigManager::load_or_default()?;
+
+    let ltype: LaunchType = launch_type.parse()?;
+    let existing = manager.config().find_launcher(&id).ok_or("Launcher not found")?;
+    let mut launcher = Launcher {
+        category: existing.category.clone(),
+        appimage: existing.appimage.clone(),
+        project: existing.project.clone(),
+        ..Launcher::new(id, name, ltype, target)
+    };
+    launcher.icon = icon;
+    launcher.script = script;
+    launcher.group = group;
+    launcher.chain = chain;
+    launcher.preconditions = preconditions;
+    launcher.fallbacks = fallbacks;
+    launcher.parameters = parameters;
+    launcher.web = web;
+    launcher.ssh = ssh;
+    launcher.container = container;
+    launcher.options = options;
+
+    manager.update_launcher(launcher)
+}
+
 /// Remove a launcher
 #[tauri::command]
 fn remove_launcher_cmd(id: String) -> Result<(), String> {
     let mut manager = ConfigManager::load_or_default()?;
     manager.remove_launcher(&id)?;
-    Ok(())
+
+    // A new launcher reusing the id must not show the old results
+    let path = CommandHistory::get_history_path();
+    let mut history = CommandHistory::load(&path)?;
+    history.remove_launcher(&id);
+    history.save(&path)
 }
 
 /// Execute a launcher
+///
+/// Command launchers return their captured output, also kept in the command history.
+/// Group and chain launchers return the result of each member or step.
+/// Parameter `values` are substituted into the target, defaults fill the gaps.
 #[tauri::command]
-async fn execute_launcher_cmd(id: String) -> Result<String, String> {
-    let manager = ConfigManager::load_or_default()?;
+async fn execute_launcher_cmd(
+    app: tauri::AppHandle,
+    id: String,
+    values: Option<HashMap<String, String>>,
+) -> Result<serde_json::Value, String> {
+    let mut manager = ConfigManager::load_or_default()?;
+    #[cfg(target_os = "linux")]
+    relink_appimage(&mut manager, &id).await?;
     
     let launcher = manager.config().launchers.iter()
         .find(|l| l.id == id)
         .ok_or("Launcher not found")?
-        .clone();
+        .with_parameters(&values.unwrap_or_default())?;
+
+    if launcher.is_embedded() {
+        if let Some(preconditions) = &launcher.preconditions {
+            initium::precondition::check_all(preconditions).await?;
+        }
+        open_embedded_window(&app, &launcher)?;
+        let report = LaunchReport::from_result(&launcher, Ok(None));
+        return Ok(json!({
+            "message": format!("Launcher '{}' opened", launcher.name),
+            "output": report.output,
+            "report": report,
+        }));
+    }
     
-    launcher.execute().await?;
-    Ok(format!("Launcher '{}' executed", launcher.name))
+    let timestamp = unix_timestamp();
+    let report = launcher.execute_in(&manager.config().launchers).await;
+    if report.output.is_none() && report.members.is_empty() {
+        report.result()?;
+    }
+
+    let path = CommandHistory::get_history_path();
+    let mut history = CommandHistory::load(&path)?;
+    history.record_report(&report, timestamp);
+    history.save(&path)?;
+
+    Ok(json!({
+        "message": format!("Launcher '{}' executed", launcher.name),
+        "output": report.output,
+        "report": report,
+    }))
+}
+
+/// Start, stop or show the logs of a container launcher
+#[tauri::command]
+async fn container_action_cmd(id: String, action: String) -> Result<CommandOutput, String> {
+    let manager = ConfigManager::load_or_default()?;
+    let launcher = manager.config().find_launcher(&id).ok_or("Launcher not found")?;
+    let options = launcher.options.clone().unwrap_or_default();
+    let action: ContainerAction = action.parse()?;
+    container::execute_action(launcher, action, &options).await
+}
+
+/// Get the status of a container launcher
+#[tauri::command]
+async fn get_container_status(id: String) -> Result<ContainerStatus, String> {
+    let manager = ConfigManager::load_or_default()?;
+    let launcher = manager.config().find_launcher(&id).ok_or("Launcher not found")?;
+    container::launcher_status(launcher).await
+}
+
+/// Open a web launcher in its own webview window, or focus it when already open
+fn open_embedded_window(app: &tauri::AppHandle, launcher: &Launcher) -> Result<(), String> {
+    let states = WindowStates::load(WindowStates::get_states_path())?;
+    let spec = EmbeddedWindow::for_launcher(launcher, &states)?;
+
+    if let Some(window) = app.get_webview_window(&spec.label) {
+        let _ = window.show();
+        return window.set_focus().map_err(|e| format!("Failed to focus window: {}", e));
+    }
+
+    let url = spec.url.parse().map_err(|e| format!("Invalid URL {}: {}", spec.url, e))?;
+    let (width, height) = webview_window::DEFAULT_WINDOW_SIZE;
+    let mut builder = tauri::WebviewWindowBuilder::new(app, &spec.label, tauri::WebviewUrl::External(url))
+        .title(&spec.title)
+        .data_directory(spec.data_directory.clone())
+        .inner_size(width as f64, height as f64);
+    if let Some(geometry) = spec.geometry {
+        builder = builder
+            .inner_size(geometry.width as f64, geometry.height as f64)
+            .position(geometry.x as f64, geometry.y as f64)
+            .maximized(geometry.maximized);
+    }
+    let icon = match &spec.icon {
+        Some(WindowIcon::File(path)) => tauri::image::Image::from_path(path).ok(),
+        Some(WindowIcon::Data(bytes)) => tauri::image::Image::from_bytes(bytes).ok(),
+        None => None,
+    };
+    if let Some(icon) = icon {
+        builder = builder.icon(icon).map_err(|e| format!("Failed to set window icon: {}", e))?;
+    }
+
+    let window = builder.build().map_err(|e| format!("Failed to open window: {}", e))?;
+    log::info!("Opened embedded window '{}' for {}", spec.label, spec.url);
+
+    let tracked = window.clone();
+    let launcher_id = launcher.id.clone();
+    window.on_window_event(move |event| {
+        if let tauri::WindowEvent::CloseRequested { .. } = event {
+            if let Some(geometry) = window_geometry(&tracked) {
+                if let Err(e) = webview_window::remember_geometry(&launcher_id, geometry) {
+                    log::warn!("{}", e);
+                }
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Logical size and position of a window
+fn window_geometry(window: &tauri::WebviewWindow) -> Option<WindowGeometry> {
+    let scale = window.scale_factor().ok()?;
+    let position = window.outer_position().ok()?.to_logical::<f64>(scale);
+    let size = window.inner_size().ok()?.to_logical::<f64>(scale);
+    Some(WindowGeometry {
+        x: position.x as i32,
+        y: position.y as i32,
+        width: size.width as u32,
+        height: size.height as u32,
+        maximized: window.is_maximized().unwrap_or(false),
+    })
+}
+
+/// Get the parameter definitions of a launcher, prompted before executing it
+#[tauri::command]
+fn get_launcher_parameters(id: String) -> Result<Vec<Parameter>, String> {
+    let manager = ConfigManager::load_or_default()?;
+    let launcher = manager.config().find_launcher(&id).ok_or("Launcher not found")?;
+    Ok(launcher.parameters.clone().unwrap_or_default())
+}
+
+/// Open a free-form web search
+///
+/// A leading keyword ("gh rust tauri") picks the engine; otherwise the search
+/// launcher `id` (or the first engine) is used.
+#[tauri::command]
+async fn search_web(query: String, id: Option<String>) -> Result<(), String> {
+    let engines = SearchEngines::load(SearchEngines::get_engines_path())?;
+    let template = match id {
+        Some(id) => {
+            let manager = ConfigManager::load_or_default()?;
+            let launcher = manager.config().find_launcher(&id).ok_or("Launcher not found")?;
+            Some(launcher.target.clone())
+        }
+        None => None,
+    };
+    search::execute_search(&query, &engines.engines, template.as_deref()).await
+}
+
+/// Get the configured search engines
+#[tauri::command]
+fn get_search_engines() -> Result<Vec<SearchEngine>, String> {
+    Ok(SearchEngines::load(SearchEngines::get_engines_path())?.engines)
+}
+
+#[tauri::command]
+fn export_search_engines() -> Result<String, String> {
+    SearchEngines::load(SearchEngines::get_engines_path())?.export_json()
+}
+
+/// Import search engines, replacing existing engines with the same keyword
+#[tauri::command]
+fn import_search_engines(json: String) -> Result<(), String> {
+    let path = SearchEngines::get_engines_path();
+    let mut engines = SearchEngines::load(&path)?;
+    engines.merge(SearchEngines::import_json(&json)?);
+    engines.save(&path)
+}
+
+/// Follow the AppImage of a launcher to its newest version when its file is gone
+#[cfg(target_os = "linux")]
+async fn relink_appimage(manager: &mut ConfigManager, id: &str) -> Result<(), String> {
+    // Only the directory of the missing file is scanned, AppImages run to be read
+    let Some(dir) = manager.config().find_launcher(id).and_then(appimage::relink_dir) else {
+        return Ok(());
+    };
+    let found: Vec<_> = scan_appimages(vec![dir]).await?.into_iter().map(|(a, _)| a).collect();
+    if appimage::sync_launchers(&mut manager.config_mut().launchers, &found, false).updated > 0 {
+        manager.save()?;
+    }
+    Ok(())
+}
+
+/// Scan AppImage directories off the async runtime, with their icons as data URLs
+#[cfg(target_os = "linux")]
+async fn scan_appimages(dirs: Vec<std::path::PathBuf>) -> Result<Vec<(appimage::AppImage, Option<String>)>, String> {
+    tokio::task::spawn_blocking(move || {
+        let work_dir = tempfile::tempdir().map_err(|e| format!("Failed to create AppImage work directory: {}", e))?;
+        Ok(appimage::scan(&dirs, work_dir.path())
+            .into_iter()
+            .map(|a| {
+                let icon = a.icon.as_ref().and_then(|p| read_file_as_base64(p.to_string_lossy().to_string()).ok());
+                (a, icon)
+            })
+            .collect())
+    })
+    .await
+    .map_err(|e| format!("AppImage scan failed: {}", e))?
+}
+
+/// Get the AppImages of the configured directories, flagged when a launcher already starts them
+#[tauri::command]
+async fn get_appimages() -> Result<Vec<serde_json::Value>, String> {
+    #[cfg(target_os = "linux")]
+    {
+        let manager = ConfigManager::load_or_default()?;
+        let found = scan_appimages(appimage::search_dirs(&manager.config().appimage_dirs)).await?;
+        Ok(found
+            .into_iter()
+            .map(|(a, icon)| {
+                let exists = manager
+                    .config()
+                    .launchers
+                    .iter()
+                    .any(|l| l.appimage.as_deref() == Some(a.desktop_id.as_str()));
+                json!({
+                    "path": a.path,
+                    "name": a.name,
+                    "version": a.version,
+                    "icon": icon,
+                    "exists": exists,
+                })
+            })
+            .collect())
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Ok(Vec::new())
+    }
+}
+
+/// Add launchers for new AppImages and point existing ones at the newest versions
+#[tauri::command]
+async fn import_appimages() -> Result<serde_json::Value, String> {
+    #[cfg(target_os = "linux")]
+    {
+        let mut manager = ConfigManager::load_or_default()?;
+        let found = scan_appimages(appimage::search_dirs(&manager.config().appimage_dirs)).await?;
+        let appimages: Vec<_> = found.iter().map(|(a, _)| a.clone()).collect();
+        let before = manager.config().launchers.len();
+        let summary = appimage::sync_launchers(&mut manager.config_mut().launchers, &appimages, true);
+        for launcher in &mut manager.config_mut().launchers[before..] {
+            launcher.icon = found
+                .iter()
+                .find(|(a, _)| launcher.appimage.as_deref() == Some(a.desktop_id.as_str()))
+                .and_then(|(_, icon)| icon.clone());
+        }
+        manager.save()?;
+        log::info!("AppImages: {} added, {} updated", summary.added, summary.updated);
+        Ok(json!(summary))
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Ok(json!({ "added": 0, "updated": 0 }))
+    }
+}
+
+/// Set the directories scanned for AppImages besides the default ones
+#[tauri::command]
+fn set_appimage_dirs(dirs: Vec<String>) -> Result<(), String> {
+    let mut manager = ConfigManager::load_or_default()?;
+    manager.set_appimage_dirs(dirs)
+}
+
+/// Set the roots, limits and editor of project discovery
+#[tauri::command]
+fn set_project_settings(settings: ProjectSettings) -> Result<(), String> {
+    let mut manager = ConfigManager::load_or_default()?;
+    manager.set_project_settings(settings)
+}
+
+/// Get the git repositories of the project roots, flagged when launchers already exist for them
+#[tauri::command]
+async fn get_projects() -> Result<Vec<serde_json::Value>, String> {
+    let manager = ConfigManager::load_or_default()?;
+    let settings = manager.config().projects.clone();
+    let projects = tokio::task::spawn_blocking(move || project::scan(&settings))
+        .await
+        .map_err(|e| format!("Project scan failed: {}", e))?;
+    Ok(projects
+        .into_iter()
+        .map(|p| {
+            let exists = manager
+                .config()
+                .launchers
+                .iter()
+                .any(|l| l.project.as_ref().is_some_and(|link| link.path == p.path));
+            json!({
+                "path": p.path,
+                "name": p.name,
+                "kind": p.kind,
+                "remote_url": p.remote_url,
+                "exists": exists,
+            })
+        })
+        .collect())
+}
+
+/// Add the launchers of new projects and update those of known ones
+#[tauri::command]
+async fn import_projects() -> Result<ProjectSync, String> {
+    let mut manager = ConfigManager::load_or_default()?;
+    let settings = manager.config().projects.clone();
+    let editor = settings.editor_command();
+    let projects = tokio::task::spawn_blocking(move || project::scan(&settings))
+        .await
+        .map_err(|e| format!("Project scan failed: {}", e))?;
+    let summary = project::sync_launchers(&mut manager.config_mut().launchers, &projects, editor.as_deref());
+    manager.save()?;
+    log::info!("Projects: {} launcher(s) added, {} updated", summary.added, summary.updated);
+    Ok(summary)
+}
+
+/// Get the installed Flatpak and Snap applications, flagged when a launcher already starts them
+#[tauri::command]
+async fn get_installed_apps() -> Result<Vec<serde_json::Value>, String> {
+    let manager = ConfigManager::load_or_default()?;
+    let launchers = &manager.config().launchers;
+    let apps = tokio::task::spawn_blocking(installed_apps)
+        .await
+        .map_err(|e| format!("Installed applications scan failed: {}", e))?;
+    Ok(apps
+        .into_iter()
+        .map(|app| {
+            let exists = launchers
+                .iter()
+                .any(|l| l.launch_type == app.launch_type && l.target == app.id);
+            json!({
+                "type": app.launch_type.as_str(),
+                "id": app.id,
+                "name": app.name,
+                "version": app.version,
+                "exists": exists,
+            })
+        })
+        .collect())
+}
+
+/// Add launchers for the selected installed applications (all when unset), returns the number added
+#[tauri::command]
+async fn import_installed_apps(ids: Option<Vec<String>>) -> Result<usize, String> {
+    let mut manager = ConfigManager::load_or_default()?;
+    let apps = tokio::task::spawn_blocking(move || {
+        let mut apps = installed_apps();
+        if let Some(ids) = ids {
+            apps.retain(|app| ids.contains(&app.id));
+        }
+        // Icons are stored as data URLs, like the icons chosen in the launcher form
+        for app in &mut apps {
+            app.icon = app.icon.take().and_then(|path| read_file_as_base64(path).ok());
+        }
+        apps
+    })
+    .await
+    .map_err(|e| format!("Installed applications scan failed: {}", e))?;
+    let added = package::merge_installed_apps(&mut manager.config_mut().launchers, &apps);
+    manager.save()?;
+    log::info!("Imported {} Flatpak and Snap application(s)", added);
+    Ok(added)
+}
+
+fn installed_apps() -> Vec<InstalledApp> {
+    #[cfg(target_os = "linux")]
+    {
+        initium::system::packages::list_installed_apps()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Vec::new()
+    }
+}
+
+/// Get the games of the local Steam libraries, flagged when a launcher already starts them
+#[tauri::command]
+async fn get_steam_games() -> Result<Vec<serde_json::Value>, String> {
+    let manager = ConfigManager::load_or_default()?;
+    let games = tokio::task::spawn_blocking(steam::installed_games)
+        .await
+        .map_err(|e| format!("Steam library scan failed: {}", e))?;
+    Ok(games
+        .into_iter()
+        .map(|g| {
+            json!({
+                "exists": steam::find_game_launcher(&manager.config().launchers, &g.app_id).is_some(),
+                "app_id": g.app_id,
+                "name": g.name,
+                "library": g.library,
+            })
+        })
+        .collect())
+}
+
+/// Add launchers for the selected Steam games (all when unset), returns the number added
+#[tauri::command]
+async fn import_steam_games(app_ids: Option<Vec<String>>) -> Result<usize, String> {
+    let mut manager = ConfigManager::load_or_default()?;
+    let mut games = tokio::task::spawn_blocking(steam::installed_games)
+        .await
+        .map_err(|e| format!("Steam library scan failed: {}", e))?;
+    if let Some(app_ids) = app_ids {
+        games.retain(|g| app_ids.contains(&g.app_id));
+    }
+    // Cover art is stored as data URLs, like the icons chosen in the launcher form
+    let icons: Vec<(String, String)> = games
+        .iter()
+        .filter_map(|g| {
+            let path = g.icon.as_ref()?.to_string_lossy().to_string();
+            Some((g.app_id.clone(), read_file_as_base64(path).ok()?))
+        })
+        .collect();
+    let added = steam::merge_games(&mut manager.config_mut().launchers, &games, &icons);
+    manager.save()?;
+    log::info!("Imported {} Steam game(s)", added);
+    Ok(added)
+}
+
+/// Get the bookmark files of the installed browser profiles
+#[tauri::command]
+fn get_bookmark_sources() -> Vec<BookmarkSource> {
+    bookmarks::find_sources()
+}
+
+/// Get the folders of a bookmark file, to choose those to import
+#[tauri::command]
+fn get_bookmark_folders(path: String, format: BookmarkFormat) -> Result<Vec<BookmarkFolder>, String> {
+    let bookmarks = bookmarks::read_bookmarks(std::path::Path::new(&path), format)?;
+    Ok(bookmarks::folders(&bookmarks))
+}
+
+/// Add web launchers for the bookmarks of the selected folders, returns the number added
+#[tauri::command]
+fn import_bookmarks(path: String, format: BookmarkFormat, folders: Vec<String>) -> Result<usize, String> {
+    let mut manager = ConfigManager::load_or_default()?;
+    let bookmarks = bookmarks::read_bookmarks(std::path::Path::new(&path), format)?;
+    let added = bookmarks::merge_bookmarks(&mut manager.config_mut().launchers, &bookmarks, &folders);
+    manager.save()?;
+    log::info!("Imported {} bookmark(s) from {}", added, path);
+    Ok(added)
+}
+
+/// Export the web launchers as a Netscape bookmark HTML file
+#[tauri::command]
+fn export_bookmarks() -> Result<String, String> {
+    let manager = ConfigManager::load_or_default()?;
+    Ok(bookmarks::export_netscape_html(&manager.config().launchers))
+}
+
+/// Add launchers for the Windows and macOS shortcuts of the given files and folders
+#[tauri::command]
+fn import_shortcuts(paths: Vec<String>) -> Result<ShortcutImport, String> {
+    let mut manager = ConfigManager::load_or_default()?;
+    let paths: Vec<std::path::PathBuf> = paths.into_iter().map(std::path::PathBuf::from).collect();
+    let summary = shortcut::import_shortcuts(&mut manager.config_mut().launchers, &paths);
+    // Icons are stored as data URLs, like the icons chosen in the launcher form
+    let first_added = manager.config().launchers.len() - summary.added;
+    for launcher in &mut manager.config_mut().launchers[first_added..] {
+        launcher.icon = launcher.icon.take().and_then(|path| read_file_as_base64(path).ok());
+    }
+    manager.save()?;
+    log::info!("Imported shortcuts: {} added, {} skipped, {} failed", summary.added, summary.skipped, summary.failed.len());
+    Ok(summary)
+}
+
+/// Get the hosts of ~/.ssh/config, flagged when a launcher already connects to them
+#[tauri::command]
+fn get_ssh_hosts() -> Result<Vec<serde_json::Value>, String> {
+    let manager = ConfigManager::load_or_default()?;
+    let hosts = ssh::parse_ssh_config(ssh::default_config_path())?;
+    Ok(hosts
+        .into_iter()
+        .map(|h| {
+            json!({
+                "exists": ssh::find_ssh_launcher(&manager.config().launchers, &h.alias).is_some(),
+                "alias": h.alias,
+                "hostname": h.hostname,
+                "options": h.options,
+            })
+        })
+        .collect())
+}
+
+/// Import the selected hosts of ~/.ssh/config (all when unset) as SSH launchers
+#[tauri::command]
+fn import_ssh_hosts(aliases: Option<Vec<String>>) -> Result<SshImport, String> {
+    let mut manager = ConfigManager::load_or_default()?;
+    let mut hosts = ssh::parse_ssh_config(ssh::default_config_path())?;
+    if let Some(aliases) = aliases {
+        hosts.retain(|h| aliases.contains(&h.alias));
+    }
+    let summary = ssh::merge_ssh_hosts(&mut manager.config_mut().launchers, hosts);
+    manager.save()?;
+    log::info!("Imported SSH hosts: {} added, {} skipped", summary.added, summary.skipped);
+    Ok(summary)
+}
+
+/// Get the installed browsers and their capabilities, the default one flagged
+#[tauri::command]
+fn get_browsers() -> Vec<serde_json::Value> {
+    #[cfg(target_os = "linux")]
+    {
+        initium::system::browsers::detect_browsers()
+            .into_iter()
+            .filter_map(|b| serde_json::to_value(b).ok())
+            .collect()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Vec::new()
+    }
+}
+
+/// Get the result history of a command launcher, oldest first
+#[tauri::command]
+fn get_command_history(id: String) -> Result<Vec<CommandRecord>, String> {
+    let history = CommandHistory::load(CommandHistory::get_history_path())?;
+    Ok(history.entries(&id).to_vec())
 }
 
 #[tauri::command]
@@ -198,18 +854,61 @@ fn export_config() -> Result<String, String> {
     manager.export_to_json()
 }
 
+/// Import a configuration with the given mode and conflict strategy
 #[tauri::command]
-fn import_config(json: String) -> Result<(), String> {
-    let manager = ConfigManager::import_from_json(&json)?;
-    manager.save()
+fn import_config(json: String, options: ImportOptions) -> Result<ImportDiff, String> {
+    let mut manager = ConfigManager::load_or_default()?;
+    manager.import_json(&json, options)
+}
+
+/// Changes an import would make, without applying them
+#[tauri::command]
+fn preview_config_import(json: String, options: ImportOptions) -> Result<ImportDiff, String> {
+    let manager = ConfigManager::load_or_default()?;
+    manager.preview_import(&json, options)
+}
+
+/// Run a command-line mode to completion, exiting with an error message on failure
+fn run_cli_mode<F: std::future::Future<Output = Result<(), String>>>(mode: F) {
+    let runtime = tokio::runtime::Runtime::new().expect("error while creating tokio runtime");
+    if let Err(e) = runtime.block_on(mode) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Dispatch command-line modes; returns false when the window should be opened
+fn run_from_args() -> bool {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        #[cfg(target_os = "linux")]
+        Some("--dbus") => run_cli_mode(initium::system::dbus_service::run_session_service()),
+        Some("--dmenu") => match DmenuOptions::from_args(&args[1..]) {
+            Ok(options) => run_cli_mode(dmenu::run_dmenu(&options)),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(2);
+            }
+        },
+        Some("--rofi") => run_cli_mode(dmenu::run_rofi_script(args.get(1).map(String::as_str))),
+        Some("tui") => run_cli_mode(initium::ui::tui::run_tui()),
+        _ => return false,
+    }
+    true
 }
 
 #[cfg(not(target_os = "macos"))]
 fn main() {
+    if run_from_args() {
+        return;
+    }
     run();
 }
 
 #[cfg(target_os = "macos")]
 fn main() {
+    if run_from_args() {
+        return;
+    }
     run();
 }
@@ -0,0 +1,172 @@
+//! Package Module
+//!
+//! Flatpak and Snap launchers start an installed application by its id
+//! instead of a hand-typed `flatpak run` command line.
+//! Features:
+//! - `flatpak run <app-id>` and `snap run <name>`
+//! - Installation checked before launch
+//! - Installed applications merged into the launchers without duplicates
+
+use crate::fallback::spawn_checked;
+use crate::launcher::{generate_unique_id, LaunchOptions, LaunchType, Launcher};
+use crate::sandbox::{self, host_succeeds};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Directory where snaps are mounted
+pub const SNAP_MOUNT_DIR: &str = "/snap";
+
+/// Application installed from a Flatpak remote or the Snap store
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InstalledApp {
+    /// `flatpak` or `snap`
+    #[serde(rename = "type")]
+    pub launch_type: LaunchType,
+    /// Flatpak application id or snap command (`firefox`, `snap.app`)
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// Installed version, if known
+    pub version: Option<String>,
+    /// Icon file, if found
+    pub icon: Option<String>,
+}
+
+impl InstalledApp {
+    /// Launcher starting this application
+    pub fn to_launcher(&self, existing_ids: &[String]) -> Launcher {
+        let id = generate_unique_id(&self.name, existing_ids);
+        let mut launcher = Launcher::new(id, self.name.clone(), self.launch_type.clone(), self.id.clone());
+        launcher.icon = self.icon.clone();
+        launcher
+    }
+}
+
+/// Snap of a snap command (`snap.app` belongs to `snap`)
+pub fn snap_name(command: &str) -> &str {
+    command.split('.').next().unwrap_or(command)
+}
+
+
+/// Whether a Flatpak application is installed, per `flatpak info`
+pub fn is_flatpak_installed(app_id: &str) -> bool {
+    host_succeeds("flatpak", &["info".to_string(), app_id.to_string()])
+}
+
+/// Whether a snap is mounted under `mount_dir`
+pub fn is_snap_installed_in(mount_dir: &Path, command: &str) -> bool {
+    let name = snap_name(command);
+    !name.is_empty() && mount_dir.join(name).join("current").exists()
+}
+
+/// Program and arguments starting a Flatpak or Snap launcher
+pub fn package_command(launch_type: &LaunchType, id: &str, args: &[String]) -> Result<(String, Vec<String>), String> {
+    let program = match launch_type {
+        LaunchType::Flatpak => "flatpak",
+        LaunchType::Snap => "snap",
+        other => return Err(format!("Not a package launcher: {}", other.as_str())),
+    };
+    let mut command_args = vec!["run".to_string(), id.to_string()];
+    command_args.extend(args.iter().cloned());
+    Ok((program.to_string(), command_args))
+}
+
+/// Check that the application is still installed, then start it
+pub async fn execute_package(launcher: &Launcher, options: &LaunchOptions) -> Result<(), String> {
+    let id = launcher.target.trim();
+    let kind = match launcher.launch_type {
+        LaunchType::Flatpak => "Flatpak",
+        _ => "Snap",
+    };
+    // `flatpak info` and the host checks run a process, off the async runtime
+    let launch_type = launcher.launch_type.clone();
+    let app_id = id.to_string();
+    let installed = tokio::task::spawn_blocking(move || match launch_type {
+        LaunchType::Flatpak => is_flatpak_installed(&app_id),
+        // The snap mount directory is not visible from a sandbox
+        _ if sandbox::is_sandboxed() => {
+            let current = Path::new(SNAP_MOUNT_DIR).join(snap_name(&app_id)).join("current");
+            host_succeeds("test", &["-e".to_string(), current.to_string_lossy().to_string()])
+        }
+        _ => is_snap_installed_in(Path::new(SNAP_MOUNT_DIR), &app_id),
+    })
+    .await
+    .map_err(|e| format!("Failed to check {} application '{}': {}", kind, id, e))?;
+    if !installed {
+        return Err(format!("{} application '{}' is not installed", kind, id));
+    }
+    let (program, args) = package_command(&launcher.launch_type, id, &options.args)?;
+    spawn_checked(&program, &args, options).await
+}
+
+/// Add a launcher per application without a launcher of the same type and id
+///
+/// Returns the number of launchers added.
+pub fn merge_installed_apps(launchers: &mut Vec<Launcher>, apps: &[InstalledApp]) -> usize {
+    let mut added = 0;
+    for app in apps {
+        if launchers
+            .iter()
+            .any(|l| l.launch_type == app.launch_type && l.target == app.id)
+        {
+            continue;
+        }
+        let existing_ids: Vec<String> = launchers.iter().map(|l| l.id.clone()).collect();
+        launchers.push(app.to_launcher(&existing_ids));
+        added += 1;
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(launch_type: LaunchType, id: &str, name: &str) -> InstalledApp {
+        InstalledApp {
+            launch_type,
+            id: id.to_string(),
+            name: name.to_string(),
+            version: None,
+            icon: None,
+        }
+    }
+
+    #[test]
+    fn test_package_command() {
+        let (program, args) =
+            package_command(&LaunchType::Flatpak, "org.gimp.GIMP", &["image.png".to_string()]).unwrap();
+        assert_eq!(program, "flatpak");
+        assert_eq!(args, vec!["run", "org.gimp.GIMP", "image.png"]);
+        assert_eq!(package_command(&LaunchType::Snap, "firefox", &[]).unwrap().0, "snap");
+        assert!(package_command(&LaunchType::App, "gimp", &[]).is_err());
+    }
+
+    #[test]
+    fn test_snap_installed() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("lxd/current")).unwrap();
+        assert_eq!(snap_name("lxd.lxc"), "lxd");
+        assert!(is_snap_installed_in(dir.path(), "lxd.lxc"));
+        assert!(!is_snap_installed_in(dir.path(), "firefox"));
+    }
+
+    #[test]
+    fn test_merge_installed_apps() {
+        let mut launchers = vec![Launcher::new(
+            "gimp".to_string(),
+            "My GIMP".to_string(),
+            LaunchType::Flatpak,
+            "org.gimp.GIMP".to_string(),
+        )];
+        let apps = vec![
+            app(LaunchType::Flatpak, "org.gimp.GIMP", "GIMP"),
+            app(LaunchType::Snap, "gimp", "GIMP"),
+        ];
+        assert_eq!(merge_installed_apps(&mut launchers, &apps), 1);
+        assert_eq!(launchers.len(), 2);
+        assert_eq!(launchers[1].launch_type, LaunchType::Snap);
+        assert_eq!(launchers[1].id, "gimp-2");
+        assert_eq!(merge_installed_apps(&mut launchers, &apps), 0);
+    }
+}
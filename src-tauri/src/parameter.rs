@@ -0,0 +1,435 @@
+//! Launcher Parameter Module
+//!
+//! Typed parameters prompted at launch time and substituted into the
+//! target, arguments, fallbacks and chain steps of a launcher as `{{name}}` placeholders.
+//! Kinds: text, choice, file, number, boolean; each with a default and validation.
+//! Values are URL-encoded in web targets and shell-quoted in app and command targets.
+
+use crate::chain::ChainStep;
+use crate::launcher::{LaunchType, Launcher};
+use crate::readiness::ReadinessProbe;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Type and validation rules of a parameter
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ParameterKind {
+    /// Free text, optionally limited in length
+    Text {
+        #[serde(default)]
+        max_length: Option<usize>,
+    },
+    /// One value among a fixed list
+    Choice { choices: Vec<String> },
+    /// File path, optionally required to exist
+    File {
+        #[serde(default)]
+        must_exist: bool,
+    },
+    /// Number within optional bounds
+    Number {
+        #[serde(default)]
+        min: Option<f64>,
+        #[serde(default)]
+        max: Option<f64>,
+    },
+    /// `true` or `false`
+    Boolean,
+}
+
+/// Parameter definition of a launcher
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Parameter {
+    /// Placeholder name, used as `{{name}}`
+    pub name: String,
+    /// Label shown when prompting
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Type and validation rules
+    #[serde(flatten)]
+    pub kind: ParameterKind,
+    /// Value used when none is given
+    #[serde(default)]
+    pub default: Option<String>,
+    /// Accept an empty value
+    #[serde(default)]
+    pub optional: bool,
+}
+
+impl Parameter {
+    /// Validate a value, returning its normalized form
+    pub fn validate(&self, value: &str) -> Result<String, String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return if self.optional {
+                Ok(String::new())
+            } else {
+                Err(format!("Parameter '{}' is required", self.name))
+            };
+        }
+        match &self.kind {
+            ParameterKind::Text { max_length: Some(max) } if value.chars().count() > *max => Err(format!(
+                "Parameter '{}' is longer than {} characters",
+                self.name, max
+            )),
+            ParameterKind::Text { .. } => Ok(value.to_string()),
+            ParameterKind::Choice { choices } => {
+                if choices.iter().any(|c| c == value) {
+                    Ok(value.to_string())
+                } else {
+                    Err(format!(
+                        "Parameter '{}' must be one of: {}",
+                        self.name,
+                        choices.join(", ")
+                    ))
+                }
+            }
+            ParameterKind::File { must_exist } => {
+                if *must_exist && !std::path::Path::new(value).exists() {
+                    Err(format!("Parameter '{}': file not found: {}", self.name, value))
+                } else {
+                    Ok(value.to_string())
+                }
+            }
+            ParameterKind::Number { min, max } => {
+                let number: f64 = value
+                    .parse()
+                    .map_err(|_| format!("Parameter '{}' must be a number", self.name))?;
+                if min.is_some_and(|min| number < min) || max.is_some_and(|max| number > max) {
+                    return Err(format!(
+                        "Parameter '{}' must be between {} and {}",
+                        self.name,
+                        min.map_or("-inf".to_string(), |m| m.to_string()),
+                        max.map_or("inf".to_string(), |m| m.to_string())
+                    ));
+                }
+                Ok(value.to_string())
+            }
+            ParameterKind::Boolean => match value.to_lowercase().as_str() {
+                "true" | "yes" | "1" | "on" => Ok("true".to_string()),
+                "false" | "no" | "0" | "off" => Ok("false".to_string()),
+                _ => Err(format!("Parameter '{}' must be true or false", self.name)),
+            },
+        }
+    }
+}
+
+/// Resolve the value of every parameter from the given values and defaults
+pub fn resolve_values(
+    parameters: &[Parameter],
+    values: &HashMap<String, String>,
+) -> Result<HashMap<String, String>, String> {
+    parameters
+        .iter()
+        .map(|parameter| {
+            let value = values
+                .get(&parameter.name)
+                .or(parameter.default.as_ref())
+                .map(String::as_str)
+                .unwrap_or("");
+            Ok((parameter.name.clone(), parameter.validate(value)?))
+        })
+        .collect()
+}
+
+/// Replace `{{name}}` placeholders, encoding each value with `encode`
+///
+/// Unknown placeholders are left untouched.
+pub fn substitute(template: &str, values: &HashMap<String, String>, encode: fn(&str) -> String) -> String {
+    let mut result = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + end].trim();
+        result.push_str(&rest[..start]);
+        match values.get(name) {
+            Some(value) => result.push_str(&encode(value)),
+            None => result.push_str(&rest[start..start + end + 2]),
+        }
+        rest = &rest[start + end + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Percent-encode a value for use in a URL (RFC 3986 unreserved characters kept)
+pub fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Quote a value as a single shell word
+pub fn shell_quote(value: &str) -> String {
+    #[cfg(target_os = "windows")]
+    {
+        windows_quote(value)
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
+/// Quote a value as one argument read back by `CommandLineToArgvW`
+///
+/// Backslashes are doubled only before a quote, including the closing one,
+/// so `C:\My Dir\` stays a single argument.
+pub fn windows_quote(value: &str) -> String {
+    let mut quoted = String::from('"');
+    let mut backslashes = 0;
+    for c in value.chars() {
+        if c == '\\' {
+            backslashes += 1;
+            continue;
+        }
+        let escaped = if c == '"' { backslashes * 2 + 1 } else { backslashes };
+        quoted.push_str(&"\\".repeat(escaped));
+        quoted.push(c);
+        backslashes = 0;
+    }
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+fn raw(value: &str) -> String {
+    value.to_string()
+}
+
+/// Encoding of values substituted into a target of the given type
+fn target_encoding(launch_type: &LaunchType) -> fn(&str) -> String {
+    match launch_type {
+        LaunchType::Web | LaunchType::Search => percent_encode,
+        LaunchType::App | LaunchType::Command => shell_quote,
+        _ => raw,
+    }
+}
+
+/// Substitute values into the targets and probes of a chain step
+fn substitute_step(step: &mut ChainStep, values: &HashMap<String, String>) {
+    match step {
+        ChainStep::Start { target, args } => {
+            *target = substitute(target, values, shell_quote);
+            for arg in args {
+                *arg = substitute(arg, values, raw);
+            }
+        }
+        ChainStep::Open { url } => *url = substitute(url, values, percent_encode),
+        ChainStep::Wait(wait) => match &mut wait.probe {
+            ReadinessProbe::Tcp { address } => *address = substitute(address, values, raw),
+            ReadinessProbe::Http { url } => *url = substitute(url, values, percent_encode),
+            ReadinessProbe::File { path } => *path = substitute(path, values, raw),
+            ReadinessProbe::LogLine { path, pattern } => {
+                *path = substitute(path, values, raw);
+                *pattern = substitute(pattern, values, raw);
+            }
+        },
+    }
+}
+
+impl Launcher {
+    /// Copy of the launcher with its parameters substituted
+    ///
+    /// Missing values fall back to the parameter defaults.
+    pub fn with_parameters(&self, values: &HashMap<String, String>) -> Result<Launcher, String> {
+        let Some(parameters) = &self.parameters else {
+            return Ok(self.clone());
+        };
+        let values = resolve_values(parameters, values)?;
+
+        let mut launcher = self.clone();
+        launcher.parameters = None;
+        launcher.target = substitute(&self.target, &values, target_encoding(&self.launch_type));
+        // Hosts, images and compose directories are passed as a single program argument
+        if matches!(self.launch_type, LaunchType::Ssh | LaunchType::Container)
+            && launcher.target != self.target
+            && (launcher.target.trim_start().starts_with('-') || launcher.target.trim().contains(char::is_whitespace))
+        {
+            return Err(format!(
+                "Parameter values turn the target of '{}' into several words or an option: {}",
+                self.name, launcher.target
+            ));
+        }
+        if let Some(web) = &mut launcher.web {
+            for url in &mut web.urls {
+                *url = substitute(url, &values, percent_encode);
+            }
+        }
+        if let Some(options) = &mut launcher.options {
+            for arg in &mut options.args {
+                *arg = substitute(arg, &values, raw);
+            }
+        }
+        for fallback in launcher.fallbacks.iter_mut().flatten() {
+            fallback.target = substitute(&fallback.target, &values, target_encoding(&fallback.launch_type));
+        }
+        for step in launcher.chain.iter_mut().flatten() {
+            substitute_step(step, &values);
+        }
+        Ok(launcher)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launcher::LaunchOptions;
+
+    fn parameter(name: &str, kind: ParameterKind) -> Parameter {
+        Parameter {
+            name: name.to_string(),
+            label: None,
+            kind,
+            default: None,
+            optional: false,
+        }
+    }
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_parameter_deserialize() {
+        let json = r#"{"name": "env", "kind": "choice", "choices": ["dev", "prod"], "default": "dev"}"#;
+        let parameter: Parameter = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            parameter.kind,
+            ParameterKind::Choice {
+                choices: vec!["dev".to_string(), "prod".to_string()]
+            }
+        );
+        assert_eq!(parameter.default.as_deref(), Some("dev"));
+    }
+
+    #[test]
+    fn test_validate_kinds() {
+        let number = parameter("port", ParameterKind::Number { min: Some(1.0), max: Some(65535.0) });
+        assert_eq!(number.validate("22").unwrap(), "22");
+        assert!(number.validate("0").is_err());
+        assert!(number.validate("ssh").is_err());
+
+        let boolean = parameter("verbose", ParameterKind::Boolean);
+        assert_eq!(boolean.validate("Yes").unwrap(), "true");
+        assert!(boolean.validate("maybe").is_err());
+
+        let text = parameter("key", ParameterKind::Text { max_length: Some(3) });
+        assert_eq!(text.validate("").unwrap_err(), "Parameter 'key' is required");
+        assert!(text.validate("ABCD").is_err());
+
+        let file = parameter("log", ParameterKind::File { must_exist: true });
+        assert!(file.validate("/initium/missing.log").is_err());
+    }
+
+    #[test]
+    fn test_resolve_values_uses_defaults() {
+        let mut env = parameter("env", ParameterKind::Choice { choices: vec!["dev".into(), "prod".into()] });
+        env.default = Some("dev".to_string());
+        let resolved = resolve_values(&[env.clone()], &HashMap::new()).unwrap();
+        assert_eq!(resolved["env"], "dev");
+        assert!(resolve_values(&[env], &values(&[("env", "test")])).is_err());
+    }
+
+    #[test]
+    fn test_substitute() {
+        let values = values(&[("key", "PROJ-1"), ("q", "a b")]);
+        assert_eq!(
+            substitute("https://jira/browse/{{key}}?q={{ q }}&x={{other}}", &values, percent_encode),
+            "https://jira/browse/PROJ-1?q=a%20b&x={{other}}"
+        );
+        assert_eq!(substitute("{{ unterminated", &values, raw), "{{ unterminated");
+    }
+
+    #[test]
+    fn test_windows_quote() {
+        use crate::shortcut::split_windows_arguments;
+
+        assert_eq!(windows_quote(r"C:\My Dir\"), r#""C:\My Dir\\""#);
+        assert_eq!(windows_quote(r#"say "hi"\"#), r#""say \"hi\"\\""#);
+        for value in [r"C:\My Dir\", r#"a\"b"#, r"\\server\share", "", "plain"] {
+            let line = format!("app.exe {} --next", windows_quote(value));
+            assert_eq!(split_windows_arguments(&line), vec!["app.exe", value, "--next"]);
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_with_parameters_quotes_shell_targets() {
+        let mut launcher = Launcher::with_options(
+            "grep".to_string(),
+            "Grep logs".to_string(),
+            LaunchType::Command,
+            "grep -r {{pattern}} /var/log".to_string(),
+            LaunchOptions {
+                args: vec!["--label={{pattern}}".to_string()],
+                ..LaunchOptions::default()
+            },
+        );
+        launcher.parameters = Some(vec![parameter("pattern", ParameterKind::Text { max_length: None })]);
+
+        let resolved = launcher.with_parameters(&values(&[("pattern", "it's; rm")])).unwrap();
+        assert_eq!(resolved.target, r"grep -r 'it'\''s; rm' /var/log");
+        assert_eq!(resolved.options.unwrap().args, vec!["--label=it's; rm"]);
+        assert!(resolved.parameters.is_none());
+        assert!(launcher.with_parameters(&HashMap::new()).is_err());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_with_parameters_fallbacks_and_chain_steps() {
+        use crate::fallback::FallbackTarget;
+        use crate::readiness::WaitFor;
+
+        let mut launcher = Launcher::new(
+            "dev".to_string(),
+            "Dev server".to_string(),
+            LaunchType::Chain,
+            String::new(),
+        );
+        launcher.fallbacks = Some(vec![
+            FallbackTarget { launch_type: LaunchType::App, target: "code {{dir}}".to_string() },
+            FallbackTarget { launch_type: LaunchType::Web, target: "https://dev/{{dir}}".to_string() },
+        ]);
+        launcher.chain = Some(vec![
+            ChainStep::Start { target: "npm --prefix {{dir}} run dev".to_string(), args: Vec::new() },
+            ChainStep::Wait(WaitFor::new(ReadinessProbe::LogLine {
+                path: "{{dir}}/dev.log".to_string(),
+                pattern: "ready".to_string(),
+            })),
+            ChainStep::Open { url: "http://localhost:5173/?dir={{dir}}".to_string() },
+        ]);
+        launcher.parameters = Some(vec![parameter("dir", ParameterKind::Text { max_length: None })]);
+
+        let resolved = launcher.with_parameters(&values(&[("dir", "my app")])).unwrap();
+        let fallbacks = resolved.fallbacks.unwrap();
+        assert_eq!(fallbacks[0].target, "code 'my app'");
+        assert_eq!(fallbacks[1].target, "https://dev/my%20app");
+        let steps = resolved.chain.unwrap();
+        assert_eq!(steps[0].describe(), "Start npm --prefix 'my app' run dev");
+        assert_eq!(steps[1].describe(), "Wait for 'ready' in my app/dev.log");
+        assert_eq!(steps[2].describe(), "Open http://localhost:5173/?dir=my%20app");
+    }
+
+    #[test]
+    fn test_with_parameters_refuses_option_targets() {
+        let mut launcher = Launcher::new(
+            "server".to_string(),
+            "Server".to_string(),
+            LaunchType::Ssh,
+            "{{host}}".to_string(),
+        );
+        launcher.parameters = Some(vec![parameter("host", ParameterKind::Text { max_length: None })]);
+
+        assert_eq!(launcher.with_parameters(&values(&[("host", "web-1")])).unwrap().target, "web-1");
+        assert!(launcher.with_parameters(&values(&[("host", "-oProxyCommand=sh")])).is_err());
+        assert!(launcher.with_parameters(&values(&[("host", "web-1 -v")])).is_err());
+    }
+}
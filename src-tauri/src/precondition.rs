@@ -0,0 +1,253 @@
+//! Precondition Module
+//!
+//! Checks evaluated before a launcher is spawned, so a launch that cannot
+//! work fails with an explicit error instead of silently in the background.
+//! Checks:
+//! - File exists
+//! - Binary found on PATH
+//! - host:port reachable
+//! - Environment variable set
+//! - Command exits with status 0
+
+use crate::launcher::{execute_command, LaunchOptions};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Condition checked by a precondition
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PreconditionCheck {
+    /// File or directory exists
+    FileExists { path: String },
+    /// Executable found on PATH
+    Binary { name: String },
+    /// `host:port` accepts TCP connections
+    Reachable {
+        address: String,
+        #[serde(default = "default_connect_timeout")]
+        timeout_ms: u64,
+    },
+    /// Environment variable set and not empty
+    EnvVar { name: String },
+    /// Shell command exits with status 0
+    Command { command: String },
+}
+
+fn default_connect_timeout() -> u64 {
+    2000
+}
+
+/// Precondition of a launcher, with an optional user-facing message
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Precondition {
+    /// Condition to check
+    pub check: PreconditionCheck,
+    /// Message shown when the check fails, e.g. "VPN not connected"
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl Precondition {
+    /// Precondition without a custom message
+    pub fn new(check: PreconditionCheck) -> Self {
+        Precondition { check, message: None }
+    }
+
+    /// Evaluate the precondition
+    ///
+    /// The error combines the message and the failure detail:
+    /// "VPN not connected (10.0.0.1:443 unreachable)".
+    pub async fn evaluate(&self) -> Result<(), String> {
+        let Err(detail) = self.check.evaluate().await else {
+            return Ok(());
+        };
+        Err(match &self.message {
+            Some(message) => format!("{} ({})", message, detail),
+            None => format!("Precondition failed: {}", detail),
+        })
+    }
+}
+
+impl PreconditionCheck {
+    /// Evaluate the check, returning a short failure detail
+    pub async fn evaluate(&self) -> Result<(), String> {
+        match self {
+            PreconditionCheck::FileExists { path } => {
+                if std::path::Path::new(path).exists() {
+                    Ok(())
+                } else {
+                    Err(format!("{} not found", path))
+                }
+            }
+            PreconditionCheck::Binary { name } => find_program(&[name.as_str()])
+                .map(|_| ())
+                .ok_or_else(|| format!("{} not found on PATH", name)),
+            PreconditionCheck::Reachable { address, timeout_ms } => {
+                let connect = tokio::net::TcpStream::connect(address.as_str());
+                match tokio::time::timeout(Duration::from_millis(*timeout_ms), connect).await {
+                    Ok(Ok(_)) => Ok(()),
+                    _ => Err(format!("{} unreachable", address)),
+                }
+            }
+            PreconditionCheck::EnvVar { name } => match std::env::var(name) {
+                Ok(value) if !value.is_empty() => Ok(()),
+                _ => Err(format!("{} is not set", name)),
+            },
+            PreconditionCheck::Command { command } => {
+                let options = LaunchOptions {
+                    timeout_secs: 10,
+                    ..LaunchOptions::default()
+                };
+                let output = execute_command(command, &options).await?;
+                if output.success {
+                    Ok(())
+                } else {
+                    Err(format!("'{}' exited with status {}", command, output.status_code))
+                }
+            }
+        }
+    }
+}
+
+/// Evaluate preconditions in order, stopping at the first failure
+pub async fn check_all(preconditions: &[Precondition]) -> Result<(), String> {
+    for precondition in preconditions {
+        precondition.evaluate().await?;
+    }
+    Ok(())
+}
+
+/// First of `names` found on PATH, on the host PATH when sandboxed
+pub fn find_program(names: &[&str]) -> Option<String> {
+    if crate::sandbox::is_sandboxed() {
+        return crate::sandbox::find_on_host(names);
+    }
+    names
+        .iter()
+        .find(|name| find_in_path(name).is_some())
+        .map(|name| name.to_string())
+}
+
+/// Locate an executable by name on PATH (paths containing a separator are checked as is)
+pub fn find_in_path(name: &str) -> Option<PathBuf> {
+    let candidate = std::path::Path::new(name);
+    if candidate.components().count() > 1 {
+        return is_executable(candidate).then(|| candidate.to_path_buf());
+    }
+
+    #[cfg(target_os = "windows")]
+    let extensions: Vec<String> = std::env::var("PATHEXT")
+        .unwrap_or_else(|_| ".EXE;.CMD;.BAT;.COM".to_string())
+        .split(';')
+        .map(|e| e.to_string())
+        .chain(std::iter::once(String::new()))
+        .collect();
+    #[cfg(not(target_os = "windows"))]
+    let extensions = [String::new()];
+
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path).find_map(|dir| {
+        extensions
+            .iter()
+            .map(|ext| dir.join(format!("{}{}", name, ext)))
+            .find(|p| is_executable(p))
+    })
+}
+
+#[cfg(unix)]
+fn is_executable(path: &std::path::Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &std::path::Path) -> bool {
+    path.is_file()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precondition_deserialize() {
+        let json = r#"{"check": {"kind": "reachable", "address": "10.0.0.1:443"}, "message": "VPN not connected"}"#;
+        let precondition: Precondition = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            precondition.check,
+            PreconditionCheck::Reachable {
+                address: "10.0.0.1:443".to_string(),
+                timeout_ms: 2000
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_message() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        drop(listener);
+
+        let precondition = Precondition {
+            check: PreconditionCheck::Reachable {
+                address: address.clone(),
+                timeout_ms: 500,
+            },
+            message: Some("VPN not connected".to_string()),
+        };
+        assert_eq!(
+            precondition.evaluate().await.unwrap_err(),
+            format!("VPN not connected ({} unreachable)", address)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reachable_listener() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let check = PreconditionCheck::Reachable {
+            address: listener.local_addr().unwrap().to_string(),
+            timeout_ms: 500,
+        };
+        assert!(check.evaluate().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_file_and_env_checks() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = PreconditionCheck::FileExists {
+            path: dir.path().to_string_lossy().to_string(),
+        };
+        assert!(file.evaluate().await.is_ok());
+
+        let missing = Precondition::new(PreconditionCheck::EnvVar {
+            name: "INITIUM_TEST_UNSET_VARIABLE".to_string(),
+        });
+        assert_eq!(
+            missing.evaluate().await.unwrap_err(),
+            "Precondition failed: INITIUM_TEST_UNSET_VARIABLE is not set"
+        );
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_binary_and_command_checks() {
+        assert!(find_in_path("sh").is_some());
+        assert!(find_in_path("initium-missing-binary").is_none());
+
+        let preconditions = vec![
+            Precondition::new(PreconditionCheck::Command {
+                command: "true".to_string(),
+            }),
+            Precondition::new(PreconditionCheck::Command {
+                command: "exit 3".to_string(),
+            }),
+        ];
+        assert_eq!(
+            check_all(&preconditions).await.unwrap_err(),
+            "Precondition failed: 'exit 3' exited with status 3"
+        );
+    }
+}
@@ -0,0 +1,470 @@
+//! Project Module
+//!
+//! Discovers the git repositories under the configured project roots and
+//! keeps a set of launchers per repository.
+//! Features:
+//! - Roots walked up to a depth limit, ignore patterns and hidden directories skipped
+//! - Project type detected from Cargo.toml, package.json or pyproject.toml
+//! - Launchers to open the repository in the editor, a terminal in it, and its remote web page
+//! - Re-scans update the launchers of known repositories instead of adding new ones
+
+use crate::launcher::{generate_unique_id, LaunchOptions, LaunchType, Launcher};
+use crate::parameter::shell_quote;
+use crate::precondition::find_program;
+use crate::ssh::wildcard_match;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Editors tried by auto-detection, in order
+pub const KNOWN_EDITORS: [&str; 7] = ["code", "codium", "zed", "subl", "kate", "gnome-text-editor", "gedit"];
+
+/// Directories never descended into by default
+pub const DEFAULT_IGNORE: [&str; 6] = ["node_modules", "target", "vendor", "build", "dist", "__pycache__"];
+
+/// Category given to the launchers of discovered projects
+pub const PROJECT_CATEGORY: &str = "Projects";
+
+/// Project discovery settings
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ProjectSettings {
+    /// Directories walked for git repositories (`~` expanded)
+    pub roots: Vec<String>,
+    /// Depth below a root at which the walk stops
+    pub max_depth: usize,
+    /// Directory name patterns skipped (`*` and `?` wildcards), full path when they contain a `/`
+    pub ignore: Vec<String>,
+    /// Editor command opening a project directory, auto-detected when unset
+    pub editor: Option<String>,
+}
+
+impl Default for ProjectSettings {
+    fn default() -> Self {
+        ProjectSettings {
+            roots: Vec::new(),
+            max_depth: 3,
+            ignore: DEFAULT_IGNORE.iter().map(|p| p.to_string()).collect(),
+            editor: None,
+        }
+    }
+}
+
+impl ProjectSettings {
+    /// Configured roots with `~` expanded
+    pub fn root_paths(&self) -> Vec<PathBuf> {
+        self.roots
+            .iter()
+            .map(|r| r.trim())
+            .filter(|r| !r.is_empty())
+            .map(|r| match r.strip_prefix("~/") {
+                Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+                None => PathBuf::from(r),
+            })
+            .collect()
+    }
+
+    /// Configured editor, auto-detected when unset
+    pub fn editor_command(&self) -> Option<String> {
+        self.editor
+            .clone()
+            .filter(|e| !e.trim().is_empty())
+            .or_else(detect_editor)
+    }
+
+    fn is_ignored(&self, path: &Path) -> bool {
+        let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        let full = path.to_string_lossy();
+        self.ignore.iter().map(|p| p.trim()).filter(|p| !p.is_empty()).any(|pattern| {
+            if pattern.contains('/') {
+                wildcard_match(pattern, &full)
+            } else {
+                wildcard_match(pattern, &name)
+            }
+        })
+    }
+}
+
+/// First known editor found on PATH
+pub fn detect_editor() -> Option<String> {
+    find_program(&KNOWN_EDITORS)
+}
+
+/// Type of project, from its manifest file
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectKind {
+    Rust,
+    Node,
+    Python,
+    Other,
+}
+
+impl ProjectKind {
+    /// Type of the project in `dir`
+    pub fn detect(dir: &Path) -> Self {
+        const MARKERS: [(&str, ProjectKind); 4] = [
+            ("Cargo.toml", ProjectKind::Rust),
+            ("package.json", ProjectKind::Node),
+            ("pyproject.toml", ProjectKind::Python),
+            ("setup.py", ProjectKind::Python),
+        ];
+        MARKERS
+            .iter()
+            .find(|(file, _)| dir.join(file).is_file())
+            .map(|(_, kind)| *kind)
+            .unwrap_or(ProjectKind::Other)
+    }
+}
+
+/// Launcher generated for a project
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectAction {
+    /// Open the directory in the editor
+    Editor,
+    /// Open a terminal in the directory
+    Terminal,
+    /// Open the web page of the remote
+    Remote,
+}
+
+/// Repository and action a launcher was generated for, to find it again on re-scans
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProjectLink {
+    pub path: String,
+    pub action: ProjectAction,
+}
+
+/// Git repository found under a project root
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Project {
+    pub path: String,
+    /// Directory name
+    pub name: String,
+    pub kind: ProjectKind,
+    /// Web page of the `origin` remote (or the first remote)
+    pub remote_url: Option<String>,
+}
+
+impl Project {
+    /// Project of a repository directory
+    pub fn read(dir: &Path) -> Self {
+        let remote_url = git_config_path(dir)
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|config| parse_remote_url(&config))
+            .and_then(|remote| remote_web_url(&remote));
+        Project {
+            path: dir.to_string_lossy().to_string(),
+            name: dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+            kind: ProjectKind::detect(dir),
+            remote_url,
+        }
+    }
+
+    /// Name, type and target of the launcher of an action, `None` when it does not apply
+    pub fn launcher_spec(&self, action: ProjectAction, editor: Option<&str>) -> Option<(String, LaunchType, String)> {
+        let path = quote_path(&self.path);
+        match action {
+            ProjectAction::Editor => {
+                let editor = editor?.trim();
+                (!editor.is_empty()).then(|| (self.name.clone(), LaunchType::App, format!("{} {}", editor, path)))
+            }
+            ProjectAction::Terminal => Some((
+                format!("{} (terminal)", self.name),
+                LaunchType::App,
+                format!("cd {} && exec \"${{SHELL:-sh}}\"", path),
+            )),
+            ProjectAction::Remote => {
+                let url = self.remote_url.clone()?;
+                Some((format!("{} (remote)", self.name), LaunchType::Web, url))
+            }
+        }
+    }
+}
+
+/// Path quoted for a shell command line when it needs it
+fn quote_path(path: &str) -> String {
+    if path.chars().all(|c| c.is_ascii_alphanumeric() || "/._-+".contains(c)) {
+        path.to_string()
+    } else {
+        shell_quote(path)
+    }
+}
+
+/// Whether a directory is the top of a git repository or worktree
+pub fn is_repository(dir: &Path) -> bool {
+    dir.join(".git").exists()
+}
+
+/// Config file of a repository, following the `gitdir:` file of worktrees and submodules
+pub fn git_config_path(repo: &Path) -> Option<PathBuf> {
+    let dot_git = repo.join(".git");
+    let git_dir = if dot_git.is_dir() {
+        dot_git
+    } else {
+        let content = std::fs::read_to_string(&dot_git).ok()?;
+        let git_dir = repo.join(content.lines().find_map(|l| l.strip_prefix("gitdir:"))?.trim());
+        // Worktrees share the config of the main repository
+        match std::fs::read_to_string(git_dir.join("commondir")) {
+            Ok(common) => git_dir.join(common.trim()),
+            Err(_) => git_dir,
+        }
+    };
+    Some(git_dir.join("config"))
+}
+
+/// URL of the `origin` remote in a git config, or of the first remote
+pub fn parse_remote_url(config: &str) -> Option<String> {
+    let mut remotes: Vec<(String, String)> = Vec::new();
+    let mut current: Option<String> = None;
+    for line in config.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if line.starts_with('[') {
+            current = line
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .trim()
+                .strip_prefix("remote ")
+                .map(|name| name.trim().trim_matches('"').to_string());
+            continue;
+        }
+        let (Some(remote), Some((key, value))) = (&current, line.split_once('=')) else {
+            continue;
+        };
+        if key.trim().eq_ignore_ascii_case("url") && !remotes.iter().any(|(name, _)| name == remote) {
+            remotes.push((remote.clone(), value.trim().trim_matches('"').to_string()));
+        }
+    }
+    remotes
+        .iter()
+        .find(|(name, _)| name == "origin")
+        .or_else(|| remotes.first())
+        .map(|(_, url)| url.clone())
+}
+
+/// Web page of a remote URL (`git@host:owner/repo.git` becomes `https://host/owner/repo`)
+///
+/// Local and `file://` remotes have no web page.
+pub fn remote_web_url(remote: &str) -> Option<String> {
+    let remote = remote.trim();
+    let (scheme, rest) = match remote.split_once("://") {
+        Some(("http", rest)) => ("http", rest),
+        Some(("https", rest)) => ("https", rest),
+        Some(("ssh" | "git" | "git+ssh" | "ssh+git", rest)) => ("https", rest),
+        Some(_) => return None,
+        None => {
+            // scp-like syntax, a colon before any slash
+            let (host, path) = remote.split_once(':')?;
+            if host.is_empty() || host.contains('/') {
+                return None;
+            }
+            let host = host.rsplit('@').next().unwrap_or(host);
+            return Some(format!("https://{}/{}", host, trim_repo_path(path)));
+        }
+    };
+    let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = authority.rsplit('@').next().unwrap_or(authority);
+    // The SSH port is not the port of the web page
+    let host = if scheme == "https" && !remote.starts_with("https") {
+        host.split(':').next().unwrap_or(host)
+    } else {
+        host
+    };
+    if host.is_empty() {
+        return None;
+    }
+    Some(format!("{}://{}/{}", scheme, host, trim_repo_path(path)))
+}
+
+fn trim_repo_path(path: &str) -> &str {
+    let path = path.trim_matches('/');
+    path.strip_suffix(".git").unwrap_or(path)
+}
+
+fn walk(dir: &Path, depth: usize, settings: &ProjectSettings, found: &mut Vec<PathBuf>) {
+    if is_repository(dir) {
+        found.push(dir.to_path_buf());
+        return;
+    }
+    if depth >= settings.max_depth {
+        return;
+    }
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return;
+    };
+    // Symbolic links are not followed, they could loop
+    let mut children: Vec<PathBuf> = read_dir
+        .flatten()
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|e| e.path())
+        .filter(|p| !p.file_name().is_some_and(|n| n.to_string_lossy().starts_with('.')))
+        .filter(|p| !settings.is_ignored(p))
+        .collect();
+    children.sort();
+    for child in children {
+        walk(&child, depth + 1, settings, found);
+    }
+}
+
+/// Git repositories under the configured roots, nested repositories not included
+pub fn find_repositories(settings: &ProjectSettings) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    for root in settings.root_paths() {
+        walk(&root, 0, settings, &mut found);
+    }
+    found.dedup();
+    found
+}
+
+/// Projects of the configured roots
+pub fn scan(settings: &ProjectSettings) -> Vec<Project> {
+    let projects: Vec<Project> = find_repositories(settings).iter().map(|dir| Project::read(dir)).collect();
+    log::info!("Found {} project(s)", projects.len());
+    projects
+}
+
+/// Result of a project synchronisation
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct ProjectSync {
+    pub added: usize,
+    /// Launchers whose target changed
+    pub updated: usize,
+}
+
+/// Add the launchers of new projects and update the targets of known ones
+///
+/// Names, icons and categories of existing launchers are left to the user.
+pub fn sync_launchers(launchers: &mut Vec<Launcher>, projects: &[Project], editor: Option<&str>) -> ProjectSync {
+    let mut summary = ProjectSync::default();
+    for project in projects {
+        for action in [ProjectAction::Editor, ProjectAction::Terminal, ProjectAction::Remote] {
+            let Some((name, launch_type, target)) = project.launcher_spec(action, editor) else {
+                continue;
+            };
+            let link = ProjectLink {
+                path: project.path.clone(),
+                action,
+            };
+            match launchers.iter_mut().find(|l| l.project.as_ref() == Some(&link)) {
+                Some(launcher) => {
+                    if launcher.target != target {
+                        launcher.target = target;
+                        summary.updated += 1;
+                    }
+                }
+                None => {
+                    let existing_ids: Vec<String> = launchers.iter().map(|l| l.id.clone()).collect();
+                    let id = generate_unique_id(&name, &existing_ids);
+                    let mut launcher = Launcher::new(id, name, launch_type, target);
+                    launcher.category = Some(PROJECT_CATEGORY.to_string());
+                    if action == ProjectAction::Terminal {
+                        launcher.options = Some(LaunchOptions {
+                            terminal: true,
+                            ..LaunchOptions::default()
+                        });
+                    }
+                    launcher.project = Some(link);
+                    launchers.push(launcher);
+                    summary.added += 1;
+                }
+            }
+        }
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init_repo(dir: &Path, config: &str) {
+        std::fs::create_dir_all(dir.join(".git")).unwrap();
+        std::fs::write(dir.join(".git/config"), config).unwrap();
+    }
+
+    #[test]
+    fn test_remote_web_url() {
+        let cases = [
+            ("git@github.com:owner/repo.git", Some("https://github.com/owner/repo")),
+            ("https://user@gitlab.com/group/sub/repo.git", Some("https://gitlab.com/group/sub/repo")),
+            ("ssh://git@git.example.com:2222/owner/repo.git", Some("https://git.example.com/owner/repo")),
+            ("http://localhost:3000/owner/repo", Some("http://localhost:3000/owner/repo")),
+            ("/srv/git/repo.git", None),
+            ("file:///srv/git/repo.git", None),
+        ];
+        for (remote, expected) in cases {
+            assert_eq!(remote_web_url(remote).as_deref(), expected, "{}", remote);
+        }
+    }
+
+    #[test]
+    fn test_parse_remote_url() {
+        let config = "[core]\n\tbare = false\n[remote \"upstream\"]\n\turl = https://github.com/up/repo.git\n[remote \"origin\"]\n\turl = git@github.com:me/repo.git\n\tfetch = +refs/heads/*:refs/remotes/origin/*\n";
+        assert_eq!(parse_remote_url(config).as_deref(), Some("git@github.com:me/repo.git"));
+        assert_eq!(
+            parse_remote_url("[remote \"upstream\"]\nurl = https://github.com/up/repo.git\n").as_deref(),
+            Some("https://github.com/up/repo.git")
+        );
+        assert_eq!(parse_remote_url("[core]\nbare = false\n"), None);
+    }
+
+    #[test]
+    fn test_find_repositories() {
+        let root = tempfile::tempdir().unwrap();
+        let api = root.path().join("work/api");
+        init_repo(&api, "[remote \"origin\"]\n\turl = git@github.com:me/api.git\n");
+        std::fs::write(api.join("Cargo.toml"), "").unwrap();
+        // Nested repositories belong to their parent
+        init_repo(&api.join("vendor-lib"), "");
+        init_repo(&root.path().join("node_modules/dep"), "");
+        init_repo(&root.path().join(".cache/repo"), "");
+        init_repo(&root.path().join("a/b/c/too-deep"), "");
+        // Worktree pointing at the main repository
+        let worktree = root.path().join("api-feature");
+        std::fs::create_dir_all(api.join(".git/worktrees/feature")).unwrap();
+        std::fs::write(api.join(".git/worktrees/feature/commondir"), "../..\n").unwrap();
+        std::fs::create_dir_all(&worktree).unwrap();
+        std::fs::write(worktree.join(".git"), format!("gitdir: {}\n", api.join(".git/worktrees/feature").display())).unwrap();
+        std::fs::write(worktree.join("package.json"), "{}").unwrap();
+
+        let settings = ProjectSettings {
+            roots: vec![root.path().to_string_lossy().to_string()],
+            ..ProjectSettings::default()
+        };
+        let projects = scan(&settings);
+        assert_eq!(projects.len(), 2);
+        assert_eq!(projects[0].name, "api-feature");
+        assert_eq!(projects[0].kind, ProjectKind::Node);
+        assert_eq!(projects[0].remote_url.as_deref(), Some("https://github.com/me/api"));
+        assert_eq!(projects[1].name, "api");
+        assert_eq!(projects[1].kind, ProjectKind::Rust);
+    }
+
+    #[test]
+    fn test_sync_updates_instead_of_duplicating() {
+        let mut project = Project {
+            path: "/home/me/src/my app".to_string(),
+            name: "my app".to_string(),
+            kind: ProjectKind::Other,
+            remote_url: None,
+        };
+        let mut launchers = Vec::new();
+        let summary = sync_launchers(&mut launchers, std::slice::from_ref(&project), Some("code"));
+        assert_eq!(summary, ProjectSync { added: 2, updated: 0 });
+        assert_eq!(launchers[0].target, "code '/home/me/src/my app'");
+        assert_eq!(launchers[1].target, "cd '/home/me/src/my app' && exec \"${SHELL:-sh}\"");
+        assert!(launchers[1].options.as_ref().unwrap().terminal);
+
+        launchers[0].name = "Renamed".to_string();
+        project.remote_url = Some("https://github.com/me/my-app".to_string());
+        let summary = sync_launchers(&mut launchers, &[project], Some("zed"));
+        assert_eq!(summary, ProjectSync { added: 1, updated: 1 });
+        assert_eq!(launchers.len(), 3);
+        assert_eq!(launchers[0].name, "Renamed");
+        assert_eq!(launchers[0].target, "zed '/home/me/src/my app'");
+        assert_eq!(launchers[2].launch_type, LaunchType::Web);
+        assert_eq!(launchers[2].category.as_deref(), Some(PROJECT_CATEGORY));
+    }
+}
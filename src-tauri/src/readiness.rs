@@ -0,0 +1,416 @@
+//! Readiness Probe Module
+//!
+//! Waits for a started service to become ready before the next chain step.
+//! Probes:
+//! - TCP port accepting connections
+//! - HTTP endpoint returning 2xx (plain `http://` only, rejected at validation otherwise)
+//! - File appearing on disk
+//! - Log file containing a line, among the lines appended while waiting
+
+use hyper_util::rt::TokioIo;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Readiness condition checked by a probe
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReadinessProbe {
+    /// `host:port` accepts TCP connections
+    Tcp { address: String },
+    /// HTTP GET returns a 2xx status
+    Http { url: String },
+    /// File exists
+    File { path: String },
+    /// Log file gets a line including `pattern`
+    LogLine { path: String, pattern: String },
+}
+
+/// Probe with its timeout and polling interval
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WaitFor {
+    /// Condition to wait for
+    pub probe: ReadinessProbe,
+    /// Maximum wait in seconds (default: 30)
+    #[serde(default = "default_wait_timeout")]
+    pub timeout_secs: u64,
+    /// Delay between two checks in milliseconds (default: 250)
+    #[serde(default = "default_interval")]
+    pub interval_ms: u64,
+}
+
+fn default_wait_timeout() -> u64 {
+    30
+}
+
+fn default_interval() -> u64 {
+    250
+}
+
+impl WaitFor {
+    /// Wait for a probe with the default timeout and interval
+    pub fn new(probe: ReadinessProbe) -> Self {
+        WaitFor {
+            probe,
+            timeout_secs: default_wait_timeout(),
+            interval_ms: default_interval(),
+        }
+    }
+}
+
+impl ReadinessProbe {
+    /// Check that the probe can be run, e.g. that an HTTP URL is plain `http://`
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            ReadinessProbe::Http { url } => parse_http_url(url).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Display for ReadinessProbe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadinessProbe::Tcp { address } => write!(f, "TCP {}", address),
+            ReadinessProbe::Http { url } => write!(f, "HTTP {}", url),
+            ReadinessProbe::File { path } => write!(f, "file {}", path),
+            ReadinessProbe::LogLine { path, pattern } => write!(f, "'{}' in {}", pattern, path),
+        }
+    }
+}
+
+/// Read position in a log file, so only the lines appended after it are matched
+#[derive(Debug, Default)]
+struct LogCursor {
+    offset: u64,
+    /// File identity, to notice a rotation
+    file_id: Option<u64>,
+    /// Last line, still being written
+    partial: Vec<u8>,
+}
+
+#[cfg(unix)]
+fn file_id(metadata: &std::fs::Metadata) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    Some(metadata.ino())
+}
+
+#[cfg(not(unix))]
+fn file_id(_metadata: &std::fs::Metadata) -> Option<u64> {
+    None
+}
+
+impl LogCursor {
+    /// Cursor at the current end of the file, at its start when it does not exist yet
+    fn at_end(path: &Path) -> Self {
+        match std::fs::metadata(path) {
+            Ok(metadata) => LogCursor {
+                offset: metadata.len(),
+                file_id: file_id(&metadata),
+                partial: Vec::new(),
+            },
+            Err(_) => LogCursor::default(),
+        }
+    }
+
+    /// Whether a line appended since the last call contains `pattern`
+    ///
+    /// A truncated or replaced (rotated) file is read again from its start.
+    fn appended_line_contains(&mut self, path: &Path, pattern: &str) -> bool {
+        let Ok(mut file) = std::fs::File::open(path) else {
+            return false;
+        };
+        let Ok(metadata) = file.metadata() else {
+            return false;
+        };
+        let id = file_id(&metadata);
+        if metadata.len() < self.offset || (self.file_id.is_some() && id != self.file_id) {
+            self.offset = 0;
+            self.partial.clear();
+        }
+        self.file_id = id;
+
+        let mut appended = Vec::new();
+        if file.seek(SeekFrom::Start(self.offset)).is_err() || file.read_to_end(&mut appended).is_err() {
+            return false;
+        }
+        self.offset += appended.len() as u64;
+        self.partial.extend_from_slice(&appended);
+
+        let found = self
+            .partial
+            .split(|b| *b == b'\n')
+            .any(|line| String::from_utf8_lossy(line).contains(pattern));
+        if let Some(end) = self.partial.iter().rposition(|b| *b == b'\n') {
+            self.partial.drain(..=end);
+        }
+        found
+    }
+}
+
+/// Check a probe once (a log file is searched entirely)
+pub async fn check(probe: &ReadinessProbe, attempt_timeout: Duration) -> bool {
+    match probe {
+        ReadinessProbe::Tcp { address } => {
+            matches!(
+                tokio::time::timeout(attempt_timeout, tokio::net::TcpStream::connect(address.as_str())).await,
+                Ok(Ok(_))
+            )
+        }
+        ReadinessProbe::Http { url } => {
+            matches!(
+                tokio::time::timeout(attempt_timeout, http_status(url)).await,
+                Ok(Ok(status)) if (200..300).contains(&status)
+            )
+        }
+        ReadinessProbe::File { path } => std::path::Path::new(path).exists(),
+        ReadinessProbe::LogLine { path, pattern } => {
+            LogCursor::default().appended_line_contains(Path::new(path), pattern)
+        }
+    }
+}
+
+/// Poll a probe until it succeeds or the timeout expires
+pub async fn wait_until_ready(wait: &WaitFor) -> Result<(), String> {
+    wait.probe.validate()?;
+    log::info!("Waiting for {} (timeout {} s)", wait.probe, wait.timeout_secs);
+
+    let started = Instant::now();
+    let timeout = Duration::from_secs(wait.timeout_secs);
+    let interval = Duration::from_millis(wait.interval_ms.max(10));
+    // Lines already in the log may come from a previous run
+    let mut log = match &wait.probe {
+        ReadinessProbe::LogLine { path, .. } => Some(LogCursor::at_end(Path::new(path))),
+        _ => None,
+    };
+
+    loop {
+        let ready = match (&wait.probe, log.as_mut()) {
+            (ReadinessProbe::LogLine { path, pattern }, Some(cursor)) => {
+                cursor.appended_line_contains(Path::new(path), pattern)
+            }
+            (probe, _) => check(probe, interval.max(Duration::from_secs(1))).await,
+        };
+        if ready {
+            log::info!("{} ready after {} ms", wait.probe, started.elapsed().as_millis());
+            return Ok(());
+        }
+        if started.elapsed() >= timeout {
+            let msg = format!("Timed out after {} s waiting for {}", wait.timeout_secs, wait.probe);
+            log::error!("{}", msg);
+            return Err(msg);
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Split an `http://host[:port]/path` URL into (host, port, path)
+fn parse_http_url(url: &str) -> Result<(String, u16, String), String> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("Only http:// URLs can be probed: {}", url))?;
+    let (authority, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    // Bracketed IPv6 hosts contain colons of their own
+    let (host, port) = match authority.strip_prefix('[') {
+        Some(bracketed) => {
+            let end = bracketed
+                .find(']')
+                .ok_or_else(|| format!("Invalid IPv6 host in URL: {}", url))?;
+            (&authority[..end + 2], &bracketed[end + 1..])
+        }
+        None => match authority.find(':') {
+            Some(i) => (&authority[..i], &authority[i..]),
+            None => (authority, ""),
+        },
+    };
+    let port = match port {
+        "" => 80,
+        port => port
+            .strip_prefix(':')
+            .and_then(|p| p.parse::<u16>().ok())
+            .ok_or_else(|| format!("Invalid port in URL: {}", url))?,
+    };
+    if host.is_empty() {
+        return Err(format!("Missing host in URL: {}", url));
+    }
+    Ok((host.to_string(), port, path.to_string()))
+}
+
+/// Send a GET request and return the response status code
+async fn http_status(url: &str) -> Result<u16, String> {
+    let (host, port, path) = parse_http_url(url)?;
+    let stream = tokio::net::TcpStream::connect((host.trim_matches(['[', ']']), port))
+        .await
+        .map_err(|e| e.to_string())?;
+    let (mut sender, connection) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+        .await
+        .map_err(|e| format!("HTTP handshake failed: {}", e))?;
+    tokio::spawn(connection);
+
+    let authority = if port == 80 { host } else { format!("{}:{}", host, port) };
+    let request = hyper::Request::get(path)
+        .header(hyper::header::HOST, authority)
+        .header(hyper::header::USER_AGENT, "initium")
+        .body(String::new())
+        .map_err(|e| format!("Invalid HTTP request for {}: {}", url, e))?;
+    let response = sender
+        .send_request(request)
+        .await
+        .map_err(|e| format!("HTTP request failed: {}", e))?;
+    Ok(response.status().as_u16())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    fn quick(probe: ReadinessProbe) -> WaitFor {
+        WaitFor {
+            probe,
+            timeout_secs: 1,
+            interval_ms: 20,
+        }
+    }
+
+    /// Serve one canned HTTP response per connection
+    async fn http_server(status_line: &'static str) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buffer = [0u8; 1024];
+                let _ = socket.read(&mut buffer).await;
+                let response = format!("{}\r\nContent-Length: 0\r\n\r\n", status_line);
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        format!("http://{}/health", address)
+    }
+
+    #[test]
+    fn test_parse_http_url() {
+        assert_eq!(
+            parse_http_url("http://localhost:5173/api").unwrap(),
+            ("localhost".to_string(), 5173, "/api".to_string())
+        );
+        assert_eq!(
+            parse_http_url("http://example.com").unwrap(),
+            ("example.com".to_string(), 80, "/".to_string())
+        );
+        assert_eq!(
+            parse_http_url("http://[::1]:8080/").unwrap(),
+            ("[::1]".to_string(), 8080, "/".to_string())
+        );
+        assert_eq!(
+            parse_http_url("http://[fe80::1]/ready").unwrap(),
+            ("[fe80::1]".to_string(), 80, "/ready".to_string())
+        );
+        assert!(parse_http_url("http://[::1:8080/").is_err());
+        assert!(parse_http_url("http://localhost:http/").is_err());
+        assert!(parse_http_url("https://example.com").is_err());
+    }
+
+    #[test]
+    fn test_probe_deserialize() {
+        let json = r#"{"probe": {"kind": "tcp", "address": "localhost:5173"}}"#;
+        let wait: WaitFor = serde_json::from_str(json).unwrap();
+        assert_eq!(wait.timeout_secs, 30);
+        assert_eq!(
+            wait.probe,
+            ReadinessProbe::Tcp {
+                address: "localhost:5173".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tcp_probe() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let probe = ReadinessProbe::Tcp { address };
+        assert!(wait_until_ready(&quick(probe.clone())).await.is_ok());
+
+        drop(listener);
+        let error = wait_until_ready(&quick(probe)).await.unwrap_err();
+        assert!(error.contains("Timed out"));
+    }
+
+    #[tokio::test]
+    async fn test_http_probe() {
+        let url = http_server("HTTP/1.1 204 No Content").await;
+        assert!(wait_until_ready(&quick(ReadinessProbe::Http { url })).await.is_ok());
+
+        let url = http_server("HTTP/1.1 503 Service Unavailable").await;
+        assert!(wait_until_ready(&quick(ReadinessProbe::Http { url })).await.is_err());
+
+        // Unsupported URLs fail at once instead of timing out
+        let probe = ReadinessProbe::Http { url: "https://localhost:5173".to_string() };
+        assert!(probe.validate().is_err());
+        let started = Instant::now();
+        assert!(wait_until_ready(&WaitFor::new(probe)).await.is_err());
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_file_probe_waits_for_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ready");
+        let probe = ReadinessProbe::File {
+            path: path.to_string_lossy().to_string(),
+        };
+        let writer = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            std::fs::write(path, "").unwrap();
+        });
+        assert!(wait_until_ready(&quick(probe)).await.is_ok());
+        writer.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_log_line_probe() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("server.log");
+        std::fs::write(&path, "starting\n").unwrap();
+        let probe = ReadinessProbe::LogLine {
+            path: path.to_string_lossy().to_string(),
+            pattern: "Listening on".to_string(),
+        };
+        assert!(!check(&probe, Duration::from_secs(1)).await);
+        let appender = {
+            let path = path.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                std::fs::write(path, "starting\nListening on :5173\n").unwrap();
+            })
+        };
+        assert!(wait_until_ready(&quick(probe)).await.is_ok());
+        appender.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_log_line_probe_ignores_previous_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("server.log");
+        std::fs::write(&path, "Listening on :5173\n").unwrap();
+        let probe = ReadinessProbe::LogLine {
+            path: path.to_string_lossy().to_string(),
+            pattern: "Listening on".to_string(),
+        };
+        assert!(check(&probe, Duration::from_secs(1)).await);
+        assert!(wait_until_ready(&quick(probe.clone())).await.is_err());
+
+        // Lines appended after the wait started, or a rotated log, count
+        let mut cursor = LogCursor::at_end(&path);
+        assert!(!cursor.appended_line_contains(&path, "Listening on"));
+        std::fs::write(&path, "Listen").unwrap();
+        assert!(!cursor.appended_line_contains(&path, "Listening on"));
+        std::fs::write(&path, "Listening on :5174\n").unwrap();
+        assert!(cursor.appended_line_contains(&path, "Listening on"));
+        assert!(!cursor.appended_line_contains(&path, "Listening on"));
+    }
+}
@@ -0,0 +1,243 @@
+//! Sandbox Module
+//!
+//! When Initium runs inside a Flatpak (or a toolbox-like container), programs
+//! spawned directly would start inside the sandbox, where the launched
+//! applications are not installed.
+//! Features:
+//! - Sandbox detected from `/.flatpak-info`, `container=flatpak`, or a
+//!   container providing `flatpak-spawn`
+//! - Spawns routed through `flatpak-spawn --host`, environment passed with `--env`
+//! - Programs looked up on the host PATH
+//! - URLs opened through the OpenURI desktop portal
+
+use crate::system::command_runner::{platform_runner, CommandOutput, OSCommandRunner};
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// File present at the root of every Flatpak sandbox
+pub const FLATPAK_INFO_PATH: &str = "/.flatpak-info";
+
+/// Timeout of the short-lived host processes (lookups, checks)
+const HOST_CHECK_TIMEOUT_SECS: u64 = 10;
+
+/// Print the first of the argument programs found on PATH
+const FIND_SCRIPT: &str = "for name; do if command -v \"$name\" >/dev/null 2>&1; then echo \"$name\"; exit 0; fi; done; exit 1";
+
+/// Environment the sandbox detection is based on
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SandboxEnv {
+    /// `/.flatpak-info` exists
+    pub flatpak_info: bool,
+    /// Value of the `container` variable (`flatpak`, `oci`, `podman`...)
+    pub container: Option<String>,
+    /// `flatpak-spawn` is on PATH
+    pub flatpak_spawn: bool,
+}
+
+impl SandboxEnv {
+    /// Environment of the current process
+    pub fn detect() -> Self {
+        if !cfg!(target_os = "linux") {
+            return SandboxEnv::default();
+        }
+        SandboxEnv {
+            flatpak_info: Path::new(FLATPAK_INFO_PATH).exists(),
+            container: std::env::var("container").ok(),
+            flatpak_spawn: crate::precondition::find_in_path("flatpak-spawn").is_some(),
+        }
+    }
+
+    /// Whether spawned programs must be sent to the host
+    ///
+    /// Other containers (`oci`, `podman`...) only count when they provide
+    /// `flatpak-spawn`, like toolbox; otherwise programs run in the container.
+    pub fn is_sandboxed(&self) -> bool {
+        match self.container.as_deref().map(str::trim) {
+            _ if self.flatpak_info => true,
+            Some("flatpak") => true,
+            Some(container) => !container.is_empty() && self.flatpak_spawn,
+            None => false,
+        }
+    }
+}
+
+/// Environment of the current process, detected once
+pub fn current() -> &'static SandboxEnv {
+    static CURRENT: OnceLock<SandboxEnv> = OnceLock::new();
+    CURRENT.get_or_init(|| {
+        let env = SandboxEnv::detect();
+        if env.is_sandboxed() {
+            log::info!("Running sandboxed, launchers are spawned on the host");
+        }
+        env
+    })
+}
+
+/// Whether Initium runs sandboxed
+pub fn is_sandboxed() -> bool {
+    current().is_sandboxed()
+}
+
+/// Program, arguments and environment of a process to start on the host
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    /// Variables set on the spawned process
+    pub env_vars: Vec<(String, String)>,
+}
+
+impl HostCommand {
+    /// Host command in the given environment, wrapped in `flatpak-spawn --host` when sandboxed
+    pub fn for_environment(env: &SandboxEnv, program: &str, args: &[String], env_vars: &[(String, String)]) -> Self {
+        if !env.is_sandboxed() {
+            return HostCommand {
+                program: program.to_string(),
+                args: args.to_vec(),
+                env_vars: env_vars.to_vec(),
+            };
+        }
+        // Variables of flatpak-spawn itself would stay in the sandbox
+        let mut host_args = vec!["--host".to_string()];
+        host_args.extend(env_vars.iter().map(|(key, value)| format!("--env={}={}", key, value)));
+        host_args.push(program.to_string());
+        host_args.extend(args.iter().cloned());
+        HostCommand {
+            program: "flatpak-spawn".to_string(),
+            args: host_args,
+            env_vars: Vec::new(),
+        }
+    }
+
+    /// Host command in the current environment
+    pub fn new(program: &str, args: &[String], env_vars: &[(String, String)]) -> Self {
+        Self::for_environment(current(), program, args, env_vars)
+    }
+
+    /// Process ready to be spawned
+    pub fn command(&self) -> std::process::Command {
+        let mut cmd = std::process::Command::new(&self.program);
+        cmd.args(&self.args);
+        for (key, value) in &self.env_vars {
+            cmd.env(key, value);
+        }
+        cmd
+    }
+}
+
+/// Run a short-lived program on the host
+fn run_on_host(program: &str, args: &[String]) -> Result<CommandOutput, String> {
+    let host = HostCommand::new(program, args, &[]);
+    let args: Vec<&str> = host.args.iter().map(String::as_str).collect();
+    platform_runner().execute_with_output(&host.program, &args, HOST_CHECK_TIMEOUT_SECS)
+}
+
+/// Whether a program run on the host exits successfully
+pub fn host_succeeds(program: &str, args: &[String]) -> bool {
+    run_on_host(program, args).is_ok_and(|output| output.success)
+}
+
+/// First of `names` found on the host PATH, in a single host process
+pub fn find_on_host(names: &[&str]) -> Option<String> {
+    let mut args = vec!["-c".to_string(), FIND_SCRIPT.to_string(), "sh".to_string()];
+    args.extend(names.iter().map(|name| name.to_string()));
+    let output = run_on_host("sh", &args).ok().filter(|output| output.success)?;
+    let found = output.stdout.trim();
+    (!found.is_empty()).then(|| found.to_string())
+}
+
+/// Open a URL with the OpenURI portal, which asks the host to pick the handler
+#[cfg(target_os = "linux")]
+pub async fn open_uri(url: &str) -> Result<(), String> {
+    use std::collections::HashMap;
+    use zbus::zvariant::Value;
+
+    log::info!("Opening URL through the OpenURI portal: {}", url);
+    let connection = zbus::Connection::session()
+        .await
+        .map_err(|e| format!("Failed to connect to the session bus: {}", e))?;
+    let options: HashMap<&str, Value> = HashMap::new();
+    connection
+        .call_method(
+            Some("org.freedesktop.portal.Desktop"),
+            "/org/freedesktop/portal/desktop",
+            Some("org.freedesktop.portal.OpenURI"),
+            "OpenURI",
+            &("", url, options),
+        )
+        .await
+        .map_err(|e| format!("Failed to open URL through the portal: {}", e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_detection() {
+        assert!(!SandboxEnv::default().is_sandboxed());
+        let flatpak = SandboxEnv {
+            flatpak_info: true,
+            ..SandboxEnv::default()
+        };
+        assert!(flatpak.is_sandboxed());
+        let flatpak_variable = SandboxEnv {
+            container: Some("flatpak".to_string()),
+            ..SandboxEnv::default()
+        };
+        assert!(flatpak_variable.is_sandboxed());
+        let docker = SandboxEnv {
+            container: Some("docker".to_string()),
+            ..SandboxEnv::default()
+        };
+        assert!(!docker.is_sandboxed());
+        let toolbox = SandboxEnv {
+            container: Some("oci".to_string()),
+            flatpak_spawn: true,
+            ..SandboxEnv::default()
+        };
+        assert!(toolbox.is_sandboxed());
+        let empty = SandboxEnv {
+            container: Some(String::new()),
+            flatpak_spawn: true,
+            ..SandboxEnv::default()
+        };
+        assert!(!empty.is_sandboxed());
+    }
+
+    #[test]
+    fn test_host_command_outside_sandbox() {
+        let env_vars = vec![("LANG".to_string(), "C".to_string())];
+        let host = HostCommand::for_environment(&SandboxEnv::default(), "gimp", &args(&["a.png"]), &env_vars);
+        assert_eq!(host.program, "gimp");
+        assert_eq!(host.args, vec!["a.png"]);
+        assert_eq!(host.env_vars, env_vars);
+    }
+
+    #[test]
+    fn test_host_command_in_flatpak() {
+        let env = SandboxEnv {
+            flatpak_info: true,
+            container: Some("flatpak".to_string()),
+            flatpak_spawn: true,
+        };
+        let env_vars = vec![("LANG".to_string(), "C".to_string())];
+        let host = HostCommand::for_environment(&env, "bash", &args(&["-c", "nohup gimp &"]), &env_vars);
+        assert_eq!(host.program, "flatpak-spawn");
+        assert_eq!(host.args, vec!["--host", "--env=LANG=C", "bash", "-c", "nohup gimp &"]);
+        assert!(host.env_vars.is_empty());
+        assert_eq!(host.command().get_program(), "flatpak-spawn");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_on_host() {
+        assert_eq!(find_on_host(&["initium-missing-binary", "sh"]).as_deref(), Some("sh"));
+        assert_eq!(find_on_host(&["initium-missing-binary"]), None);
+    }
+}
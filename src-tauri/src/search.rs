@@ -0,0 +1,244 @@
+//! Web Search Module
+//!
+//! Search launchers open a query in a web search engine.
+//! Features:
+//! - URL templates with a `%s` placeholder (`https://duckduckgo.com/?q=%s`)
+//! - Keyword shortcuts picking the engine ("gh rust tauri", "!w paris"), case-insensitive
+//! - Default engine set, stored in the settings directory
+//! - Import and export of engines as JSON
+
+use crate::config_manager::ConfigManager;
+use crate::launcher::execute_url;
+use crate::launcher::LaunchOptions;
+use crate::parameter::percent_encode;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Web search engine
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchEngine {
+    /// Shortcut typed before the terms, e.g. `gh`
+    pub keyword: String,
+    /// Display name
+    pub name: String,
+    /// URL template, `%s` is replaced by the encoded terms
+    pub url: String,
+}
+
+impl SearchEngine {
+    fn new(keyword: &str, name: &str, url: &str) -> Self {
+        SearchEngine {
+            keyword: keyword.to_string(),
+            name: name.to_string(),
+            url: url.to_string(),
+        }
+    }
+}
+
+/// Engines shipped with Initium, DuckDuckGo first
+pub fn default_engines() -> Vec<SearchEngine> {
+    vec![
+        SearchEngine::new("d", "DuckDuckGo", "https://duckduckgo.com/?q=%s"),
+        SearchEngine::new("g", "Google", "https://www.google.com/search?q=%s"),
+        SearchEngine::new("gh", "GitHub", "https://github.com/search?q=%s"),
+        SearchEngine::new("w", "Wikipedia", "https://en.wikipedia.org/w/index.php?search=%s"),
+        SearchEngine::new("yt", "YouTube", "https://www.youtube.com/results?search_query=%s"),
+        SearchEngine::new("crates", "crates.io", "https://crates.io/search?q=%s"),
+        SearchEngine::new("rs", "docs.rs", "https://docs.rs/releases/search?query=%s"),
+        SearchEngine::new("mdn", "MDN", "https://developer.mozilla.org/search?q=%s"),
+    ]
+}
+
+/// Replace `%s` in a URL template with the encoded terms
+pub fn build_search_url(template: &str, terms: &str) -> String {
+    template.replace("%s", &percent_encode(terms.trim()))
+}
+
+/// Find the engine selected by the first word of a query
+///
+/// The keyword may be written as is (`gh rust`) or as a bang (`!gh rust`).
+/// Returns the engine and the remaining terms.
+pub fn parse_query<'a>(query: &'a str, engines: &'a [SearchEngine]) -> Option<(&'a SearchEngine, &'a str)> {
+    let query = query.trim_start();
+    let (first, rest) = query.split_once(char::is_whitespace).unwrap_or((query, ""));
+    let keyword = first.strip_prefix('!').unwrap_or(first).to_lowercase();
+    engines
+        .iter()
+        .find(|e| e.keyword.to_lowercase() == keyword)
+        .map(|engine| (engine, rest.trim()))
+}
+
+/// Build the URL of a free-form query
+///
+/// Without a matching keyword, `default_template` (or the first engine) is used.
+pub fn resolve_query(query: &str, engines: &[SearchEngine], default_template: Option<&str>) -> Result<String, String> {
+    if let Some((engine, terms)) = parse_query(query, engines) {
+        return Ok(build_search_url(&engine.url, terms));
+    }
+    let template = default_template
+        .filter(|t| !t.is_empty())
+        .or_else(|| engines.first().map(|e| e.url.as_str()))
+        .ok_or("No search engine configured")?;
+    Ok(build_search_url(template, query))
+}
+
+/// Open a free-form query in the browser
+pub async fn execute_search(query: &str, engines: &[SearchEngine], default_template: Option<&str>) -> Result<(), String> {
+    let url = resolve_query(query, engines, default_template)?;
+    log::info!("Searching: {}", url);
+    execute_url(&url, &LaunchOptions::default()).await
+}
+
+/// Configured search engines
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchEngines {
+    #[serde(default)]
+    pub engines: Vec<SearchEngine>,
+}
+
+impl Default for SearchEngines {
+    fn default() -> Self {
+        SearchEngines {
+            engines: default_engines(),
+        }
+    }
+}
+
+impl SearchEngines {
+    /// Get search engines file path
+    pub fn get_engines_path() -> PathBuf {
+        ConfigManager::get_settings_dir_path().join("search_engines.json")
+    }
+
+    /// Load engines from a file, the default set if the file does not exist
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(SearchEngines::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read search engines: {}", e))?;
+        Self::import_json(&content)
+    }
+
+    /// Save engines to a file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        std::fs::write(path, self.export_json()?)
+            .map_err(|e| format!("Failed to save search engines: {}", e))
+    }
+
+    /// Export engines as JSON
+    pub fn export_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(&self)
+            .map_err(|e| format!("Failed to serialize search engines: {}", e))
+    }
+
+    /// Parse engines from JSON, refusing duplicate keywords and templates without `%s`
+    ///
+    /// Keywords are stored in lowercase, as queries match them case-insensitively.
+    pub fn import_json(json: &str) -> Result<Self, String> {
+        let mut engines: SearchEngines =
+            serde_json::from_str(json).map_err(|e| format!("Failed to parse search engines: {}", e))?;
+        for engine in &mut engines.engines {
+            engine.keyword = engine.keyword.to_lowercase();
+        }
+        engines.validate()?;
+        Ok(engines)
+    }
+
+    /// Add or replace engines by keyword
+    pub fn merge(&mut self, other: SearchEngines) {
+        for mut engine in other.engines {
+            engine.keyword = engine.keyword.to_lowercase();
+            match self.engines.iter_mut().find(|e| e.keyword == engine.keyword) {
+                Some(existing) => *existing = engine,
+                None => self.engines.push(engine),
+            }
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        for (index, engine) in self.engines.iter().enumerate() {
+            if engine.keyword.is_empty() || engine.keyword.contains(char::is_whitespace) {
+                return Err(format!("Invalid search keyword: '{}'", engine.keyword));
+            }
+            if !engine.url.contains("%s") {
+                return Err(format!("Search URL of '{}' has no %s placeholder", engine.name));
+            }
+            if self.engines[..index].iter().any(|e| e.keyword == engine.keyword) {
+                return Err(format!("Duplicate search keyword: {}", engine.keyword));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_query_keywords() {
+        let engines = default_engines();
+        let (engine, terms) = parse_query("gh rust tauri", &engines).unwrap();
+        assert_eq!((engine.name.as_str(), terms), ("GitHub", "rust tauri"));
+        let (engine, terms) = parse_query("!w paris", &engines).unwrap();
+        assert_eq!((engine.name.as_str(), terms), ("Wikipedia", "paris"));
+        assert!(parse_query("rust tauri", &engines).is_none());
+    }
+
+    #[test]
+    fn test_resolve_query() {
+        let engines = default_engines();
+        assert_eq!(
+            resolve_query("w Île de France", &engines, None).unwrap(),
+            "https://en.wikipedia.org/w/index.php?search=%C3%8Ele%20de%20France"
+        );
+        assert_eq!(
+            resolve_query("a&b", &engines, Some("https://example.com/?q=%s")).unwrap(),
+            "https://example.com/?q=a%26b"
+        );
+        assert_eq!(
+            resolve_query("rust", &engines, None).unwrap(),
+            "https://duckduckgo.com/?q=rust"
+        );
+        assert!(resolve_query("rust", &[], None).is_err());
+    }
+
+    #[test]
+    fn test_import_validation() {
+        assert!(SearchEngines::import_json(r#"{"engines": [{"keyword": "x", "name": "X", "url": "https://x"}]}"#).is_err());
+        let duplicate = r#"{"engines": [
+            {"keyword": "x", "name": "X", "url": "https://x/%s"},
+            {"keyword": "x", "name": "Y", "url": "https://y/%s"}
+        ]}"#;
+        assert!(SearchEngines::import_json(duplicate).unwrap_err().contains("Duplicate"));
+        let shadowed = r#"{"engines": [
+            {"keyword": "GH", "name": "X", "url": "https://x/%s"},
+            {"keyword": "gh", "name": "Y", "url": "https://y/%s"}
+        ]}"#;
+        assert!(SearchEngines::import_json(shadowed).unwrap_err().contains("Duplicate"));
+        let upper = SearchEngines::import_json(r#"{"engines": [{"keyword": "AW", "name": "A", "url": "https://a/%s"}]}"#);
+        assert_eq!(upper.unwrap().engines[0].keyword, "aw");
+    }
+
+    #[test]
+    fn test_merge_and_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("search_engines.json");
+        assert_eq!(SearchEngines::load(&path).unwrap(), SearchEngines::default());
+
+        let mut engines = SearchEngines::default();
+        engines.merge(SearchEngines {
+            engines: vec![
+                SearchEngine::new("G", "Startpage", "https://www.startpage.com/do/search?q=%s"),
+                SearchEngine::new("aw", "ArchWiki", "https://wiki.archlinux.org/?search=%s"),
+            ],
+        });
+        assert_eq!(engines.engines.len(), default_engines().len() + 1);
+        assert_eq!(parse_query("g x", &engines.engines).unwrap().0.name, "Startpage");
+
+        engines.save(&path).unwrap();
+        assert_eq!(SearchEngines::load(&path).unwrap(), engines);
+    }
+}
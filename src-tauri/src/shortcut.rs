@@ -0,0 +1,594 @@
+//! Shortcut Module
+//!
+//! Imports the shortcut files of Windows and macOS as launchers, for users
+//! bringing their shortcut folders along.
+//! Features:
+//! - `.url` InternetShortcut INI files and `.webloc` plists (XML or binary) as web launchers
+//! - `.lnk` Shell Link files as app launchers: target, arguments, working directory, icon location
+//! - Folders imported recursively, shortcuts already imported skipped
+
+use crate::launcher::{generate_unique_id, LaunchOptions, LaunchType, Launcher};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Shortcut file extensions, lowercase
+pub const SHORTCUT_EXTENSIONS: [&str; 3] = ["url", "lnk", "webloc"];
+
+/// Image file extensions of usable shortcut icons, lowercase
+const ICON_EXTENSIONS: [&str; 5] = ["ico", "png", "svg", "jpg", "jpeg"];
+
+/// Depth at which folder imports stop
+const MAX_FOLDER_DEPTH: usize = 8;
+
+/// Size of the Shell Link header
+const LNK_HEADER_SIZE: usize = 0x4C;
+
+/// Shell Link class id, as stored in the header
+const LNK_CLSID: [u8; 16] = [
+    0x01, 0x14, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46,
+];
+
+/// Link flags of the Shell Link header
+const HAS_LINK_TARGET_ID_LIST: u32 = 0x01;
+const HAS_LINK_INFO: u32 = 0x02;
+const HAS_NAME: u32 = 0x04;
+const HAS_RELATIVE_PATH: u32 = 0x08;
+const HAS_WORKING_DIR: u32 = 0x10;
+const HAS_ARGUMENTS: u32 = 0x20;
+const HAS_ICON_LOCATION: u32 = 0x40;
+const IS_UNICODE: u32 = 0x80;
+
+/// Link info flags
+const VOLUME_ID_AND_LOCAL_BASE_PATH: u32 = 0x01;
+const COMMON_NETWORK_RELATIVE_LINK: u32 = 0x02;
+
+/// Target and options of a `.lnk` Shell Link
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ShellLink {
+    /// Local or network path of the target
+    pub target: Option<String>,
+    /// Description shown as tooltip
+    pub description: Option<String>,
+    /// Target relative to the link file
+    pub relative_path: Option<String>,
+    pub working_dir: Option<String>,
+    pub arguments: Option<String>,
+    /// Icon file, `.ico`, `.exe` or `.dll`
+    pub icon_location: Option<String>,
+}
+
+/// Little-endian reader over the bytes of a Shell Link
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.pos.checked_add(len).filter(|end| *end <= self.data.len());
+        let end = end.ok_or("Truncated shortcut file")?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Counted string of the StringData section
+    fn counted_string(&mut self, unicode: bool) -> Result<String, String> {
+        let count = self.u16()? as usize;
+        if unicode {
+            Ok(decode_utf16(self.bytes(count * 2)?))
+        } else {
+            Ok(String::from_utf8_lossy(self.bytes(count)?).to_string())
+        }
+    }
+}
+
+fn decode_utf16(bytes: &[u8]) -> String {
+    let units: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// Null-terminated string at an offset
+fn c_string(data: &[u8], offset: usize, unicode: bool) -> Option<String> {
+    let data = data.get(offset..)?;
+    if unicode {
+        let end = data.chunks_exact(2).position(|c| c == [0, 0])? * 2;
+        Some(decode_utf16(&data[..end]))
+    } else {
+        let end = data.iter().position(|b| *b == 0)?;
+        Some(String::from_utf8_lossy(&data[..end]).to_string())
+    }
+}
+
+/// Target path of the LinkInfo structure
+fn link_info_target(info: &[u8]) -> Option<String> {
+    let field = |offset: usize| -> Option<usize> {
+        let bytes = info.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+    };
+    let header_size = field(4)?;
+    let flags = field(8)? as u32;
+    let suffix = if header_size >= 0x24 && field(0x20)? != 0 {
+        c_string(info, field(0x20)?, true)
+    } else {
+        c_string(info, field(0x18)?, false)
+    }
+    .unwrap_or_default();
+
+    if flags & VOLUME_ID_AND_LOCAL_BASE_PATH != 0 {
+        let base = if header_size >= 0x24 && field(0x1C)? != 0 {
+            c_string(info, field(0x1C)?, true)
+        } else {
+            c_string(info, field(0x10)?, false)
+        }?;
+        return Some(format!("{}{}", base, suffix));
+    }
+    if flags & COMMON_NETWORK_RELATIVE_LINK != 0 {
+        let link = field(0x14)?;
+        let net_name = c_string(info, link + field(link + 8)?, false)?;
+        return Some(if suffix.is_empty() {
+            net_name
+        } else {
+            format!("{}\\{}", net_name.trim_end_matches('\\'), suffix)
+        });
+    }
+    None
+}
+
+/// Parse a `.lnk` Shell Link file
+pub fn parse_shell_link(data: &[u8]) -> Result<ShellLink, String> {
+    let mut reader = Reader { data, pos: 0 };
+    let header = reader.bytes(LNK_HEADER_SIZE).map_err(|_| "Not a Shell Link file")?;
+    if header[..4] != [0x4C, 0, 0, 0] || header[4..20] != LNK_CLSID {
+        return Err("Not a Shell Link file".to_string());
+    }
+    let flags = u32::from_le_bytes([header[0x14], header[0x15], header[0x16], header[0x17]]);
+
+    if flags & HAS_LINK_TARGET_ID_LIST != 0 {
+        let size = reader.u16()? as usize;
+        reader.bytes(size)?;
+    }
+
+    let mut link = ShellLink::default();
+    if flags & HAS_LINK_INFO != 0 {
+        let start = reader.pos;
+        let size = reader.u32()? as usize;
+        reader.pos = start;
+        link.target = link_info_target(reader.bytes(size)?).filter(|t| !t.is_empty());
+    }
+
+    let unicode = flags & IS_UNICODE != 0;
+    let mut string = |flag: u32| -> Result<Option<String>, String> {
+        if flags & flag == 0 {
+            return Ok(None);
+        }
+        let value = reader.counted_string(unicode)?;
+        Ok(Some(value).filter(|v| !v.is_empty()))
+    };
+    // StringData entries follow each other in this order
+    link.description = string(HAS_NAME)?;
+    link.relative_path = string(HAS_RELATIVE_PATH)?;
+    link.working_dir = string(HAS_WORKING_DIR)?;
+    link.arguments = string(HAS_ARGUMENTS)?;
+    link.icon_location = string(HAS_ICON_LOCATION)?;
+    Ok(link)
+}
+
+/// URL of a `.url` InternetShortcut file
+pub fn parse_internet_shortcut(content: &str) -> Result<String, String> {
+    let mut in_section = false;
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_section = line.eq_ignore_ascii_case("[InternetShortcut]");
+            continue;
+        }
+        if let (true, Some((key, value))) = (in_section, line.split_once('=')) {
+            if key.trim().eq_ignore_ascii_case("URL") && !value.trim().is_empty() {
+                return Ok(value.trim().to_string());
+            }
+        }
+    }
+    Err("No URL in the InternetShortcut section".to_string())
+}
+
+/// URL of a `.webloc` property list, XML or binary
+pub fn parse_webloc(data: &[u8]) -> Result<String, String> {
+    let value = plist::Value::from_reader(std::io::Cursor::new(data))
+        .map_err(|e| format!("Failed to parse webloc: {}", e))?;
+    value
+        .as_dictionary()
+        .and_then(|dict| dict.get("URL"))
+        .and_then(|url| url.as_string())
+        .filter(|url| !url.trim().is_empty())
+        .map(|url| url.trim().to_string())
+        .ok_or_else(|| "No URL in the webloc file".to_string())
+}
+
+/// Split Windows command-line arguments, following the `CommandLineToArgvW` rules
+///
+/// Backslashes are literal unless they precede a double quote, so paths
+/// like `C:\My Notes\` survive.
+pub fn split_windows_arguments(line: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let mut backslashes = 1;
+                while chars.peek() == Some(&'\\') {
+                    chars.next();
+                    backslashes += 1;
+                }
+                if chars.peek() == Some(&'"') {
+                    // 2n backslashes escape themselves, 2n+1 also escape the quote
+                    word.push_str(&"\\".repeat(backslashes / 2));
+                    if backslashes % 2 == 1 {
+                        chars.next();
+                        word.push('"');
+                    }
+                } else {
+                    word.push_str(&"\\".repeat(backslashes));
+                }
+                in_word = true;
+            }
+            '"' => {
+                // A doubled quote inside quotes is a literal quote
+                if in_quotes && chars.peek() == Some(&'"') {
+                    chars.next();
+                    word.push('"');
+                } else {
+                    in_quotes = !in_quotes;
+                }
+                in_word = true;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            c => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+/// Launcher of a shortcut file, named after the file
+pub fn read_shortcut(path: &Path, existing_ids: &[String]) -> Result<Launcher, String> {
+    let name = path
+        .file_stem()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let data = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let id = generate_unique_id(&name, existing_ids);
+
+    match extension.as_str() {
+        "url" => {
+            let url = parse_internet_shortcut(&String::from_utf8_lossy(&data))?;
+            Ok(Launcher::new(id, name, LaunchType::Web, url))
+        }
+        "webloc" => Ok(Launcher::new(id, name, LaunchType::Web, parse_webloc(&data)?)),
+        "lnk" => {
+            let link = parse_shell_link(&data)?;
+            let target = link
+                .target
+                .clone()
+                .or_else(|| link.relative_path.as_deref().map(|p| resolve_link_path(path, p)))
+                .ok_or_else(|| format!("{} has no target", path.display()))?;
+            // App targets are shell command lines on Linux
+            #[cfg(target_os = "linux")]
+            let target = crate::system::appimage::launcher_target(&target);
+            let mut launcher = Launcher::new(id, name, LaunchType::App, target);
+            if link.arguments.is_some() || link.working_dir.is_some() {
+                launcher.options = Some(LaunchOptions {
+                    args: link.arguments.as_deref().map(split_windows_arguments).unwrap_or_default(),
+                    working_dir: link.working_dir.clone(),
+                    ..LaunchOptions::default()
+                });
+            }
+            // Icons are only usable when the image came along with the shortcut,
+            // icons embedded in executables and libraries are not extracted
+            launcher.icon = link
+                .icon_location
+                .as_deref()
+                .map(|icon| resolve_link_path(path, icon))
+                .filter(|icon| is_icon_file(Path::new(icon)));
+            Ok(launcher)
+        }
+        other => Err(format!("Unsupported shortcut type: .{}", other)),
+    }
+}
+
+/// Path stored in a shell link, relative ones resolved against the folder of the shortcut
+///
+/// Windows separators are converted; drive and UNC paths are kept as they are.
+fn resolve_link_path(shortcut: &Path, value: &str) -> String {
+    if value.as_bytes().get(1) == Some(&b':') || value.starts_with("\\\\") {
+        return value.to_string();
+    }
+    let value = value.replace('\\', "/");
+    if Path::new(&value).is_absolute() {
+        return value;
+    }
+    shortcut
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join(value)
+        .to_string_lossy()
+        .to_string()
+}
+
+fn is_icon_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .is_some_and(|e| ICON_EXTENSIONS.contains(&e.to_string_lossy().to_lowercase().as_str()))
+}
+
+fn is_shortcut(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|e| SHORTCUT_EXTENSIONS.contains(&e.to_string_lossy().to_lowercase().as_str()))
+}
+
+fn collect_shortcuts(dir: &Path, depth: usize, found: &mut Vec<PathBuf>) {
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut paths: Vec<PathBuf> = read_dir.flatten().map(|e| e.path()).collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() && depth < MAX_FOLDER_DEPTH {
+            collect_shortcuts(&path, depth + 1, found);
+        } else if path.is_file() && is_shortcut(&path) {
+            found.push(path);
+        }
+    }
+}
+
+/// Shortcut files of the given files and folders
+pub fn find_shortcuts(paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            collect_shortcuts(path, 0, &mut found);
+        } else {
+            found.push(path.clone());
+        }
+    }
+    found
+}
+
+/// Result of a shortcut import
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ShortcutImport {
+    pub added: usize,
+    /// Shortcuts whose target already has a launcher
+    pub skipped: usize,
+    /// Files that could not be read, with the reason
+    pub failed: Vec<String>,
+}
+
+/// Add a launcher per shortcut of the given files and folders
+pub fn import_shortcuts(launchers: &mut Vec<Launcher>, paths: &[PathBuf]) -> ShortcutImport {
+    let mut summary = ShortcutImport::default();
+    for path in find_shortcuts(paths) {
+        let existing_ids: Vec<String> = launchers.iter().map(|l| l.id.clone()).collect();
+        match read_shortcut(&path, &existing_ids) {
+            Ok(launcher) => {
+                if launchers
+                    .iter()
+                    .any(|l| l.launch_type == launcher.launch_type && l.target == launcher.target)
+                {
+                    summary.skipped += 1;
+                    continue;
+                }
+                launchers.push(launcher);
+                summary.added += 1;
+            }
+            Err(e) => {
+                log::warn!("Skipping shortcut {}: {}", path.display(), e);
+                summary.failed.push(format!("{}: {}", path.display(), e));
+            }
+        }
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counted(value: &str) -> Vec<u8> {
+        let units: Vec<u16> = value.encode_utf16().collect();
+        let mut bytes = (units.len() as u16).to_le_bytes().to_vec();
+        bytes.extend(units.iter().flat_map(|u| u.to_le_bytes()));
+        bytes
+    }
+
+    /// Unicode Shell Link with an id list, a local base path and all strings but the relative path
+    fn shell_link(target: &str, description: &str, working_dir: &str, arguments: &str, icon: &str) -> Vec<u8> {
+        let flags = HAS_LINK_TARGET_ID_LIST
+            | HAS_LINK_INFO
+            | HAS_NAME
+            | HAS_WORKING_DIR
+            | HAS_ARGUMENTS
+            | HAS_ICON_LOCATION
+            | IS_UNICODE;
+        let mut data = vec![0u8; LNK_HEADER_SIZE];
+        data[0] = 0x4C;
+        data[4..20].copy_from_slice(&LNK_CLSID);
+        data[0x14..0x18].copy_from_slice(&flags.to_le_bytes());
+
+        // Id list, skipped by the parser
+        data.extend(6u16.to_le_bytes());
+        data.extend([4, 0, 0xAA, 0xBB, 0, 0]);
+
+        // LinkInfo: header, empty volume id, local base path, empty suffix
+        let volume_id = [0x10u8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0];
+        let base_offset = 0x1C + volume_id.len();
+        let suffix_offset = base_offset + target.len() + 1;
+        let size = suffix_offset + 1;
+        for value in [size, 0x1C, VOLUME_ID_AND_LOCAL_BASE_PATH as usize, 0x1C, base_offset, 0, suffix_offset] {
+            data.extend((value as u32).to_le_bytes());
+        }
+        data.extend(volume_id);
+        data.extend(target.as_bytes());
+        data.extend([0, 0]);
+
+        for value in [description, working_dir, arguments, icon] {
+            data.extend(counted(value));
+        }
+        data
+    }
+
+    #[test]
+    fn test_parse_shell_link() {
+        let data = shell_link(
+            "C:\\Program Files\\Notepad++\\notepad++.exe",
+            "Edit text",
+            "C:\\Users\\me",
+            "-multiInst \"C:\\My Notes\\todo.txt\"",
+            "C:\\Program Files\\Notepad++\\notepad++.exe",
+        );
+        let link = parse_shell_link(&data).unwrap();
+        assert_eq!(link.target.as_deref(), Some("C:\\Program Files\\Notepad++\\notepad++.exe"));
+        assert_eq!(link.description.as_deref(), Some("Edit text"));
+        assert_eq!(link.relative_path, None);
+        assert_eq!(link.working_dir.as_deref(), Some("C:\\Users\\me"));
+        assert_eq!(link.arguments.as_deref(), Some("-multiInst \"C:\\My Notes\\todo.txt\""));
+        assert!(link.icon_location.is_some());
+
+        assert!(parse_shell_link(&data[..data.len() - 4]).is_err());
+        assert!(parse_shell_link(b"[InternetShortcut]").is_err());
+    }
+
+    #[test]
+    fn test_split_windows_arguments() {
+        assert_eq!(
+            split_windows_arguments(r#"-multiInst "C:\My Notes\todo.txt" C:\Temp\ a\\"b c" d\"e"#),
+            vec!["-multiInst", "C:\\My Notes\\todo.txt", "C:\\Temp\\", "a\\b c", "d\"e"]
+        );
+        assert_eq!(split_windows_arguments(r#""" x"#), vec!["", "x"]);
+    }
+
+    #[test]
+    fn test_parse_internet_shortcut() {
+        let content = "[{000214A0-0000-0000-C000-000000000046}]\r\nProp3=19,11\r\n[InternetShortcut]\r\nIDList=\r\nURL=https://intranet.local/wiki\r\nIconIndex=0\r\n";
+        assert_eq!(parse_internet_shortcut(content).unwrap(), "https://intranet.local/wiki");
+        assert!(parse_internet_shortcut("[Other]\nURL=https://example.com\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_webloc() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>URL</key>
+	<string>https://www.apple.com/</string>
+</dict>
+</plist>"#;
+        assert_eq!(parse_webloc(xml.as_bytes()).unwrap(), "https://www.apple.com/");
+
+        let mut dict = plist::Dictionary::new();
+        dict.insert("URL".to_string(), plist::Value::String("https://example.com/".to_string()));
+        let mut binary = Vec::new();
+        plist::Value::Dictionary(dict).to_writer_binary(&mut binary).unwrap();
+        assert_eq!(parse_webloc(&binary).unwrap(), "https://example.com/");
+
+        assert!(parse_webloc(b"not a plist").is_err());
+    }
+
+    #[test]
+    fn test_import_shortcut_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("Work")).unwrap();
+        std::fs::write(dir.path().join("Wiki.url"), "[InternetShortcut]\nURL=https://wiki.local/\n").unwrap();
+        std::fs::write(dir.path().join("Work/Wiki copy.URL"), "[InternetShortcut]\nURL=https://wiki.local/\n").unwrap();
+        std::fs::write(
+            dir.path().join("Work/Notepad.lnk"),
+            shell_link("C:\\Windows\\notepad.exe", "", "C:\\Temp", "a.txt", "C:\\missing.ico"),
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("Broken.webloc"), "garbage").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let mut launchers = Vec::new();
+        let summary = import_shortcuts(&mut launchers, &[dir.path().to_path_buf()]);
+        assert_eq!(summary.added, 2);
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(summary.failed.len(), 1);
+
+        assert_eq!(launchers[0].name, "Wiki");
+        assert_eq!(launchers[0].launch_type, LaunchType::Web);
+        assert_eq!(launchers[1].name, "Notepad");
+        assert_eq!(launchers[1].launch_type, LaunchType::App);
+        #[cfg(target_os = "linux")]
+        assert_eq!(launchers[1].target, "'C:\\Windows\\notepad.exe'");
+        #[cfg(not(target_os = "linux"))]
+        assert_eq!(launchers[1].target, "C:\\Windows\\notepad.exe");
+        let options = launchers[1].options.as_ref().unwrap();
+        assert_eq!(options.args, vec!["a.txt"]);
+        assert_eq!(options.working_dir.as_deref(), Some("C:\\Temp"));
+        assert_eq!(launchers[1].icon, None);
+    }
+
+    #[test]
+    fn test_shortcut_icon_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let read = |icon: &Path| {
+            let path = dir.path().join("App.lnk");
+            std::fs::write(&path, shell_link("/opt/app/app", "", "", "", &icon.to_string_lossy())).unwrap();
+            read_shortcut(&path, &[]).unwrap().icon
+        };
+        let png = dir.path().join("app.PNG");
+        let exe = dir.path().join("app.exe");
+        std::fs::write(&png, "").unwrap();
+        std::fs::write(&exe, "").unwrap();
+
+        assert_eq!(read(&png), Some(png.to_string_lossy().to_string()));
+        assert_eq!(read(&exe), None);
+        assert_eq!(read(&dir.path().join("missing.ico")), None);
+        // Relative icons sit next to the shortcut
+        std::fs::create_dir_all(dir.path().join("icons")).unwrap();
+        std::fs::write(dir.path().join("icons/app.png"), "").unwrap();
+        assert_eq!(
+            read(Path::new("icons\\app.png")),
+            Some(dir.path().join("icons/app.png").to_string_lossy().to_string())
+        );
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_resolve_link_path() {
+        let shortcut = Path::new("/home/me/Shortcuts/App.lnk");
+        assert_eq!(resolve_link_path(shortcut, "..\\Tools\\app.exe"), "/home/me/Shortcuts/../Tools/app.exe");
+        assert_eq!(resolve_link_path(shortcut, ".\\app.ico"), "/home/me/Shortcuts/./app.ico");
+        assert_eq!(resolve_link_path(shortcut, "C:\\Tools\\app.exe"), "C:\\Tools\\app.exe");
+        assert_eq!(resolve_link_path(shortcut, "\\\\server\\share\\app.exe"), "\\\\server\\share\\app.exe");
+        assert_eq!(resolve_link_path(shortcut, "/opt/app/app.png"), "/opt/app/app.png");
+    }
+}
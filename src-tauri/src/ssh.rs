@@ -0,0 +1,346 @@
+//! SSH Module
+//!
+//! SSH launchers open a session to a host in the configured terminal.
+//! Features:
+//! - User, port, identity file and jump host per launcher
+//! - Import of the hosts declared in `~/.ssh/config`, following `Include`
+//!   and skipping wildcard patterns
+//! - Imported launchers keep only the alias, so `ssh` applies the
+//!   configuration itself; existing SSH launchers are left untouched
+
+use crate::launcher::{generate_unique_id, LaunchOptions, LaunchType, Launcher};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Maximum nesting of `Include` directives, as in OpenSSH
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// Connection options of an SSH launcher, the host being the launcher target
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SshOptions {
+    /// Remote user
+    #[serde(default)]
+    pub user: Option<String>,
+    /// Remote port
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// Private key file (`-i`)
+    #[serde(default)]
+    pub identity: Option<String>,
+    /// Jump host (`-J`)
+    #[serde(default)]
+    pub jump_host: Option<String>,
+}
+
+impl SshOptions {
+    /// `ssh` arguments connecting to `host`
+    pub fn arguments(&self, host: &str) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(port) = self.port {
+            args.push("-p".to_string());
+            args.push(port.to_string());
+        }
+        if let Some(identity) = &self.identity {
+            args.push("-i".to_string());
+            args.push(identity.clone());
+        }
+        if let Some(jump_host) = &self.jump_host {
+            args.push("-J".to_string());
+            args.push(jump_host.clone());
+        }
+        args.push("--".to_string());
+        match &self.user {
+            Some(user) => args.push(format!("{}@{}", user, host)),
+            None => args.push(host.to_string()),
+        }
+        args
+    }
+}
+
+/// Open an SSH session in the configured terminal
+///
+/// Launcher arguments are run as the remote command.
+pub async fn open_ssh_session(launcher: &Launcher, options: &LaunchOptions) -> Result<(), String> {
+    let host = launcher.target.trim();
+    if host.is_empty() {
+        return Err(format!("Launcher '{}' has no SSH host", launcher.name));
+    }
+    let mut args = launcher.ssh.clone().unwrap_or_default().arguments(host);
+    args.extend(options.args.iter().cloned());
+    let options = LaunchOptions {
+        args,
+        ..options.clone()
+    };
+    crate::terminal::execute_in_terminal("ssh", &options).await
+}
+
+/// Host declared in an SSH client configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SshHost {
+    /// `Host` alias
+    pub alias: String,
+    /// Real host name, when different from the alias
+    pub hostname: Option<String>,
+    /// User, port, identity file and jump host
+    pub options: SshOptions,
+}
+
+impl SshHost {
+    fn new(alias: &str) -> Self {
+        SshHost {
+            alias: alias.to_string(),
+            hostname: None,
+            options: SshOptions::default(),
+        }
+    }
+}
+
+/// Default SSH client configuration, `~/.ssh/config`
+pub fn default_config_path() -> PathBuf {
+    dirs::home_dir().unwrap_or_default().join(".ssh").join("config")
+}
+
+/// Parse the hosts of an SSH configuration file, empty if the file does not exist
+///
+/// Relative `Include` paths are resolved against the directory of `path`.
+pub fn parse_ssh_config<P: AsRef<Path>>(path: P) -> Result<Vec<SshHost>, String> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let ssh_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+    let mut hosts = Vec::new();
+    parse_file(path, &ssh_dir, 0, &mut hosts)?;
+    Ok(hosts)
+}
+
+fn parse_file(path: &Path, ssh_dir: &Path, depth: usize, hosts: &mut Vec<SshHost>) -> Result<(), String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read SSH config {}: {}", path.display(), e))?;
+    // Hosts the current block applies to
+    let mut current: Vec<usize> = Vec::new();
+
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = match line.find(|c: char| c.is_whitespace() || c == '=') {
+            Some(index) => (&line[..index], line[index..].trim_start_matches(|c: char| c.is_whitespace() || c == '=')),
+            None => (line, ""),
+        };
+        let value = value.trim().trim_matches('"');
+
+        match key.to_lowercase().as_str() {
+            "host" => {
+                current.clear();
+                // Wildcard and negated patterns are defaults, not hosts
+                for alias in value.split_whitespace().filter(|p| !p.contains(['*', '?', '!'])) {
+                    let index = match hosts.iter().position(|h| h.alias == alias) {
+                        Some(index) => index,
+                        None => {
+                            hosts.push(SshHost::new(alias));
+                            hosts.len() - 1
+                        }
+                    };
+                    current.push(index);
+                }
+            }
+            "match" => current.clear(),
+            "include" => {
+                if depth >= MAX_INCLUDE_DEPTH {
+                    return Err(format!("Too many nested Include in {}", path.display()));
+                }
+                for pattern in value.split_whitespace() {
+                    for included in expand_include(pattern, ssh_dir) {
+                        parse_file(&included, ssh_dir, depth + 1, hosts)?;
+                    }
+                }
+            }
+            keyword => {
+                // The first value obtained for a host wins, as in OpenSSH
+                for &index in &current {
+                    let host = &mut hosts[index];
+                    match keyword {
+                        "hostname" => set_once(&mut host.hostname, value),
+                        "user" => set_once(&mut host.options.user, value),
+                        "identityfile" => set_once(&mut host.options.identity, value),
+                        "proxyjump" if value != "none" => set_once(&mut host.options.jump_host, value),
+                        "port" if host.options.port.is_none() => host.options.port = value.parse().ok(),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn set_once(field: &mut Option<String>, value: &str) {
+    if field.is_none() && !value.is_empty() {
+        *field = Some(value.to_string());
+    }
+}
+
+/// Files matching an `Include` pattern, sorted; wildcards apply to the file name only
+fn expand_include(pattern: &str, ssh_dir: &Path) -> Vec<PathBuf> {
+    let path = match pattern.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+        None => ssh_dir.join(pattern),
+    };
+    let file_pattern = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    if !file_pattern.contains(['*', '?']) {
+        return if path.is_file() { vec![path] } else { Vec::new() };
+    }
+
+    let Some(dir) = path.parent() else {
+        return Vec::new();
+    };
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = read_dir
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_file())
+        .filter(|p| {
+            p.file_name()
+                .is_some_and(|n| wildcard_match(&file_pattern, &n.to_string_lossy()))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Match `name` against a pattern with `*` and `?` wildcards
+pub fn wildcard_match(pattern: &str, name: &str) -> bool {
+    fn matches(pattern: &[char], name: &[char]) -> bool {
+        match pattern.split_first() {
+            None => name.is_empty(),
+            Some(('*', rest)) => (0..=name.len()).any(|skip| matches(rest, &name[skip..])),
+            Some(('?', rest)) => !name.is_empty() && matches(rest, &name[1..]),
+            Some((c, rest)) => name.first() == Some(c) && matches(rest, &name[1..]),
+        }
+    }
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    matches(&pattern, &name)
+}
+
+/// SSH launcher connecting to `alias`, if any
+pub fn find_ssh_launcher<'a>(launchers: &'a [Launcher], alias: &str) -> Option<&'a Launcher> {
+    launchers
+        .iter()
+        .find(|l| l.launch_type == LaunchType::Ssh && l.target == alias)
+}
+
+/// Result of an SSH hosts import
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct SshImport {
+    pub added: usize,
+    pub skipped: usize,
+}
+
+/// Add a launcher per host not yet having one
+///
+/// Launchers only hold the alias: `ssh` reads the user, port, identity file and
+/// jump host from its configuration, including the `Match` blocks not parsed here.
+pub fn merge_ssh_hosts(launchers: &mut Vec<Launcher>, hosts: Vec<SshHost>) -> SshImport {
+    let mut summary = SshImport::default();
+    for host in hosts {
+        if find_ssh_launcher(launchers, &host.alias).is_some() {
+            summary.skipped += 1;
+            continue;
+        }
+
+        let existing_ids: Vec<String> = launchers.iter().map(|l| l.id.clone()).collect();
+        let id = generate_unique_id(&host.alias, &existing_ids);
+        launchers.push(Launcher::new(id, host.alias.clone(), LaunchType::Ssh, host.alias));
+        summary.added += 1;
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssh_arguments() {
+        let options = SshOptions {
+            user: Some("ops".to_string()),
+            port: Some(2222),
+            identity: Some("~/.ssh/id_ops".to_string()),
+            jump_host: Some("bastion".to_string()),
+        };
+        assert_eq!(
+            options.arguments("db1"),
+            vec!["-p", "2222", "-i", "~/.ssh/id_ops", "-J", "bastion", "--", "ops@db1"]
+        );
+        assert_eq!(SshOptions::default().arguments("-oProxyCommand=x"), vec!["--", "-oProxyCommand=x"]);
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.conf", "hosts.conf"));
+        assert!(wildcard_match("web?", "web1"));
+        assert!(!wildcard_match("web?", "web12"));
+        assert!(!wildcard_match("*.conf", "hosts.conf.bak"));
+    }
+
+    #[test]
+    fn test_parse_ssh_config() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("config.d")).unwrap();
+        std::fs::write(
+            dir.path().join("config"),
+            "Include config.d/*.conf\n\nHost *\n    User nobody\n\nHost web1 web2 !web3\n    HostName %h.example.com\n    User deploy\n\nHost db1\n    User=admin\n    Port 2222\n    ProxyJump bastion\n\nMatch host db1\n    User other\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("config.d/10-lab.conf"),
+            "Host lab\n  HostName 10.0.0.5\n  IdentityFile \"~/.ssh/id lab\"\nHost db1\n  User first\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("config.d/ignored.txt"), "Host ignored\n").unwrap();
+
+        let hosts = parse_ssh_config(dir.path().join("config")).unwrap();
+        let aliases: Vec<&str> = hosts.iter().map(|h| h.alias.as_str()).collect();
+        assert_eq!(aliases, vec!["lab", "db1", "web1", "web2"]);
+
+        assert_eq!(hosts[0].hostname.as_deref(), Some("10.0.0.5"));
+        assert_eq!(hosts[0].options.identity.as_deref(), Some("~/.ssh/id lab"));
+        // The included value comes first and wins
+        assert_eq!(hosts[1].options.user.as_deref(), Some("first"));
+        assert_eq!(hosts[1].options.port, Some(2222));
+        assert_eq!(hosts[1].options.jump_host.as_deref(), Some("bastion"));
+        assert_eq!(hosts[2].options.user.as_deref(), Some("deploy"));
+
+        assert!(parse_ssh_config(dir.path().join("missing")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_merge_ssh_hosts() {
+        let mut existing = Launcher::new("db1".to_string(), "Database".to_string(), LaunchType::Ssh, "db1".to_string());
+        existing.icon = Some("db.png".to_string());
+        let mut launchers = vec![existing];
+
+        let mut web1 = SshHost::new("web1");
+        web1.options.port = Some(2222);
+        let hosts = vec![SshHost::new("db1"), web1];
+
+        let summary = merge_ssh_hosts(&mut launchers, hosts.clone());
+        assert_eq!(summary, SshImport { added: 1, skipped: 1 });
+        assert_eq!(launchers.len(), 2);
+        assert_eq!(launchers[0].name, "Database");
+        assert_eq!(launchers[1].launch_type, LaunchType::Ssh);
+        assert_eq!(launchers[1].target, "web1");
+        assert!(launchers[1].ssh.is_none());
+
+        // Importing again adds nothing
+        assert_eq!(merge_ssh_hosts(&mut launchers, hosts), SshImport { added: 0, skipped: 2 });
+        assert_eq!(launchers.len(), 2);
+    }
+}
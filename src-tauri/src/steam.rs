@@ -0,0 +1,452 @@
+//! Steam Module
+//!
+//! Imports the games installed in the local Steam libraries as URL launchers
+//! starting them through the Steam client.
+//! Features:
+//! - `libraryfolders.vdf` and `appmanifest_*.acf` parsing (Valve KeyValues)
+//! - Games started with `steam://rungameid/<id>`
+//! - Library cover art of the local cache used as icon
+//! - Redistributables and compatibility tools skipped
+
+use crate::launcher::{generate_unique_id, LaunchType, Launcher};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Fully installed bit of the manifest `StateFlags`
+const STATE_FULLY_INSTALLED: u64 = 4;
+
+/// Steamworks Common Redistributables
+const REDISTRIBUTABLES_APP_ID: &str = "228980";
+
+/// Names of the tools Steam installs as apps
+const TOOL_PREFIXES: [&str; 3] = ["Proton ", "Steam Linux Runtime", "Steamworks "];
+
+/// Cover art file names of the library cache, best first
+const COVER_NAMES: [&str; 3] = ["library_600x900.jpg", "header.jpg", "icon.jpg"];
+
+/// Value of a KeyValues entry
+#[derive(Debug, Clone, PartialEq)]
+pub enum VdfValue {
+    String(String),
+    Object(Vec<(String, VdfValue)>),
+}
+
+impl VdfValue {
+    /// Child of an object, keys compared case-insensitively like Steam does
+    pub fn get(&self, key: &str) -> Option<&VdfValue> {
+        match self {
+            VdfValue::Object(entries) => entries
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(key))
+                .map(|(_, v)| v),
+            VdfValue::String(_) => None,
+        }
+    }
+
+    /// String child of an object
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        match self.get(key) {
+            Some(VdfValue::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Entries of an object
+    pub fn entries(&self) -> &[(String, VdfValue)] {
+        match self {
+            VdfValue::Object(entries) => entries,
+            VdfValue::String(_) => &[],
+        }
+    }
+}
+
+/// Token of a KeyValues document
+#[derive(Debug, PartialEq)]
+enum Token {
+    Text(String),
+    Open,
+    Close,
+}
+
+fn tokenize(content: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => tokens.push(Token::Open),
+            '}' => tokens.push(Token::Close),
+            '"' => {
+                let mut text = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => match chars.next() {
+                            Some('n') => text.push('\n'),
+                            Some('t') => text.push('\t'),
+                            Some(other) => text.push(other),
+                            None => break,
+                        },
+                        other => text.push(other),
+                    }
+                }
+                tokens.push(Token::Text(text));
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            c if c.is_whitespace() => {}
+            c => {
+                // Unquoted word, ends at whitespace or a brace
+                let mut text = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || next == '{' || next == '}' || next == '"' {
+                        break;
+                    }
+                    text.push(next);
+                    chars.next();
+                }
+                tokens.push(Token::Text(text));
+            }
+        }
+    }
+    tokens
+}
+
+fn parse_entries(tokens: &mut std::iter::Peekable<std::vec::IntoIter<Token>>) -> Vec<(String, VdfValue)> {
+    let mut entries = Vec::new();
+    while let Some(token) = tokens.next() {
+        let key = match token {
+            Token::Text(key) => key,
+            Token::Close => break,
+            Token::Open => continue,
+        };
+        match tokens.next() {
+            Some(Token::Text(value)) => entries.push((key, VdfValue::String(value))),
+            Some(Token::Open) => entries.push((key, VdfValue::Object(parse_entries(tokens)))),
+            Some(Token::Close) | None => break,
+        }
+    }
+    entries
+}
+
+/// Parse a KeyValues document (`.vdf`, `.acf`) into its root object
+pub fn parse_vdf(content: &str) -> VdfValue {
+    let mut tokens = tokenize(content).into_iter().peekable();
+    VdfValue::Object(parse_entries(&mut tokens))
+}
+
+/// Default Steam installation directories of the platform
+pub fn default_roots() -> Vec<PathBuf> {
+    let home = dirs::home_dir().unwrap_or_default();
+    if cfg!(target_os = "windows") {
+        vec![PathBuf::from(r"C:\Program Files (x86)\Steam"), PathBuf::from(r"C:\Program Files\Steam")]
+    } else if cfg!(target_os = "macos") {
+        vec![home.join("Library/Application Support/Steam")]
+    } else {
+        vec![
+            home.join(".local/share/Steam"),
+            home.join(".steam/steam"),
+            home.join(".var/app/com.valvesoftware.Steam/.local/share/Steam"),
+        ]
+    }
+}
+
+/// First existing Steam installation directory
+pub fn find_root() -> Option<PathBuf> {
+    default_roots().into_iter().find(|root| root.join("steamapps").is_dir())
+}
+
+/// Library folders declared in `steamapps/libraryfolders.vdf`, the root included
+pub fn library_folders(root: &Path) -> Vec<PathBuf> {
+    let mut folders = vec![root.to_path_buf()];
+    let Ok(content) = std::fs::read_to_string(root.join("steamapps/libraryfolders.vdf")) else {
+        return folders;
+    };
+    let document = parse_vdf(&content);
+    let Some(libraries) = document.get("libraryfolders") else {
+        return folders;
+    };
+    for (key, value) in libraries.entries() {
+        if !key.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+        // Older clients store the path directly, newer ones in a "path" key
+        let path = match value {
+            VdfValue::String(path) => Some(path.as_str()),
+            object => object.get_str("path"),
+        };
+        if let Some(path) = path.map(PathBuf::from) {
+            if !folders.iter().any(|f| same_dir(f, &path)) {
+                folders.push(path);
+            }
+        }
+    }
+    folders
+}
+
+fn same_dir(a: &Path, b: &Path) -> bool {
+    a == b || matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b)
+}
+
+/// Installed Steam game
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SteamGame {
+    pub app_id: String,
+    pub name: String,
+    /// Library folder the game is installed in
+    pub library: PathBuf,
+    /// Cover art from the library cache
+    pub icon: Option<PathBuf>,
+}
+
+impl SteamGame {
+    /// URL starting the game through the Steam client
+    pub fn url(&self) -> String {
+        game_url(&self.app_id)
+    }
+
+    /// Launcher starting this game
+    pub fn to_launcher(&self, existing_ids: &[String], icon: Option<String>) -> Launcher {
+        let id = generate_unique_id(&self.name, existing_ids);
+        let mut launcher = Launcher::new(id, self.name.clone(), LaunchType::Web, self.url());
+        launcher.icon = icon;
+        launcher
+    }
+}
+
+/// URL starting a game through the Steam client
+pub fn game_url(app_id: &str) -> String {
+    format!("steam://rungameid/{}", app_id)
+}
+
+/// Whether an app is a redistributable or compatibility tool rather than a game
+fn is_tool(app_id: &str, name: &str) -> bool {
+    app_id == REDISTRIBUTABLES_APP_ID || TOOL_PREFIXES.iter().any(|prefix| name.starts_with(prefix))
+}
+
+/// App id and name of a fully installed game manifest
+pub fn parse_app_manifest(content: &str) -> Option<(String, String)> {
+    let document = parse_vdf(content);
+    let state = document.get("AppState")?;
+    let app_id = state.get_str("appid")?.trim();
+    let name = state.get_str("name")?.trim();
+    if app_id.is_empty() || name.is_empty() || is_tool(app_id, name) {
+        return None;
+    }
+    // Games being downloaded have no complete install yet
+    if let Some(flags) = state.get_str("StateFlags").and_then(|f| f.trim().parse::<u64>().ok()) {
+        if flags & STATE_FULLY_INSTALLED == 0 {
+            return None;
+        }
+    }
+    Some((app_id.to_string(), name.to_string()))
+}
+
+/// Cover art of a game in the library cache
+///
+/// Older clients store `<id>_library_600x900.jpg` files, newer ones a
+/// `<id>/` directory, sometimes with the images in a hashed subdirectory.
+pub fn cover_art(root: &Path, app_id: &str) -> Option<PathBuf> {
+    let cache = root.join("appcache/librarycache");
+    for name in COVER_NAMES {
+        let flat = cache.join(format!("{}_{}", app_id, name));
+        if flat.is_file() {
+            return Some(flat);
+        }
+        let nested = cache.join(app_id).join(name);
+        if nested.is_file() {
+            return Some(nested);
+        }
+    }
+    let mut subdirs: Vec<PathBuf> = std::fs::read_dir(cache.join(app_id))
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    subdirs.sort();
+    COVER_NAMES
+        .iter()
+        .find_map(|name| subdirs.iter().map(|dir| dir.join(name)).find(|p| p.is_file()))
+}
+
+/// Installed games of all the library folders of a Steam installation, sorted by name
+pub fn list_games(root: &Path) -> Vec<SteamGame> {
+    let mut games: Vec<SteamGame> = Vec::new();
+    for library in library_folders(root) {
+        let Ok(read_dir) = std::fs::read_dir(library.join("steamapps")) else {
+            continue;
+        };
+        for path in read_dir.flatten().map(|e| e.path()) {
+            let is_manifest = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with("appmanifest_") && n.ends_with(".acf"));
+            if !is_manifest {
+                continue;
+            }
+            let Some((app_id, name)) = std::fs::read_to_string(&path).ok().and_then(|c| parse_app_manifest(&c)) else {
+                continue;
+            };
+            if games.iter().any(|g| g.app_id == app_id) {
+                continue;
+            }
+            games.push(SteamGame {
+                icon: cover_art(root, &app_id),
+                app_id,
+                name,
+                library: library.clone(),
+            });
+        }
+    }
+    games.sort_by_key(|g| g.name.to_lowercase());
+    games
+}
+
+/// Installed games of the default Steam installation, empty when Steam is not installed
+pub fn installed_games() -> Vec<SteamGame> {
+    match find_root() {
+        Some(root) => {
+            let games = list_games(&root);
+            log::info!("Found {} Steam game(s) in {}", games.len(), root.display());
+            games
+        }
+        None => {
+            log::info!("Steam installation not found");
+            Vec::new()
+        }
+    }
+}
+
+/// Launcher already starting a Steam game
+pub fn find_game_launcher<'a>(launchers: &'a [Launcher], app_id: &str) -> Option<&'a Launcher> {
+    let url = game_url(app_id);
+    launchers
+        .iter()
+        .find(|l| l.launch_type == LaunchType::Web && l.target.trim() == url)
+}
+
+/// Add a launcher per game without one, icons given as data URLs by app id
+///
+/// Returns the number of launchers added.
+pub fn merge_games(launchers: &mut Vec<Launcher>, games: &[SteamGame], icons: &[(String, String)]) -> usize {
+    let mut added = 0;
+    for game in games {
+        if find_game_launcher(launchers, &game.app_id).is_some() {
+            continue;
+        }
+        let icon = icons.iter().find(|(id, _)| *id == game.app_id).map(|(_, icon)| icon.clone());
+        let existing_ids: Vec<String> = launchers.iter().map(|l| l.id.clone()).collect();
+        launchers.push(game.to_launcher(&existing_ids, icon));
+        added += 1;
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIBRARY_FOLDERS: &str = r#"
+"libraryfolders"
+{
+	"0"
+	{
+		"path"		"ROOT"
+		"label"		""
+		"apps"
+		{
+			"620"		"12683420"
+		}
+	}
+	"1"
+	{
+		"path"		"EXTRA"
+	}
+}
+"#;
+
+    fn manifest(app_id: &str, name: &str, flags: u64) -> String {
+        format!(
+            "\"AppState\"\n{{\n\t\"appid\"\t\t\"{}\"\n\t\"name\"\t\t\"{}\"\n\t\"StateFlags\"\t\t\"{}\"\n\t\"UserConfig\"\n\t{{\n\t}}\n}}\n",
+            app_id, name, flags
+        )
+    }
+
+    #[test]
+    fn test_parse_vdf() {
+        let document = parse_vdf("// comment\n\"Root\" { \"Key\" \"a \\\"b\\\"\" Unquoted value \"Child\" { } }");
+        let root = document.get("root").unwrap();
+        assert_eq!(root.get_str("key"), Some("a \"b\""));
+        assert_eq!(root.get_str("Unquoted"), Some("value"));
+        assert_eq!(root.get("Child"), Some(&VdfValue::Object(Vec::new())));
+        assert_eq!(root.get_str("Child"), None);
+    }
+
+    #[test]
+    fn test_parse_app_manifest() {
+        assert_eq!(
+            parse_app_manifest(&manifest("620", "Portal 2", 4)),
+            Some(("620".to_string(), "Portal 2".to_string()))
+        );
+        // Update pending, still playable
+        assert!(parse_app_manifest(&manifest("620", "Portal 2", 6)).is_some());
+        // Download not finished
+        assert_eq!(parse_app_manifest(&manifest("620", "Portal 2", 1026)), None);
+        assert_eq!(parse_app_manifest(&manifest("228980", "Steamworks Common Redistributables", 4)), None);
+        assert_eq!(parse_app_manifest(&manifest("1493710", "Proton Experimental", 4)), None);
+        assert_eq!(parse_app_manifest("\"Other\" { }"), None);
+    }
+
+    #[test]
+    fn test_list_games() {
+        let root = tempfile::tempdir().unwrap();
+        let extra = tempfile::tempdir().unwrap();
+        let steamapps = root.path().join("steamapps");
+        std::fs::create_dir_all(&steamapps).unwrap();
+        std::fs::create_dir_all(extra.path().join("steamapps")).unwrap();
+        let folders = LIBRARY_FOLDERS
+            .replace("ROOT", &root.path().to_string_lossy())
+            .replace("EXTRA", &extra.path().to_string_lossy());
+        std::fs::write(steamapps.join("libraryfolders.vdf"), folders).unwrap();
+        std::fs::write(steamapps.join("appmanifest_620.acf"), manifest("620", "Portal 2", 4)).unwrap();
+        std::fs::write(steamapps.join("appmanifest_228980.acf"), manifest("228980", "Steamworks Common Redistributables", 4)).unwrap();
+        std::fs::write(extra.path().join("steamapps/appmanifest_70.acf"), manifest("70", "Half-Life", 4)).unwrap();
+
+        let cache = root.path().join("appcache/librarycache");
+        std::fs::create_dir_all(cache.join("70/0a1b2c")).unwrap();
+        std::fs::write(cache.join("620_library_600x900.jpg"), "").unwrap();
+        std::fs::write(cache.join("70/0a1b2c/library_600x900.jpg"), "").unwrap();
+
+        assert_eq!(library_folders(root.path()).len(), 2);
+        let games = list_games(root.path());
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].name, "Half-Life");
+        assert_eq!(games[0].library, extra.path());
+        assert_eq!(games[0].icon, Some(cache.join("70/0a1b2c/library_600x900.jpg")));
+        assert_eq!(games[1].url(), "steam://rungameid/620");
+        assert_eq!(games[1].icon, Some(cache.join("620_library_600x900.jpg")));
+    }
+
+    #[test]
+    fn test_merge_games() {
+        let game = SteamGame {
+            app_id: "620".to_string(),
+            name: "Portal 2".to_string(),
+            library: PathBuf::new(),
+            icon: None,
+        };
+        let mut launchers = Vec::new();
+        let icons = vec![("620".to_string(), "data:image/jpeg;base64,".to_string())];
+        assert_eq!(merge_games(&mut launchers, std::slice::from_ref(&game), &icons), 1);
+        assert_eq!(launchers[0].id, "portal-2");
+        assert_eq!(launchers[0].launch_type, LaunchType::Web);
+        assert_eq!(launchers[0].target, "steam://rungameid/620");
+        assert_eq!(launchers[0].icon.as_deref(), Some("data:image/jpeg;base64,"));
+        assert_eq!(merge_games(&mut launchers, &[game], &icons), 0);
+    }
+}
@@ -0,0 +1,364 @@
+//! AppImage Discovery (Linux)
+//!
+//! Finds the AppImages of the configured directories and reads the desktop
+//! entry and icon embedded in their squashfs payload.
+//! Features:
+//! - Default directories `~/Applications` and `~/.local/bin`, plus user-chosen ones
+//! - Name and icon from the embedded desktop entry, extracted with `--appimage-extract`
+//! - Launchers follow an AppImage replaced by a newer version
+
+use crate::fallback::split_command_line;
+use crate::launcher::{generate_unique_id, LaunchType, Launcher};
+use crate::parameter::shell_quote;
+use crate::system::browsers::parse_desktop_entry;
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Time allowed to the AppImage runtime to extract a file
+const EXTRACT_TIMEOUT_SECS: u64 = 10;
+
+/// Icon extensions looked up in the payload, best first
+const ICON_EXTENSIONS: [&str; 3] = ["svg", "png", "xpm"];
+
+/// AppImage found on disk
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AppImage {
+    /// AppImage file
+    pub path: String,
+    /// Embedded desktop file id, stable across versions
+    pub desktop_id: String,
+    /// Name of the embedded desktop entry
+    pub name: String,
+    /// `X-AppImage-Version` of the embedded desktop entry
+    pub version: Option<String>,
+    /// Icon extracted from the payload, removed with the extraction directory
+    #[serde(skip)]
+    pub icon: Option<PathBuf>,
+    /// Modification time in seconds since the epoch
+    pub modified: u64,
+}
+
+/// Default AppImage directories
+pub fn default_dirs() -> Vec<PathBuf> {
+    let home = dirs::home_dir().unwrap_or_default();
+    vec![home.join("Applications"), home.join(".local/bin")]
+}
+
+/// Default directories followed by the configured ones, `~` expanded
+pub fn search_dirs(configured: &[String]) -> Vec<PathBuf> {
+    let mut dirs = default_dirs();
+    for dir in configured.iter().map(|d| d.trim()).filter(|d| !d.is_empty()) {
+        let dir = match dir.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+            None => PathBuf::from(dir),
+        };
+        if !dirs.contains(&dir) {
+            dirs.push(dir);
+        }
+    }
+    dirs
+}
+
+/// Whether a file is a type 2 AppImage (ELF with the `AI\x02` magic at offset 8)
+pub fn is_appimage(path: &Path) -> bool {
+    let mut header = [0u8; 11];
+    let Ok(mut file) = std::fs::File::open(path) else {
+        return false;
+    };
+    file.read_exact(&mut header).is_ok() && header[..4] == *b"\x7fELF" && header[8..11] == *b"AI\x02"
+}
+
+/// AppImage files of the given directories, sorted by path
+pub fn find_appimages(dirs: &[PathBuf]) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    for dir in dirs {
+        let Ok(read_dir) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        found.extend(read_dir.flatten().map(|e| e.path()).filter(|p| p.is_file() && is_appimage(p)));
+    }
+    found.sort();
+    found
+}
+
+fn modified_secs(path: &Path) -> u64 {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Extract the payload files matching `pattern` into `dir/squashfs-root`
+fn extract(appimage: &Path, pattern: &str, dir: &Path) -> Result<(), String> {
+    let mut child = Command::new(appimage)
+        .arg("--appimage-extract")
+        .arg(pattern)
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to run {}: {}", appimage.display(), e))?;
+
+    let started = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => return Ok(()),
+            Ok(None) if started.elapsed() >= Duration::from_secs(EXTRACT_TIMEOUT_SECS) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("Extraction from {} timed out", appimage.display()));
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(20)),
+            Err(e) => return Err(format!("Failed to wait for {}: {}", appimage.display(), e)),
+        }
+    }
+}
+
+/// Read the desktop entry and icon of an extracted payload root
+///
+/// Returns the desktop file id, the entry and the icon file.
+pub fn read_extracted(root: &Path) -> Option<(String, std::collections::HashMap<String, String>, Option<PathBuf>)> {
+    let mut desktop_files: Vec<PathBuf> = std::fs::read_dir(root)
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "desktop"))
+        .collect();
+    desktop_files.sort();
+    let desktop = desktop_files.first()?;
+    let entry = parse_desktop_entry(&std::fs::read_to_string(desktop).ok()?);
+    let desktop_id = desktop.file_name()?.to_string_lossy().to_string();
+
+    let icon = entry
+        .get("Icon")
+        .and_then(|icon| {
+            ICON_EXTENSIONS
+                .iter()
+                .map(|ext| root.join(format!("{}.{}", icon, ext)))
+                .find(|p| p.is_file())
+        })
+        .or_else(|| Some(root.join(".DirIcon")).filter(|p| p.is_file()));
+    Some((desktop_id, entry, icon))
+}
+
+/// Read the embedded metadata of an AppImage, extracting into `work_dir`
+pub fn read_appimage(path: &Path, work_dir: &Path) -> Result<AppImage, String> {
+    std::fs::create_dir_all(work_dir).map_err(|e| format!("Failed to create {}: {}", work_dir.display(), e))?;
+    extract(path, "*.desktop", work_dir)?;
+    let root = work_dir.join("squashfs-root");
+    let (_, entry, _) = read_extracted(&root)
+        .ok_or_else(|| format!("No desktop entry in {}", path.display()))?;
+    if let Some(icon) = entry.get("Icon") {
+        for ext in ICON_EXTENSIONS {
+            extract(path, &format!("{}.{}", icon, ext), work_dir)?;
+        }
+    }
+    extract(path, ".DirIcon", work_dir)?;
+
+    let (desktop_id, entry, icon) = read_extracted(&root)
+        .ok_or_else(|| format!("No desktop entry in {}", path.display()))?;
+    let file_name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    Ok(AppImage {
+        path: path.to_string_lossy().to_string(),
+        desktop_id,
+        name: entry.get("Name").cloned().unwrap_or(file_name),
+        version: entry.get("X-AppImage-Version").cloned(),
+        icon,
+        modified: modified_secs(path),
+    })
+}
+
+/// Newest version of each AppImage, by desktop id
+pub fn newest_versions(appimages: Vec<AppImage>) -> Vec<AppImage> {
+    let mut newest: Vec<AppImage> = Vec::new();
+    for appimage in appimages {
+        match newest.iter_mut().find(|a| a.desktop_id == appimage.desktop_id) {
+            Some(existing) if appimage.modified > existing.modified => *existing = appimage,
+            Some(_) => {}
+            None => newest.push(appimage),
+        }
+    }
+    newest
+}
+
+/// App launcher target running an AppImage, quoted when the path needs it
+pub fn launcher_target(path: &str) -> String {
+    if path.chars().all(|c| c.is_ascii_alphanumeric() || "/._-+".contains(c)) {
+        path.to_string()
+    } else {
+        shell_quote(path)
+    }
+}
+
+/// Result of an AppImage synchronisation
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct AppImageSync {
+    pub added: usize,
+    /// Launchers moved to a newer version
+    pub updated: usize,
+}
+
+/// Point launchers at the newest version of their AppImage, and add launchers for new ones when `add` is set
+///
+/// `appimages` must hold one version per desktop id, see [`newest_versions`].
+pub fn sync_launchers(launchers: &mut Vec<Launcher>, appimages: &[AppImage], add: bool) -> AppImageSync {
+    let mut summary = AppImageSync::default();
+    for appimage in appimages {
+        let existing = launchers
+            .iter_mut()
+            .find(|l| l.appimage.as_deref() == Some(appimage.desktop_id.as_str()));
+        match existing {
+            Some(launcher) => {
+                let target = launcher_target(&appimage.path);
+                if launcher.target != target {
+                    log::info!("AppImage of '{}' moved to {}", launcher.name, appimage.path);
+                    launcher.target = target;
+                    summary.updated += 1;
+                }
+            }
+            None if add => {
+                let existing_ids: Vec<String> = launchers.iter().map(|l| l.id.clone()).collect();
+                let id = generate_unique_id(&appimage.name, &existing_ids);
+                let target = launcher_target(&appimage.path);
+                let mut launcher = Launcher::new(id, appimage.name.clone(), LaunchType::App, target);
+                launcher.appimage = Some(appimage.desktop_id.clone());
+                launchers.push(launcher);
+                summary.added += 1;
+            }
+            None => {}
+        }
+    }
+    summary
+}
+
+/// Whether the AppImage file of a launcher is gone
+pub fn is_missing(launcher: &Launcher) -> bool {
+    launcher.appimage.is_some()
+        && !split_command_line(&launcher.target)
+            .first()
+            .is_some_and(|program| Path::new(program).exists())
+}
+
+/// Directory of the missing AppImage of a launcher, where a newer version is looked for
+pub fn relink_dir(launcher: &Launcher) -> Option<PathBuf> {
+    if !is_missing(launcher) {
+        return None;
+    }
+    let program = split_command_line(&launcher.target).into_iter().next()?;
+    Path::new(&program)
+        .parent()
+        .filter(|dir| dir.is_dir())
+        .map(Path::to_path_buf)
+}
+
+/// Scan the directories and read every AppImage, one version per desktop id
+///
+/// The extracted files live in `work_dir` until the caller removes it.
+pub fn scan(dirs: &[PathBuf], work_dir: &Path) -> Vec<AppImage> {
+    let appimages = find_appimages(dirs)
+        .iter()
+        .enumerate()
+        .filter_map(|(index, path)| match read_appimage(path, &work_dir.join(index.to_string())) {
+            Ok(appimage) => Some(appimage),
+            Err(e) => {
+                log::warn!("Skipping AppImage {}: {}", path.display(), e);
+                None
+            }
+        })
+        .collect();
+    newest_versions(appimages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn appimage(desktop_id: &str, path: &str, modified: u64) -> AppImage {
+        AppImage {
+            path: path.to_string(),
+            desktop_id: desktop_id.to_string(),
+            name: "Foo".to_string(),
+            version: None,
+            icon: None,
+            modified,
+        }
+    }
+
+    #[test]
+    fn test_is_appimage() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut header = b"\x7fELF\x02\x01\x01\x00AI\x02".to_vec();
+        header.extend([0u8; 32]);
+        std::fs::write(dir.path().join("Foo.AppImage"), &header).unwrap();
+        std::fs::write(dir.path().join("script.sh"), "#!/bin/sh\n").unwrap();
+        std::fs::write(dir.path().join("plain"), b"\x7fELF\x02\x01\x01\x00\x00\x00\x00").unwrap();
+
+        let found = find_appimages(&[dir.path().to_path_buf(), dir.path().join("missing")]);
+        assert_eq!(found, vec![dir.path().join("Foo.AppImage")]);
+    }
+
+    #[test]
+    fn test_read_appimage_with_fake_runtime() {
+        let dir = tempfile::tempdir().unwrap();
+        // Stand-in for the AppImage runtime, extracting fixed files
+        let fake = dir.path().join("Foo-1.2.AppImage");
+        std::fs::write(
+            &fake,
+            "#!/bin/sh\nmkdir -p squashfs-root\ncase \"$2\" in\n  '*.desktop') printf '[Desktop Entry]\\nName=Foo Editor\\nIcon=foo\\nX-AppImage-Version=1.2\\n' > squashfs-root/foo.desktop ;;\n  foo.png) echo png > squashfs-root/foo.png ;;\nesac\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&fake, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let appimage = read_appimage(&fake, &dir.path().join("work")).unwrap();
+        assert_eq!(appimage.desktop_id, "foo.desktop");
+        assert_eq!(appimage.name, "Foo Editor");
+        assert_eq!(appimage.version.as_deref(), Some("1.2"));
+        assert!(appimage.icon.unwrap().ends_with("squashfs-root/foo.png"));
+    }
+
+    #[test]
+    fn test_sync_follows_newer_version() {
+        let found = newest_versions(vec![
+            appimage("foo.desktop", "/apps/Foo-1.2.AppImage", 10),
+            appimage("foo.desktop", "/apps/Foo-1.3.AppImage", 20),
+            appimage("bar.desktop", "/apps/Bar.AppImage", 5),
+        ]);
+        assert_eq!(found.len(), 2);
+
+        let mut foo = Launcher::new("foo".to_string(), "Foo".to_string(), LaunchType::App, "/apps/Foo-1.2.AppImage".to_string());
+        foo.appimage = Some("foo.desktop".to_string());
+        let mut launchers = vec![foo];
+
+        assert_eq!(sync_launchers(&mut launchers, &found, false), AppImageSync { added: 0, updated: 1 });
+        assert_eq!(launchers[0].target, "/apps/Foo-1.3.AppImage");
+        assert_eq!(launchers.len(), 1);
+
+        assert_eq!(sync_launchers(&mut launchers, &found, true), AppImageSync { added: 1, updated: 0 });
+        assert_eq!(launchers[1].appimage.as_deref(), Some("bar.desktop"));
+        assert_eq!(sync_launchers(&mut launchers, &found, true), AppImageSync::default());
+        assert_eq!(launcher_target("/home/me/My Apps/Foo.AppImage"), "'/home/me/My Apps/Foo.AppImage'");
+    }
+
+    #[test]
+    fn test_relink_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let old = dir.path().join("My Foo-1.2.AppImage");
+        let mut foo = Launcher::new("foo".to_string(), "Foo".to_string(), LaunchType::App, launcher_target(&old.to_string_lossy()));
+        assert_eq!(relink_dir(&foo), None);
+
+        foo.appimage = Some("foo.desktop".to_string());
+        assert_eq!(relink_dir(&foo), Some(dir.path().to_path_buf()));
+
+        std::fs::write(&old, "").unwrap();
+        assert_eq!(relink_dir(&foo), None);
+        foo.target = "/initium-missing-dir/Foo.AppImage".to_string();
+        assert_eq!(relink_dir(&foo), None);
+    }
+}
@@ -0,0 +1,329 @@
+//! Browser Detection (Linux)
+//!
+//! Discovers installed browsers from desktop entries handling
+//! `x-scheme-handler/http`, including Flatpak and Snap exports, and reads
+//! the default browser from the mimeapps.list files.
+
+use crate::browser::BrowserFamily;
+use freedesktop_entry_parser::Entry;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+const HTTP_HANDLER: &str = "x-scheme-handler/http";
+
+/// Where a browser is installed from
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BrowserSource {
+    System,
+    Flatpak,
+    Snap,
+}
+
+/// Command-line features supported by a browser
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct BrowserCapabilities {
+    /// Named profiles (`-P` or `--profile-directory`)
+    pub profiles: bool,
+    /// Private or incognito windows
+    pub private_mode: bool,
+    /// Chrome-less app windows (`--app=`)
+    pub app_mode: bool,
+}
+
+impl BrowserCapabilities {
+    /// Capabilities of a browser family
+    pub fn of(family: BrowserFamily) -> Self {
+        match family {
+            BrowserFamily::Firefox => BrowserCapabilities {
+                profiles: true,
+                private_mode: true,
+                app_mode: false,
+            },
+            BrowserFamily::Chromium => BrowserCapabilities {
+                profiles: true,
+                private_mode: true,
+                app_mode: true,
+            },
+            BrowserFamily::Other => BrowserCapabilities::default(),
+        }
+    }
+}
+
+/// Installed browser
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Browser {
+    /// Desktop file id, e.g. `firefox.desktop`
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// Command without desktop field codes, usable as `WebOptions.browser`
+    pub command: String,
+    /// Optional icon name
+    pub icon: Option<String>,
+    /// System package, Flatpak or Snap
+    pub source: BrowserSource,
+    /// Command-line conventions
+    pub family: BrowserFamily,
+    /// Supported profile, private and app modes
+    pub capabilities: BrowserCapabilities,
+    /// Current default browser
+    pub is_default: bool,
+}
+
+/// Parse the `[Desktop Entry]` group of a desktop file, without localized keys
+///
+/// An unparsable file gives an empty group.
+pub fn parse_desktop_entry(content: &str) -> HashMap<String, String> {
+    let Ok(entry) = Entry::parse(content) else {
+        return HashMap::new();
+    };
+    entry
+        .section("Desktop Entry")
+        .map(|section| {
+            section
+                .attrs()
+                .filter(|(key, _)| key.param.is_none())
+                .filter_map(|(key, values)| Some((key.key.clone(), values.first()?.clone())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Remove desktop entry field codes (`%u`, `%U`, ...) and Flatpak `@@` markers from an Exec line
+pub fn strip_field_codes(exec: &str) -> String {
+    exec.split_whitespace()
+        .filter(|word| !(word.len() == 2 && word.starts_with('%') && word != &"%%"))
+        .filter(|word| !word.starts_with("@@"))
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("%%", "%")
+}
+
+/// Read the default handlers of `mime` from mimeapps.list files, by priority
+pub fn default_handlers(mimeapps_files: &[PathBuf], mime: &str) -> Vec<String> {
+    let mut handlers = Vec::new();
+    for path in mimeapps_files {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        let mut in_defaults = false;
+        for line in content.lines().map(str::trim) {
+            if line.starts_with('[') {
+                in_defaults = line == "[Default Applications]";
+            } else if in_defaults {
+                if let Some((key, value)) = line.split_once('=') {
+                    if key.trim() == mime {
+                        handlers.extend(
+                            value
+                                .split(';')
+                                .map(str::trim)
+                                .filter(|id| !id.is_empty())
+                                .map(String::from),
+                        );
+                    }
+                }
+            }
+        }
+    }
+    handlers
+}
+
+fn source_of(path: &Path, exec: &str) -> BrowserSource {
+    let path = path.to_string_lossy();
+    if path.contains("/flatpak/") || exec.starts_with("flatpak ") || exec.contains("/flatpak ") {
+        BrowserSource::Flatpak
+    } else if path.contains("/snapd/") || exec.starts_with("/snap/") || exec.starts_with("snap ") {
+        BrowserSource::Snap
+    } else {
+        BrowserSource::System
+    }
+}
+
+/// Discover browsers in the given application directories
+///
+/// Directories come by priority: the first desktop file with a given id wins.
+pub fn discover_browsers_in(app_dirs: &[PathBuf], mimeapps_files: &[PathBuf]) -> Vec<Browser> {
+    let defaults = default_handlers(mimeapps_files, HTTP_HANDLER);
+    let mut browsers: Vec<Browser> = Vec::new();
+    let mut seen = Vec::new();
+
+    for dir in app_dirs {
+        let Ok(read_dir) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        let mut paths: Vec<PathBuf> = read_dir.flatten().map(|e| e.path()).collect();
+        paths.sort();
+        for path in paths {
+            let Some(id) = path.file_name().map(|n| n.to_string_lossy().to_string()) else {
+                continue;
+            };
+            if !id.ends_with(".desktop") || seen.contains(&id) {
+                continue;
+            }
+            seen.push(id.clone());
+
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let entry = parse_desktop_entry(&content);
+            let handles_http = entry
+                .get("MimeType")
+                .is_some_and(|m| m.split(';').any(|t| t.trim() == HTTP_HANDLER));
+            let hidden = entry.get("Hidden").is_some_and(|v| v == "true");
+            let (Some(name), Some(exec)) = (entry.get("Name"), entry.get("Exec")) else {
+                continue;
+            };
+            if !handles_http || hidden {
+                continue;
+            }
+
+            let command = strip_field_codes(exec);
+            let family = match BrowserFamily::detect(&command) {
+                BrowserFamily::Other => BrowserFamily::detect(&id),
+                family => family,
+            };
+            browsers.push(Browser {
+                is_default: false,
+                id,
+                name: name.clone(),
+                source: source_of(&path, &command),
+                command,
+                icon: entry.get("Icon").cloned(),
+                family,
+                capabilities: BrowserCapabilities::of(family),
+            });
+        }
+    }
+
+    if let Some(index) = defaults.iter().find_map(|id| browsers.iter().position(|b| b.id == *id)) {
+        browsers[index].is_default = true;
+    }
+    browsers
+}
+
+fn data_home() -> PathBuf {
+    std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|h| h.join(".local/share")))
+        .unwrap_or_default()
+}
+
+fn data_dirs() -> Vec<PathBuf> {
+    let dirs = std::env::var("XDG_DATA_DIRS")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+    dirs.split(':').map(PathBuf::from).collect()
+}
+
+/// Application directories, by priority, including Flatpak and Snap exports
+pub fn application_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![
+        data_home().join("applications"),
+        data_home().join("flatpak/exports/share/applications"),
+    ];
+    dirs.extend(data_dirs().into_iter().map(|d| d.join("applications")));
+    for extra in [
+        "/var/lib/flatpak/exports/share/applications",
+        "/var/lib/snapd/desktop/applications",
+    ] {
+        let extra = PathBuf::from(extra);
+        if !dirs.contains(&extra) {
+            dirs.push(extra);
+        }
+    }
+    dirs
+}
+
+/// mimeapps.list files, by priority
+pub fn mimeapps_files() -> Vec<PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(dirs::config_dir)
+        .unwrap_or_default();
+    let config_dirs = std::env::var("XDG_CONFIG_DIRS")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .unwrap_or_else(|| "/etc/xdg".to_string());
+
+    let mut files = vec![config_home.join("mimeapps.list")];
+    files.extend(config_dirs.split(':').map(|d| Path::new(d).join("mimeapps.list")));
+    files.push(data_home().join("applications/mimeapps.list"));
+    files.extend(data_dirs().into_iter().map(|d| d.join("applications/mimeapps.list")));
+    files
+}
+
+/// Discover the browsers installed on this system
+pub fn detect_browsers() -> Vec<Browser> {
+    let browsers = discover_browsers_in(&application_dirs(), &mimeapps_files());
+    log::info!("Detected {} browser(s)", browsers.len());
+    browsers
+}
+
+/// Current default browser, if any
+pub fn default_browser() -> Option<Browser> {
+    detect_browsers().into_iter().find(|b| b.is_default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, content: &str) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(dir.join(name), content).unwrap();
+    }
+
+    #[test]
+    fn test_strip_field_codes() {
+        assert_eq!(strip_field_codes("firefox %u"), "firefox");
+        assert_eq!(
+            strip_field_codes("/usr/bin/flatpak run --command=brave com.brave.Browser @@u %U @@"),
+            "/usr/bin/flatpak run --command=brave com.brave.Browser"
+        );
+        assert_eq!(strip_field_codes("app --ratio 50%%"), "app --ratio 50%");
+    }
+
+    #[test]
+    fn test_parse_desktop_entry_ignores_actions() {
+        let entry = parse_desktop_entry(
+            "# Firefox\n[Desktop Entry]\nName=Firefox\nName[fr]=Navigateur Firefox\nExec=firefox %u\n\n[Desktop Action new-private-window]\nExec=firefox --private-window %u\n",
+        );
+        assert_eq!(entry["Exec"], "firefox %u");
+        assert_eq!(entry["Name"], "Firefox");
+        assert!(parse_desktop_entry("[Desktop Action new-window]\nExec=firefox\n").is_empty());
+    }
+
+    #[test]
+    fn test_discover_browsers() {
+        let root = tempfile::tempdir().unwrap();
+        let user = root.path().join("user/applications");
+        let system = root.path().join("usr/share/applications");
+        let flatpak = root.path().join("flatpak/exports/share/applications");
+
+        write(&system, "firefox.desktop", "[Desktop Entry]\nName=Firefox\nExec=firefox %u\nIcon=firefox\nMimeType=text/html;x-scheme-handler/http;x-scheme-handler/https;\n");
+        write(&system, "gimp.desktop", "[Desktop Entry]\nName=GIMP\nExec=gimp %U\nMimeType=image/png;\n");
+        write(&flatpak, "org.chromium.Chromium.desktop", "[Desktop Entry]\nName=Chromium\nExec=/usr/bin/flatpak run --branch=stable org.chromium.Chromium @@u %U @@\nMimeType=x-scheme-handler/http;\n");
+        // A user entry hides the system one
+        write(&user, "firefox.desktop", "[Desktop Entry]\nName=Firefox\nExec=firefox %u\nHidden=true\nMimeType=x-scheme-handler/http;\n");
+
+        let config = root.path().join("config");
+        write(&config, "mimeapps.list", "[Added Associations]\nx-scheme-handler/http=firefox.desktop;\n[Default Applications]\nx-scheme-handler/http=missing.desktop;org.chromium.Chromium.desktop;\n");
+
+        let browsers = discover_browsers_in(&[user, system.clone(), flatpak], &[config.join("mimeapps.list")]);
+        assert_eq!(browsers.len(), 1);
+        let chromium = &browsers[0];
+        assert_eq!(chromium.source, BrowserSource::Flatpak);
+        assert_eq!(chromium.family, BrowserFamily::Chromium);
+        assert!(chromium.capabilities.app_mode);
+        assert!(chromium.is_default);
+
+        let browsers = discover_browsers_in(&[system], &[]);
+        assert_eq!(browsers[0].command, "firefox");
+        assert_eq!(browsers[0].source, BrowserSource::System);
+        assert!(!browsers[0].capabilities.app_mode);
+        assert!(!browsers[0].is_default);
+    }
+}
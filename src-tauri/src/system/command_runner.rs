@@ -1,11 +1,13 @@
 //! OS Command Runner Module
 //!
 //! Provides platform-specific command execution abstraction
-//! Supports: execute, execute_with_output, kill_process, is_process_running
+//! Supports: execute, execute_with_output, execute_with_env, execute_with_input, kill_process, is_process_running
 
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
 
 /// Output from a command execution
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CommandOutput {
     /// Exit status code
     pub status_code: i32,
@@ -15,6 +17,9 @@ pub struct CommandOutput {
     pub stderr: String,
     /// Whether execution was successful
     pub success: bool,
+    /// Execution duration in milliseconds
+    #[serde(default)]
+    pub duration_ms: u64,
 }
 
 /// Abstract trait for OS-specific command execution
@@ -30,6 +35,27 @@ pub trait OSCommandRunner {
         timeout_secs: u64,
     ) -> Result<CommandOutput, String>;
 
+    /// Execute a command with extra environment variables and capture output
+    ///
+    /// The process is killed when it runs longer than `timeout_secs` (0 disables the timeout).
+    fn execute_with_env(
+        &self,
+        program: &str,
+        args: &[&str],
+        env_vars: &[(String, String)],
+        timeout_secs: u64,
+    ) -> Result<CommandOutput, String>;
+
+    /// Execute a command with extra environment variables, writing `input` to its stdin, and capture output
+    fn execute_with_input(
+        &self,
+        program: &str,
+        args: &[&str],
+        env_vars: &[(String, String)],
+        input: &str,
+        timeout_secs: u64,
+    ) -> Result<CommandOutput, String>;
+
     /// Kill a process by PID
     fn kill_process(&self, pid: u32) -> Result<(), String>;
 
@@ -37,6 +63,130 @@ pub trait OSCommandRunner {
     fn is_process_running(&self, pid: u32) -> bool;
 }
 
+/// Command runner for the current platform
+#[cfg(target_os = "linux")]
+pub fn platform_runner() -> LinuxCommandRunner {
+    LinuxCommandRunner
+}
+
+/// Command runner for the current platform
+#[cfg(target_os = "windows")]
+pub fn platform_runner() -> WindowsCommandRunner {
+    WindowsCommandRunner
+}
+
+/// Command runner for the current platform
+#[cfg(target_os = "macos")]
+pub fn platform_runner() -> MacOSCommandRunner {
+    MacOSCommandRunner
+}
+
+/// How long output pipes are still drained once the command exited
+///
+/// Background processes started by the command can keep the pipes open forever.
+const PIPE_GRACE: Duration = Duration::from_millis(500);
+
+/// Read a pipe to its end in the background, into a buffer shared with the caller
+fn drain_pipe<R: std::io::Read + Send + 'static>(
+    pipe: Option<R>,
+) -> (std::sync::Arc<std::sync::Mutex<Vec<u8>>>, std::thread::JoinHandle<()>) {
+    let buffer = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let shared = buffer.clone();
+    let reader = std::thread::spawn(move || {
+        let Some(mut pipe) = pipe else { return };
+        let mut chunk = [0u8; 4096];
+        while let Ok(read) = pipe.read(&mut chunk) {
+            if read == 0 {
+                break;
+            }
+            if let Ok(mut buffer) = shared.lock() {
+                buffer.extend_from_slice(&chunk[..read]);
+            }
+        }
+    });
+    (buffer, reader)
+}
+
+/// Kill a spawned command along with every process it started
+fn kill_tree(child: &mut std::process::Child) {
+    // The command leads its own process group, killing the group reaches its background children
+    #[cfg(unix)]
+    let _ = std::process::Command::new("kill")
+        .args(["-9", "--", &format!("-{}", child.id())])
+        .output();
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Run a prepared command, capturing output and enforcing the timeout
+fn run_with_timeout(
+    mut cmd: std::process::Command,
+    input: Option<&str>,
+    timeout_secs: u64,
+) -> Result<CommandOutput, String> {
+    cmd.stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+    if input.is_some() {
+        cmd.stdin(std::process::Stdio::piped());
+    }
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+
+    let started = Instant::now();
+    let mut child = cmd.spawn().map_err(|e| {
+        log::error!("Execution error: {}", e);
+        format!("Execution error: {}", e)
+    })?;
+
+    // Feed stdin from its own thread, the command may fill its output pipes before reading it all
+    if let (Some(mut stdin), Some(input)) = (child.stdin.take(), input) {
+        let input = input.to_string();
+        std::thread::spawn(move || {
+            use std::io::Write;
+            let _ = stdin.write_all(input.as_bytes());
+        });
+    }
+
+    // Drain both pipes in the background so a chatty process cannot block on a full pipe
+    let (stdout_buffer, stdout_reader) = drain_pipe(child.stdout.take());
+    let (stderr_buffer, stderr_reader) = drain_pipe(child.stderr.take());
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if timeout_secs > 0 && started.elapsed() >= Duration::from_secs(timeout_secs) => {
+                kill_tree(&mut child);
+                log::error!("Command timed out after {} seconds", timeout_secs);
+                return Err(format!("Command timed out after {} seconds", timeout_secs));
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(20)),
+            Err(e) => {
+                log::error!("Execution error: {}", e);
+                return Err(format!("Execution error: {}", e));
+            }
+        }
+    };
+
+    let exited = Instant::now();
+    while !(stdout_reader.is_finished() && stderr_reader.is_finished()) && exited.elapsed() < PIPE_GRACE {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let stdout = String::from_utf8_lossy(&stdout_buffer.lock().map(|b| b.clone()).unwrap_or_default()).to_string();
+    let stderr = String::from_utf8_lossy(&stderr_buffer.lock().map(|b| b.clone()).unwrap_or_default()).to_string();
+    let status_code = status.code().unwrap_or(-1);
+    let duration_ms = started.elapsed().as_millis() as u64;
+
+    log::info!("Command completed with status: {} in {} ms", status_code, duration_ms);
+
+    Ok(CommandOutput {
+        status_code,
+        stdout,
+        stderr,
+        success: status.success(),
+        duration_ms,
+    })
+}
+
 /// Linux-specific command runner
 #[cfg(target_os = "linux")]
 pub struct LinuxCommandRunner;
@@ -80,20 +230,23 @@ impl OSCommandRunner for LinuxCommandRunner {
             cmd.arg(arg);
         }
 
+        let started = Instant::now();
         match cmd.output() {
             Ok(output) => {
                 let stdout = String::from_utf8_lossy(&output.stdout).to_string();
                 let stderr = String::from_utf8_lossy(&output.stderr).to_string();
                 let status_code = output.status.code().unwrap_or(-1);
                 let success = output.status.success();
+                let duration_ms = started.elapsed().as_millis() as u64;
 
-                log::info!("Command completed with status: {}", status_code);
+                log::info!("Command completed with status: {} in {} ms", status_code, duration_ms);
 
                 Ok(CommandOutput {
                     status_code,
                     stdout,
                     stderr,
                     success,
+                    duration_ms,
                 })
             }
             Err(e) => {
@@ -103,6 +256,47 @@ impl OSCommandRunner for LinuxCommandRunner {
         }
     }
 
+    fn execute_with_env(
+        &self,
+        program: &str,
+        args: &[&str],
+        env_vars: &[(String, String)],
+        timeout_secs: u64,
+    ) -> Result<CommandOutput, String> {
+        log::info!("Executing with env (Linux): {} {:?}", program, args);
+
+        let mut cmd = std::process::Command::new(program);
+        for arg in args {
+            cmd.arg(arg);
+        }
+        for (key, value) in env_vars {
+            cmd.env(key, value);
+        }
+
+        run_with_timeout(cmd, None, timeout_secs)
+    }
+
+    fn execute_with_input(
+        &self,
+        program: &str,
+        args: &[&str],
+        env_vars: &[(String, String)],
+        input: &str,
+        timeout_secs: u64,
+    ) -> Result<CommandOutput, String> {
+        log::info!("Executing with input (Linux): {} {:?}", program, args);
+
+        let mut cmd = std::process::Command::new(program);
+        for arg in args {
+            cmd.arg(arg);
+        }
+        for (key, value) in env_vars {
+            cmd.env(key, value);
+        }
+
+        run_with_timeout(cmd, Some(input), timeout_secs)
+    }
+
     fn kill_process(&self, pid: u32) -> Result<(), String> {
         log::info!("Killing process (Linux): PID {}", pid);
 
@@ -176,20 +370,23 @@ impl OSCommandRunner for WindowsCommandRunner {
             cmd.arg(arg);
         }
 
+        let started = Instant::now();
         match cmd.output() {
             Ok(output) => {
                 let stdout = String::from_utf8_lossy(&output.stdout).to_string();
                 let stderr = String::from_utf8_lossy(&output.stderr).to_string();
                 let status_code = output.status.code().unwrap_or(-1);
                 let success = output.status.success();
+                let duration_ms = started.elapsed().as_millis() as u64;
 
-                log::info!("Command completed with status: {}", status_code);
+                log::info!("Command completed with status: {} in {} ms", status_code, duration_ms);
 
                 Ok(CommandOutput {
                     status_code,
                     stdout,
                     stderr,
                     success,
+                    duration_ms,
                 })
             }
             Err(e) => {
@@ -199,6 +396,49 @@ impl OSCommandRunner for WindowsCommandRunner {
         }
     }
 
+    fn execute_with_env(
+        &self,
+        program: &str,
+        args: &[&str],
+        env_vars: &[(String, String)],
+        timeout_secs: u64,
+    ) -> Result<CommandOutput, String> {
+        log::info!("Executing with env (Windows): {} {:?}", program, args);
+
+        let mut cmd = std::process::Command::new("cmd");
+        cmd.arg("/C").arg(program);
+        for arg in args {
+            cmd.arg(arg);
+        }
+        for (key, value) in env_vars {
+            cmd.env(key, value);
+        }
+
+        run_with_timeout(cmd, None, timeout_secs)
+    }
+
+    fn execute_with_input(
+        &self,
+        program: &str,
+        args: &[&str],
+        env_vars: &[(String, String)],
+        input: &str,
+        timeout_secs: u64,
+    ) -> Result<CommandOutput, String> {
+        log::info!("Executing with input (Windows): {} {:?}", program, args);
+
+        let mut cmd = std::process::Command::new("cmd");
+        cmd.arg("/C").arg(program);
+        for arg in args {
+            cmd.arg(arg);
+        }
+        for (key, value) in env_vars {
+            cmd.env(key, value);
+        }
+
+        run_with_timeout(cmd, Some(input), timeout_secs)
+    }
+
     fn kill_process(&self, pid: u32) -> Result<(), String> {
         log::info!("Killing process (Windows): PID {}", pid);
 
@@ -275,20 +515,23 @@ impl OSCommandRunner for MacOSCommandRunner {
             cmd.arg(arg);
         }
 
+        let started = Instant::now();
         match cmd.output() {
             Ok(output) => {
                 let stdout = String::from_utf8_lossy(&output.stdout).to_string();
                 let stderr = String::from_utf8_lossy(&output.stderr).to_string();
                 let status_code = output.status.code().unwrap_or(-1);
                 let success = output.status.success();
+                let duration_ms = started.elapsed().as_millis() as u64;
 
-                log::info!("Command completed with status: {}", status_code);
+                log::info!("Command completed with status: {} in {} ms", status_code, duration_ms);
 
                 Ok(CommandOutput {
                     status_code,
                     stdout,
                     stderr,
                     success,
+                    duration_ms,
                 })
             }
             Err(e) => {
@@ -298,6 +541,47 @@ impl OSCommandRunner for MacOSCommandRunner {
         }
     }
 
+    fn execute_with_env(
+        &self,
+        program: &str,
+        args: &[&str],
+        env_vars: &[(String, String)],
+        timeout_secs: u64,
+    ) -> Result<CommandOutput, String> {
+        log::info!("Executing with env (macOS): {} {:?}", program, args);
+
+        let mut cmd = std::process::Command::new(program);
+        for arg in args {
+            cmd.arg(arg);
+        }
+        for (key, value) in env_vars {
+            cmd.env(key, value);
+        }
+
+        run_with_timeout(cmd, None, timeout_secs)
+    }
+
+    fn execute_with_input(
+        &self,
+        program: &str,
+        args: &[&str],
+        env_vars: &[(String, String)],
+        input: &str,
+        timeout_secs: u64,
+    ) -> Result<CommandOutput, String> {
+        log::info!("Executing with input (macOS): {} {:?}", program, args);
+
+        let mut cmd = std::process::Command::new(program);
+        for arg in args {
+            cmd.arg(arg);
+        }
+        for (key, value) in env_vars {
+            cmd.env(key, value);
+        }
+
+        run_with_timeout(cmd, Some(input), timeout_secs)
+    }
+
     fn kill_process(&self, pid: u32) -> Result<(), String> {
         log::info!("Killing process (macOS): PID {}", pid);
 
@@ -343,6 +627,7 @@ mod tests {
             stdout: "hello".to_string(),
             stderr: "".to_string(),
             success: true,
+            duration_ms: 12,
         };
         assert_eq!(output.status_code, 0);
         assert_eq!(output.stdout, "hello");
@@ -472,4 +757,64 @@ fn test_execute_with_output_failing_command() {
     assert!(!output.success);
     assert_eq!(output.status_code, 1);
     }
+
+    #[test]
+    fn test_execute_with_env() {
+        let runner = platform_runner();
+        let env = vec![("INITIUM_TEST_VAR".to_string(), "hello".to_string())];
+
+        #[cfg(target_os = "windows")]
+        let result = runner.execute_with_env("echo %INITIUM_TEST_VAR%", &[], &env, 5);
+        #[cfg(not(target_os = "windows"))]
+        let result = runner.execute_with_env("sh", &["-c", "echo $INITIUM_TEST_VAR"], &env, 5);
+
+        let output = result.expect("Failed to execute");
+        assert!(output.success);
+        assert!(output.stdout.contains("hello"));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_execute_with_env_timeout() {
+        let runner = platform_runner();
+        let started = Instant::now();
+        let result = runner.execute_with_env("sleep", &["5"], &[], 1);
+        assert!(result.unwrap_err().contains("timed out"));
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_execute_with_env_background_child() {
+        let runner = platform_runner();
+        let started = Instant::now();
+        let output = runner
+            .execute_with_env("sh", &["-c", "echo started; sleep 30 &"], &[], 5)
+            .expect("Failed to execute");
+        assert!(output.stdout.contains("started"));
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_execute_with_env_timeout_kills_group() {
+        let runner = platform_runner();
+        let marker = std::env::temp_dir().join(format!("initium-group-{}", std::process::id()));
+        let script = format!("(sleep 2; touch {}) & sleep 5", marker.display());
+        let result = runner.execute_with_env("sh", &["-c", &script], &[], 1);
+        assert!(result.unwrap_err().contains("timed out"));
+        std::thread::sleep(Duration::from_secs(2));
+        assert!(!marker.exists());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_execute_with_input() {
+        let runner = platform_runner();
+        let output = runner
+            .execute_with_input("sh", &["/dev/stdin", "world"], &[], "echo hello $1", 5)
+            .expect("Failed to execute");
+        assert!(output.success);
+        assert_eq!(output.stdout.trim(), "hello world");
+    }
 }
@@ -0,0 +1,343 @@
+//! D-Bus Session Service Module
+//!
+//! Exposes Initium on the session bus (Linux only)
+//! Interfaces:
+//! - `com.initium.app.Launcher`: ListLaunchers, Launch(id), Search(query)
+//! - `org.gnome.Shell.SearchProvider2`: launchers in the GNOME overview search
+
+use crate::config::Config;
+use crate::config_manager::ConfigManager;
+use crate::launcher::Launcher;
+use std::collections::HashMap;
+use zbus::fdo;
+use zbus::zvariant::Value;
+
+/// Well-known bus name owned by the service
+pub const BUS_NAME: &str = "com.initium.app";
+
+/// Object path of the launcher and search provider interfaces
+pub const OBJECT_PATH: &str = "/com/initium/app";
+
+/// Session bus service backed by the Initium configuration
+#[derive(Clone, Default)]
+pub struct InitiumService {
+    /// Fixed configuration; `None` reloads from disk on every call
+    config: Option<Config>,
+}
+
+impl InitiumService {
+    /// Create a service reading the user configuration on every call
+    pub fn new() -> Self {
+        InitiumService { config: None }
+    }
+
+    /// Create a service serving a fixed configuration
+    pub fn with_config(config: Config) -> Self {
+        InitiumService {
+            config: Some(config),
+        }
+    }
+
+    fn load_config(&self) -> fdo::Result<Config> {
+        match &self.config {
+            Some(config) => Ok(config.clone()),
+            None => ConfigManager::load_or_default()
+                .map(|manager| manager.config().clone())
+                .map_err(fdo::Error::Failed),
+        }
+    }
+
+    async fn launch(&self, id: &str) -> fdo::Result<()> {
+        let config = self.load_config()?;
+        let launcher = config
+            .find_launcher(id)
+            .ok_or_else(|| fdo::Error::InvalidArgs(format!("Launcher not found: {}", id)))?;
+        log::info!("D-Bus launch request: {}", id);
+        launcher
+            .execute_in(&config.launchers)
+            .await
+            .result()
+            .map_err(fdo::Error::Failed)
+    }
+
+    fn search_ids(&self, terms: &[String]) -> fdo::Result<Vec<String>> {
+        let config = self.load_config()?;
+        let terms: Vec<&str> = terms.iter().map(|t| t.as_str()).collect();
+        Ok(config
+            .launchers
+            .iter()
+            .filter(|l| l.matches_terms(&terms))
+            .map(|l| l.id.clone())
+            .collect())
+    }
+}
+
+/// Launcher interface: `com.initium.app.Launcher`
+pub struct LauncherInterface(pub InitiumService);
+
+#[zbus::interface(name = "com.initium.app.Launcher")]
+impl LauncherInterface {
+    /// List launchers as (id, name, type, target) tuples
+    async fn list_launchers(&self) -> fdo::Result<Vec<(String, String, String, String)>> {
+        let config = self.0.load_config()?;
+        Ok(config.launchers.iter().map(launcher_tuple).collect())
+    }
+
+    /// Execute the launcher with the given id
+    async fn launch(&self, id: String) -> fdo::Result<()> {
+        self.0.launch(&id).await
+    }
+
+    /// Ids of the launchers matching the query
+    async fn search(&self, query: String) -> fdo::Result<Vec<String>> {
+        let terms: Vec<String> = query.split_whitespace().map(String::from).collect();
+        self.0.search_ids(&terms)
+    }
+}
+
+/// GNOME Shell search provider: `org.gnome.Shell.SearchProvider2`
+pub struct SearchProvider(pub InitiumService);
+
+#[zbus::interface(name = "org.gnome.Shell.SearchProvider2")]
+impl SearchProvider {
+    async fn get_initial_result_set(&self, terms: Vec<String>) -> fdo::Result<Vec<String>> {
+        self.0.search_ids(&terms)
+    }
+
+    async fn get_subsearch_result_set(
+        &self,
+        previous_results: Vec<String>,
+        terms: Vec<String>,
+    ) -> fdo::Result<Vec<String>> {
+        let ids = self.0.search_ids(&terms)?;
+        Ok(ids
+            .into_iter()
+            .filter(|id| previous_results.contains(id))
+            .collect())
+    }
+
+    async fn get_result_metas(
+        &self,
+        identifiers: Vec<String>,
+    ) -> fdo::Result<Vec<HashMap<String, Value<'static>>>> {
+        let config = self.0.load_config()?;
+        Ok(identifiers
+            .iter()
+            .filter_map(|id| config.find_launcher(id))
+            .map(result_meta)
+            .collect())
+    }
+
+    async fn activate_result(
+        &self,
+        identifier: String,
+        _terms: Vec<String>,
+        _timestamp: u32,
+    ) -> fdo::Result<()> {
+        self.0.launch(&identifier).await
+    }
+
+    async fn launch_search(&self, terms: Vec<String>, _timestamp: u32) -> fdo::Result<()> {
+        log::info!("D-Bus launch search: {:?}", terms);
+        let current_exe = std::env::current_exe().map_err(|e| fdo::Error::Failed(e.to_string()))?;
+        std::process::Command::new(current_exe)
+            .spawn()
+            .map_err(|e| fdo::Error::Failed(format!("Failed to open Initium: {}", e)))?;
+        Ok(())
+    }
+}
+
+fn launcher_tuple(launcher: &Launcher) -> (String, String, String, String) {
+    (
+        launcher.id.clone(),
+        launcher.name.clone(),
+        launcher.launch_type.as_str().to_string(),
+        launcher.target.clone(),
+    )
+}
+
+/// Build the result metadata dictionary expected by GNOME Shell
+fn result_meta(launcher: &Launcher) -> HashMap<String, Value<'static>> {
+    let mut meta = HashMap::new();
+    meta.insert("id".to_string(), Value::from(launcher.id.clone()));
+    meta.insert("name".to_string(), Value::from(launcher.name.clone()));
+    meta.insert(
+        "description".to_string(),
+        Value::from(launcher.target.clone()),
+    );
+    if let Some(icon) = launcher.icon_name() {
+        meta.insert("gicon".to_string(), Value::from(icon.to_string()));
+    }
+    meta
+}
+
+/// Register both interfaces on an existing connection builder
+fn serve(
+    builder: zbus::connection::Builder<'static>,
+    service: InitiumService,
+) -> zbus::Result<zbus::connection::Builder<'static>> {
+    builder
+        .serve_at(OBJECT_PATH, LauncherInterface(service.clone()))?
+        .serve_at(OBJECT_PATH, SearchProvider(service))
+}
+
+/// Connect to the session bus and own `BUS_NAME`
+pub async fn start_session_service(service: InitiumService) -> Result<zbus::Connection, String> {
+    let builder = zbus::connection::Builder::session()
+        .and_then(|b| b.name(BUS_NAME))
+        .map_err(|e| format!("D-Bus setup failed: {}", e))?;
+    let connection = serve(builder, service)
+        .map_err(|e| format!("D-Bus setup failed: {}", e))?
+        .build()
+        .await
+        .map_err(|e| format!("Failed to connect to session bus: {}", e))?;
+    log::info!("D-Bus service registered as {}", BUS_NAME);
+    Ok(connection)
+}
+
+/// Connect to a specific bus address (e.g. a private `dbus-daemon`) and own `BUS_NAME`
+pub async fn start_service_at(
+    address: &str,
+    service: InitiumService,
+) -> Result<zbus::Connection, String> {
+    let builder = zbus::connection::Builder::address(address)
+        .and_then(|b| b.name(BUS_NAME))
+        .map_err(|e| format!("D-Bus setup failed: {}", e))?;
+    serve(builder, service)
+        .map_err(|e| format!("D-Bus setup failed: {}", e))?
+        .build()
+        .await
+        .map_err(|e| format!("Failed to connect to {}: {}", address, e))
+}
+
+/// Run the session service until the process is terminated
+pub async fn run_session_service() -> Result<(), String> {
+    let _connection = start_session_service(InitiumService::new()).await?;
+    std::future::pending::<()>().await;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launcher::LaunchType;
+
+    fn test_config() -> Config {
+        Config {
+            launchers: vec![
+                Launcher::new(
+                    "github".to_string(),
+                    "GitHub".to_string(),
+                    LaunchType::Web,
+                    "https://github.com".to_string(),
+                ),
+                Launcher::new(
+                    "gimp".to_string(),
+                    "GIMP".to_string(),
+                    LaunchType::App,
+                    "gimp".to_string(),
+                ),
+            ],
+            ..Config::default()
+        }
+    }
+
+    /// Bus daemon killed when dropped, even when a test fails
+    struct BusDaemon(std::process::Child);
+
+    impl Drop for BusDaemon {
+        fn drop(&mut self) {
+            let _ = self.0.kill();
+            let _ = self.0.wait();
+        }
+    }
+
+    /// Start a private session bus, returning the daemon and its address
+    fn private_bus() -> Option<(BusDaemon, String)> {
+        use std::io::BufRead;
+
+        let mut daemon = BusDaemon(
+            std::process::Command::new("dbus-daemon")
+                .args(["--session", "--nofork", "--print-address"])
+                .stdout(std::process::Stdio::piped())
+                .spawn()
+                .ok()?,
+        );
+        let stdout = daemon.0.stdout.take()?;
+        let mut address = String::new();
+        std::io::BufReader::new(stdout).read_line(&mut address).ok()?;
+        Some((daemon, address.trim().to_string()))
+    }
+
+    #[test]
+    fn test_search_ids() {
+        let service = InitiumService::with_config(test_config());
+        let ids = service.search_ids(&["GI".to_string()]).unwrap();
+        assert_eq!(ids, vec!["github", "gimp"]);
+        let ids = service.search_ids(&["git".to_string(), "hub".to_string()]).unwrap();
+        assert_eq!(ids, vec!["github"]);
+    }
+
+    #[test]
+    fn test_result_meta() {
+        let mut launcher = test_config().launchers[0].clone();
+        launcher.icon = Some("github".to_string());
+        let meta = result_meta(&launcher);
+        assert_eq!(meta.get("id"), Some(&Value::from("github")));
+        assert_eq!(meta.get("name"), Some(&Value::from("GitHub")));
+        assert!(meta.contains_key("gicon"));
+    }
+
+    #[tokio::test]
+    async fn test_subsearch_filters_previous_results() {
+        let provider = SearchProvider(InitiumService::with_config(test_config()));
+        let ids = provider
+            .get_subsearch_result_set(vec!["gimp".to_string()], vec!["gi".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(ids, vec!["gimp"]);
+    }
+
+    #[tokio::test]
+    async fn test_private_bus_roundtrip() {
+        let Some((_daemon, address)) = private_bus() else {
+            eprintln!("dbus-daemon not available, skipping");
+            return;
+        };
+
+        let _server = start_service_at(&address, InitiumService::with_config(test_config()))
+            .await
+            .expect("Failed to start service");
+        let client = zbus::connection::Builder::address(address.as_str())
+            .unwrap()
+            .build()
+            .await
+            .expect("Failed to connect client");
+
+        let reply = client
+            .call_method(Some(BUS_NAME), OBJECT_PATH, Some("com.initium.app.Launcher"), "ListLaunchers", &())
+            .await
+            .expect("ListLaunchers failed");
+        let launchers: Vec<(String, String, String, String)> = reply.body().deserialize().unwrap();
+        assert_eq!(launchers.len(), 2);
+        assert_eq!(launchers[0].2, "web");
+
+        let reply = client
+            .call_method(
+                Some(BUS_NAME),
+                OBJECT_PATH,
+                Some("org.gnome.Shell.SearchProvider2"),
+                "GetInitialResultSet",
+                &(vec!["gimp"],),
+            )
+            .await
+            .expect("GetInitialResultSet failed");
+        let ids: Vec<String> = reply.body().deserialize().unwrap();
+        assert_eq!(ids, vec!["gimp"]);
+
+        let result = client
+            .call_method(Some(BUS_NAME), OBJECT_PATH, Some("com.initium.app.Launcher"), "Launch", &("missing",))
+            .await;
+        assert!(result.is_err());
+    }
+}
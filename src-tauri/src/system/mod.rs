@@ -1,4 +1,12 @@
 //! Module système - Gestion des services OS
+#[cfg(target_os = "linux")]
+pub mod appimage;
 pub mod autostart;
+#[cfg(target_os = "linux")]
+pub mod browsers;
 pub mod command_runner;
+#[cfg(target_os = "linux")]
+pub mod dbus_service;
+#[cfg(target_os = "linux")]
+pub mod packages;
 pub mod platform;
@@ -0,0 +1,205 @@
+//! Package Discovery (Linux)
+//!
+//! Lists the installed Flatpak applications from `flatpak list` and the
+//! graphical snaps from the snapd desktop directory.
+
+use crate::launcher::LaunchType;
+use crate::package::{is_snap_installed_in, InstalledApp, SNAP_MOUNT_DIR};
+use crate::sandbox::HostCommand;
+use crate::system::browsers::parse_desktop_entry;
+use crate::system::command_runner::{platform_runner, OSCommandRunner};
+use std::path::{Path, PathBuf};
+
+/// Columns requested from `flatpak list`, tab-separated in the output
+pub const FLATPAK_COLUMNS: &str = "application,name,version,installation";
+
+/// Desktop files exported by snapd
+pub const SNAP_DESKTOP_DIR: &str = "/var/lib/snapd/desktop/applications";
+
+/// Icon sizes looked up in Flatpak exports, best first
+const ICON_SIZES: [&str; 5] = ["scalable", "256x256", "128x128", "64x64", "48x48"];
+
+/// Flatpak installation directory (`system`, `user` or a custom path)
+fn installation_dir(installation: &str) -> PathBuf {
+    match installation {
+        "user" => dirs::data_dir().unwrap_or_default().join("flatpak"),
+        "system" | "" => PathBuf::from("/var/lib/flatpak"),
+        custom => PathBuf::from(custom),
+    }
+}
+
+/// Exported icon of a Flatpak application
+pub fn flatpak_icon(installation: &Path, app_id: &str) -> Option<String> {
+    let icons = installation.join("exports/share/icons/hicolor");
+    ICON_SIZES.iter().find_map(|size| {
+        let extension = if *size == "scalable" { "svg" } else { "png" };
+        let path = icons.join(size).join("apps").join(format!("{}.{}", app_id, extension));
+        path.is_file().then(|| path.to_string_lossy().to_string())
+    })
+}
+
+/// Parse `flatpak list --app --columns=` output with [`FLATPAK_COLUMNS`]
+pub fn parse_flatpak_list(stdout: &str) -> Vec<InstalledApp> {
+    let mut apps: Vec<InstalledApp> = Vec::new();
+    for line in stdout.lines() {
+        let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+        let Some(id) = columns.first().filter(|id| !id.is_empty()) else {
+            continue;
+        };
+        // Applications installed both system-wide and per user are listed twice
+        if apps.iter().any(|a| a.id == *id) {
+            continue;
+        }
+        let column = |index: usize| columns.get(index).filter(|c| !c.is_empty()).map(|c| c.to_string());
+        apps.push(InstalledApp {
+            launch_type: LaunchType::Flatpak,
+            id: id.to_string(),
+            name: column(1).unwrap_or_else(|| id.to_string()),
+            version: column(2),
+            icon: flatpak_icon(&installation_dir(columns.get(3).copied().unwrap_or("")), id),
+        });
+    }
+    apps
+}
+
+/// Installed Flatpak applications, empty when Flatpak is not available
+pub fn list_flatpak_apps() -> Vec<InstalledApp> {
+    let columns = format!("--columns={}", FLATPAK_COLUMNS);
+    let host = HostCommand::new("flatpak", &["list".to_string(), "--app".to_string(), columns], &[]);
+    let args: Vec<&str> = host.args.iter().map(String::as_str).collect();
+    match platform_runner().execute_with_output(&host.program, &args, 10) {
+        Ok(output) if output.success => parse_flatpak_list(&output.stdout),
+        Ok(output) => {
+            log::warn!("flatpak list failed: {}", output.stderr.trim());
+            Vec::new()
+        }
+        Err(e) => {
+            log::info!("Flatpak not available: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Snap command of a snapd desktop entry
+///
+/// Taken from the `/snap/bin/<command>` of the Exec line, or from the
+/// `<snap>_<app>.desktop` file name.
+pub fn snap_command(file_name: &str, exec: &str) -> Option<String> {
+    let from_exec = exec
+        .split_whitespace()
+        .find_map(|word| word.strip_prefix("/snap/bin/"))
+        .map(String::from);
+    from_exec.or_else(|| {
+        let stem = file_name.strip_suffix(".desktop")?;
+        let (snap, app) = stem.split_once('_')?;
+        Some(if snap == app { snap.to_string() } else { format!("{}.{}", snap, app) })
+    })
+}
+
+/// Graphical snaps declared in `desktop_dir` and mounted under `mount_dir`
+pub fn list_snap_apps_in(desktop_dir: &Path, mount_dir: &Path) -> Vec<InstalledApp> {
+    let Ok(read_dir) = std::fs::read_dir(desktop_dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = read_dir.flatten().map(|e| e.path()).collect();
+    paths.sort();
+
+    let mut apps: Vec<InstalledApp> = Vec::new();
+    for path in paths {
+        let file_name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let entry = parse_desktop_entry(&content);
+        let (Some(name), Some(exec)) = (entry.get("Name"), entry.get("Exec")) else {
+            continue;
+        };
+        let Some(command) = snap_command(&file_name, exec) else {
+            continue;
+        };
+        if entry.get("NoDisplay").is_some_and(|v| v == "true")
+            || !is_snap_installed_in(mount_dir, &command)
+            || apps.iter().any(|a| a.id == command)
+        {
+            continue;
+        }
+        apps.push(InstalledApp {
+            launch_type: LaunchType::Snap,
+            id: command,
+            name: name.clone(),
+            version: None,
+            icon: entry.get("Icon").filter(|i| Path::new(i).is_file()).cloned(),
+        });
+    }
+    apps
+}
+
+/// Installed graphical snaps
+pub fn list_snap_apps() -> Vec<InstalledApp> {
+    list_snap_apps_in(Path::new(SNAP_DESKTOP_DIR), Path::new(SNAP_MOUNT_DIR))
+}
+
+/// Installed Flatpak and Snap applications
+pub fn list_installed_apps() -> Vec<InstalledApp> {
+    let mut apps = list_flatpak_apps();
+    apps.extend(list_snap_apps());
+    log::info!("Found {} Flatpak and Snap application(s)", apps.len());
+    apps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_flatpak_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let icons = dir.path().join("exports/share/icons/hicolor/128x128/apps");
+        std::fs::create_dir_all(&icons).unwrap();
+        std::fs::write(icons.join("org.gimp.GIMP.png"), "").unwrap();
+
+        let output = format!(
+            "org.gimp.GIMP\tGNU Image Manipulation Program\t2.10.38\t{}\ncom.spotify.Client\tSpotify\t\tsystem\norg.gimp.GIMP\tGIMP\t2.10.38\tuser\n\n",
+            dir.path().display()
+        );
+        let apps = parse_flatpak_list(&output);
+        assert_eq!(apps.len(), 2);
+        assert_eq!(apps[0].launch_type, LaunchType::Flatpak);
+        assert_eq!(apps[0].name, "GNU Image Manipulation Program");
+        assert_eq!(apps[0].version.as_deref(), Some("2.10.38"));
+        assert!(apps[0].icon.as_deref().unwrap().ends_with("128x128/apps/org.gimp.GIMP.png"));
+        assert_eq!(apps[1].version, None);
+    }
+
+    #[test]
+    fn test_snap_command() {
+        assert_eq!(
+            snap_command("firefox_firefox.desktop", "env BAMF_DESKTOP_FILE_HINT=/var/lib/snapd/desktop/applications/firefox_firefox.desktop /snap/bin/firefox %u").as_deref(),
+            Some("firefox")
+        );
+        assert_eq!(snap_command("lxd_lxc.desktop", "lxc").as_deref(), Some("lxd.lxc"));
+        assert_eq!(snap_command("broken.desktop", "broken"), None);
+    }
+
+    #[test]
+    fn test_list_snap_apps() {
+        let root = tempfile::tempdir().unwrap();
+        let desktop = root.path().join("desktop");
+        let mount = root.path().join("snap");
+        std::fs::create_dir_all(&desktop).unwrap();
+        std::fs::create_dir_all(mount.join("spotify/current")).unwrap();
+        std::fs::write(
+            desktop.join("spotify_spotify.desktop"),
+            "[Desktop Entry]\nName=Spotify\nExec=/snap/bin/spotify %U\nIcon=/missing/icon.png\n",
+        )
+        .unwrap();
+        // Leftover desktop file of a removed snap
+        std::fs::write(desktop.join("vlc_vlc.desktop"), "[Desktop Entry]\nName=VLC\nExec=/snap/bin/vlc\n").unwrap();
+
+        let apps = list_snap_apps_in(&desktop, &mount);
+        assert_eq!(apps.len(), 1);
+        assert_eq!(apps[0].id, "spotify");
+        assert_eq!(apps[0].launch_type, LaunchType::Snap);
+        assert_eq!(apps[0].icon, None);
+    }
+}
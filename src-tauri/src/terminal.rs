@@ -0,0 +1,141 @@
+//! Terminal Module
+//!
+//! Runs CLI launchers (htop, ssh...) inside a terminal emulator.
+//! Features:
+//! - Global terminal setting, auto-detected when unset
+//!   (gnome-terminal, konsole, alacritty, kitty, wezterm, foot, xterm)
+//! - Per-terminal argument syntax
+//! - Hold the terminal open after the command exits
+
+use crate::config_manager::ConfigManager;
+use crate::fallback::{spawn_checked, split_command_line};
+use crate::launcher::LaunchOptions;
+use crate::parameter::shell_quote;
+use crate::precondition::find_program;
+
+/// Terminals tried by auto-detection, in order
+pub const KNOWN_TERMINALS: [&str; 7] = [
+    "gnome-terminal",
+    "konsole",
+    "alacritty",
+    "kitty",
+    "wezterm",
+    "foot",
+    "xterm",
+];
+
+/// First known terminal found on PATH
+pub fn detect_terminal() -> Option<String> {
+    find_program(&KNOWN_TERMINALS)
+}
+
+/// Terminal from the configuration, auto-detected when unset
+pub fn configured_terminal() -> Result<String, String> {
+    let configured = ConfigManager::load_or_default()?
+        .config()
+        .terminal
+        .clone()
+        .filter(|t| !t.trim().is_empty());
+    configured
+        .or_else(detect_terminal)
+        .ok_or_else(|| "No terminal emulator found, set one in the settings".to_string())
+}
+
+/// Shell command line of a program and its arguments
+pub fn command_line(program: &str, args: &[String]) -> String {
+    let mut line = program.to_string();
+    for arg in args {
+        line.push(' ');
+        line.push_str(&shell_quote(arg));
+    }
+    line
+}
+
+/// Wrap a command line so the terminal waits for Enter after it exits
+pub fn hold_command_line(line: &str) -> String {
+    format!(
+        "{}; status=$?; echo; echo \"[exited with status $status, press Enter to close]\"; read _",
+        line
+    )
+}
+
+/// Program and arguments running `line` through `sh -c` in `terminal`
+///
+/// `terminal` is a known terminal name or a command; unknown terminals get `-e`.
+pub fn terminal_command(terminal: &str, line: &str, hold: bool) -> Result<(String, Vec<String>), String> {
+    let mut words = split_command_line(terminal).into_iter();
+    let program = words.next().ok_or("No terminal configured")?;
+    let mut args: Vec<String> = words.collect();
+
+    let name = std::path::Path::new(&program)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    match name.as_str() {
+        "gnome-terminal" => args.push("--".to_string()),
+        "wezterm" => args.extend(["start".to_string(), "--".to_string()]),
+        "kitty" | "foot" => {}
+        _ => args.push("-e".to_string()),
+    }
+
+    let line = if hold {
+        hold_command_line(line)
+    } else {
+        line.to_string()
+    };
+    args.extend(["sh".to_string(), "-c".to_string(), line]);
+    Ok((program, args))
+}
+
+/// Run an application command line in the configured terminal
+pub async fn execute_in_terminal(target: &str, options: &LaunchOptions) -> Result<(), String> {
+    let terminal = configured_terminal()?;
+    let mut line = command_line(target, &options.args);
+    if let Some(dir) = &options.working_dir {
+        line = format!("cd {} && {}", shell_quote(dir), line);
+    }
+    let (program, args) = terminal_command(&terminal, &line, options.hold)?;
+    log::info!("Running in terminal {}: {}", terminal, line);
+    spawn_checked(&program, &args, options).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terminal_syntax() {
+        let line = "htop";
+        let cases = [
+            ("gnome-terminal", vec!["--", "sh", "-c", "htop"]),
+            ("konsole", vec!["-e", "sh", "-c", "htop"]),
+            ("alacritty", vec!["-e", "sh", "-c", "htop"]),
+            ("kitty", vec!["sh", "-c", "htop"]),
+            ("wezterm", vec!["start", "--", "sh", "-c", "htop"]),
+            ("foot", vec!["sh", "-c", "htop"]),
+            ("/usr/bin/xterm", vec!["-e", "sh", "-c", "htop"]),
+        ];
+        for (terminal, expected) in cases {
+            let (_, args) = terminal_command(terminal, line, false).unwrap();
+            assert_eq!(args, expected, "{}", terminal);
+        }
+    }
+
+    #[test]
+    fn test_custom_terminal_command() {
+        let (program, args) = terminal_command("kitty --class initium", "htop", false).unwrap();
+        assert_eq!(program, "kitty");
+        assert_eq!(args, vec!["--class", "initium", "sh", "-c", "htop"]);
+        assert!(terminal_command("", "htop", false).is_err());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_command_line_and_hold() {
+        let line = command_line("ssh", &["my host".to_string()]);
+        assert_eq!(line, "ssh 'my host'");
+        let (_, args) = terminal_command("xterm", &line, true).unwrap();
+        assert!(args[3].starts_with("ssh 'my host'; status=$?"));
+        assert!(args[3].ends_with("read _"));
+    }
+}
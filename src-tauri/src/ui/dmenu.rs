@@ -0,0 +1,282 @@
+//! dmenu / rofi Module
+//!
+//! Lets Initium's launchers be picked from dmenu-compatible menus
+//! without opening the Tauri window.
+//! Modes:
+//! - `initium --dmenu [--icons] [--menu <command>]`: pipes the launcher names
+//!   into the menu command, reads the selection back and executes it;
+//!   parameter values are asked through the same menu
+//! - `initium --rofi [selection]`: rofi script-mode protocol
+//!   (`rofi -show initium -modi "initium:initium --rofi"`)
+
+use crate::config_manager::ConfigManager;
+use crate::launcher::Launcher;
+use crate::parameter::{Parameter, ParameterKind};
+use std::collections::HashMap;
+use std::io::Write;
+
+/// Default menu command for `--dmenu`
+pub const DEFAULT_MENU_COMMAND: &str = "dmenu -i";
+
+/// Options of the `--dmenu` mode
+#[derive(Debug, Clone, PartialEq)]
+pub struct DmenuOptions {
+    /// Menu command run through `sh -c`
+    pub menu_command: String,
+    /// Append rofi `\0icon\x1f` row metadata
+    pub icons: bool,
+}
+
+impl Default for DmenuOptions {
+    fn default() -> Self {
+        DmenuOptions {
+            menu_command: DEFAULT_MENU_COMMAND.to_string(),
+            icons: false,
+        }
+    }
+}
+
+impl DmenuOptions {
+    /// Parse the arguments following `--dmenu`
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut options = DmenuOptions::default();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--icons" => options.icons = true,
+                "--menu" => {
+                    options.menu_command = iter
+                        .next()
+                        .ok_or("--menu requires a command")?
+                        .clone();
+                }
+                other => return Err(format!("Unknown dmenu option: {}", other)),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Format one menu row, with optional rofi icon and info metadata
+fn format_row(launcher: &Launcher, icons: bool, info: bool) -> String {
+    let mut row = launcher.name.replace('\n', " ");
+    let mut meta = Vec::new();
+    if icons {
+        if let Some(icon) = launcher.icon_name() {
+            meta.push(format!("icon\x1f{}", icon));
+        }
+    }
+    if info {
+        meta.push(format!("info\x1f{}", launcher.id));
+    }
+    if !meta.is_empty() {
+        row.push('\0');
+        row.push_str(&meta.join("\x1f"));
+    }
+    row
+}
+
+/// Format the menu input, one launcher per line
+pub fn format_entries(launchers: &[Launcher], icons: bool) -> String {
+    launchers
+        .iter()
+        .map(|l| format!("{}\n", format_row(l, icons, false)))
+        .collect()
+}
+
+/// Find the launcher matching a menu selection (by name, then by id)
+pub fn find_selection<'a>(launchers: &'a [Launcher], selection: &str) -> Option<&'a Launcher> {
+    let selection = selection.trim_end_matches(['\n', '\r']);
+    launchers
+        .iter()
+        .find(|l| l.name == selection)
+        .or_else(|| launchers.iter().find(|l| l.id == selection))
+}
+
+/// Run the menu command with the given rows, returning the selection or `None` when cancelled
+fn run_menu(menu_command: &str, rows: &str) -> Result<Option<String>, String> {
+    log::info!("Spawning menu: sh -c '{}'", menu_command);
+    let mut child = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(menu_command)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to spawn menu: {}", e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(rows.as_bytes())
+            .map_err(|e| format!("Failed to write menu entries: {}", e))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to read menu selection: {}", e))?;
+    let selection = String::from_utf8_lossy(&output.stdout)
+        .trim_end_matches(['\n', '\r'])
+        .to_string();
+    if !output.status.success() || selection.trim().is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(selection))
+}
+
+/// Menu rows offered for a parameter: its choices, or its default value
+///
+/// Any other value can be typed in the menu.
+pub fn parameter_rows(parameter: &Parameter) -> String {
+    let rows: Vec<String> = match &parameter.kind {
+        ParameterKind::Choice { choices } => choices.clone(),
+        ParameterKind::Boolean => vec!["true".to_string(), "false".to_string()],
+        _ => parameter.default.iter().cloned().collect(),
+    };
+    rows.iter().map(|row| format!("{}\n", row.replace('\n', " "))).collect()
+}
+
+/// Ask the value of each parameter through the menu, `None` when cancelled
+fn prompt_parameters(menu_command: &str, parameters: &[Parameter]) -> Result<Option<HashMap<String, String>>, String> {
+    let mut values = HashMap::new();
+    for parameter in parameters {
+        let Some(value) = run_menu(menu_command, &parameter_rows(parameter))? else {
+            return Ok(None);
+        };
+        values.insert(parameter.name.clone(), value);
+    }
+    Ok(Some(values))
+}
+
+/// Run the menu command and execute the selected launcher
+///
+/// The values of parameterized launchers are asked through the same menu.
+pub async fn run_dmenu(options: &DmenuOptions) -> Result<(), String> {
+    let manager = ConfigManager::load_or_default()?;
+    let launchers = &manager.config().launchers;
+
+    let Some(selection) = run_menu(&options.menu_command, &format_entries(launchers, options.icons))? else {
+        log::info!("Menu cancelled");
+        return Ok(());
+    };
+
+    let launcher = find_selection(launchers, &selection)
+        .ok_or_else(|| format!("Launcher not found: {}", selection.trim()))?;
+    let values = match launcher.parameters.as_deref() {
+        Some(parameters) if !parameters.is_empty() => {
+            match prompt_parameters(&options.menu_command, parameters)? {
+                Some(values) => values,
+                None => {
+                    log::info!("Menu cancelled");
+                    return Ok(());
+                }
+            }
+        }
+        _ => HashMap::new(),
+    };
+    launcher.with_parameters(&values)?.execute_in(launchers).await.result()
+}
+
+/// rofi script mode: list entries, or execute the selected entry
+///
+/// rofi passes the selected row as the first argument and its `info`
+/// metadata in `ROFI_INFO`. Parameterized launchers run with their default values.
+pub async fn run_rofi_script(selection: Option<&str>) -> Result<(), String> {
+    let manager = ConfigManager::load_or_default()?;
+    let launchers = &manager.config().launchers;
+
+    let Some(selection) = selection else {
+        let mut stdout = std::io::stdout();
+        let _ = writeln!(stdout, "\0prompt\x1fInitium");
+        for launcher in launchers {
+            writeln!(stdout, "{}", format_row(launcher, true, true))
+                .map_err(|e| format!("Failed to write entries: {}", e))?;
+        }
+        return Ok(());
+    };
+
+    let launcher = std::env::var("ROFI_INFO")
+        .ok()
+        .and_then(|id| launchers.iter().find(|l| l.id == id))
+        .or_else(|| find_selection(launchers, selection))
+        .ok_or_else(|| format!("Launcher not found: {}", selection))?;
+    launcher.execute_in(launchers).await.result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launcher::LaunchType;
+
+    fn launchers() -> Vec<Launcher> {
+        let mut github = Launcher::new(
+            "github".to_string(),
+            "GitHub".to_string(),
+            LaunchType::Web,
+            "https://github.com".to_string(),
+        );
+        github.icon = Some("/icons/github.png".to_string());
+        let mut gimp = Launcher::new(
+            "gimp".to_string(),
+            "GIMP".to_string(),
+            LaunchType::App,
+            "gimp".to_string(),
+        );
+        gimp.icon = Some("data:image/png;base64,AAAA".to_string());
+        vec![github, gimp]
+    }
+
+    #[test]
+    fn test_format_entries_plain() {
+        assert_eq!(format_entries(&launchers(), false), "GitHub\nGIMP\n");
+    }
+
+    #[test]
+    fn test_format_entries_icons() {
+        assert_eq!(
+            format_entries(&launchers(), true),
+            "GitHub\0icon\x1f/icons/github.png\nGIMP\n"
+        );
+    }
+
+    #[test]
+    fn test_format_row_info() {
+        assert_eq!(
+            format_row(&launchers()[1], true, true),
+            "GIMP\0info\x1fgimp"
+        );
+    }
+
+    #[test]
+    fn test_find_selection() {
+        let launchers = launchers();
+        assert_eq!(find_selection(&launchers, "GIMP\n").unwrap().id, "gimp");
+        assert_eq!(find_selection(&launchers, "github").unwrap().id, "github");
+        assert!(find_selection(&launchers, "Firefox").is_none());
+    }
+
+    #[test]
+    fn test_parameter_rows() {
+        let mut parameter: Parameter = serde_json::from_str(
+            r#"{"name": "env", "kind": "choice", "choices": ["dev", "prod"]}"#,
+        )
+        .unwrap();
+        assert_eq!(parameter_rows(&parameter), "dev\nprod\n");
+        parameter.kind = ParameterKind::Text { max_length: None };
+        assert_eq!(parameter_rows(&parameter), "");
+        parameter.default = Some("dev".to_string());
+        assert_eq!(parameter_rows(&parameter), "dev\n");
+    }
+
+    #[test]
+    fn test_dmenu_options_from_args() {
+        let args = vec![
+            "--icons".to_string(),
+            "--menu".to_string(),
+            "rofi -dmenu -show-icons".to_string(),
+        ];
+        let options = DmenuOptions::from_args(&args).unwrap();
+        assert!(options.icons);
+        assert_eq!(options.menu_command, "rofi -dmenu -show-icons");
+        assert_eq!(DmenuOptions::from_args(&[]).unwrap(), DmenuOptions::default());
+        assert!(DmenuOptions::from_args(&["--menu".to_string()]).is_err());
+    }
+}
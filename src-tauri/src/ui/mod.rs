@@ -1,2 +1,5 @@
 //! Module UI - Interface utilisateur avec Servo
+pub mod dmenu;
 pub mod servo_engine;
+pub mod tui;
+pub mod webview_window;
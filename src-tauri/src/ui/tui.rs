@@ -0,0 +1,822 @@
+//! Terminal UI Module
+//!
+//! Full-screen terminal dashboard (`initium tui`) for headless boxes and tmux.
+//! Features:
+//! - Category browsing and fuzzy search
+//! - Launching through the launcher execution layer, asking parameter values first
+//! - Running processes of app launchers (Linux), with kill support
+//! - In-memory log view
+
+use crate::config_manager::ConfigManager;
+use crate::fallback::split_command_line;
+use crate::launcher::{LaunchType, Launcher};
+use crate::parameter::{Parameter, ParameterKind};
+use crate::system::command_runner::{platform_runner, OSCommandRunner};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Tabs};
+use ratatui::Frame;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Category listing every launcher
+const ALL_CATEGORY: &str = "All";
+/// Category of launchers without one
+const UNCATEGORIZED: &str = "Uncategorized";
+/// Maximum number of log lines kept in memory
+const MAX_LOG_LINES: usize = 500;
+
+/// Logger collecting records for the log view (stderr would corrupt the screen)
+struct TuiLogger {
+    lines: Mutex<Vec<String>>,
+}
+
+static LOGGER: TuiLogger = TuiLogger {
+    lines: Mutex::new(Vec::new()),
+};
+
+impl log::Log for TuiLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Info
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        if let Ok(mut lines) = self.lines.lock() {
+            lines.push(format!("[{}] {}", record.level(), record.args()));
+            if lines.len() > MAX_LOG_LINES {
+                let excess = lines.len() - MAX_LOG_LINES;
+                lines.drain(..excess);
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+fn log_lines() -> Vec<String> {
+    LOGGER.lines.lock().map(|l| l.clone()).unwrap_or_default()
+}
+
+/// Active view of the terminal UI
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum View {
+    Launchers,
+    Processes,
+    Logs,
+}
+
+impl View {
+    const ALL: [View; 3] = [View::Launchers, View::Processes, View::Logs];
+
+    fn title(&self) -> &'static str {
+        match self {
+            View::Launchers => "Launchers",
+            View::Processes => "Processes",
+            View::Logs => "Logs",
+        }
+    }
+
+    fn index(&self) -> usize {
+        View::ALL.iter().position(|v| v == self).unwrap_or(0)
+    }
+
+    fn next(&self) -> View {
+        View::ALL[(self.index() + 1) % View::ALL.len()]
+    }
+
+    fn previous(&self) -> View {
+        View::ALL[(self.index() + View::ALL.len() - 1) % View::ALL.len()]
+    }
+}
+
+/// A running process started from an app launcher
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunningProcess {
+    pub pid: u32,
+    pub launcher_name: String,
+    pub command: String,
+}
+
+/// Action requested by a key press, executed by the event loop
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// Launch the launcher with this id
+    Launch(String),
+    /// Launch the launcher with this id and parameter values
+    LaunchWith(String, HashMap<String, String>),
+    Kill(u32),
+    RefreshProcesses,
+}
+
+/// Score a fuzzy (subsequence) match of `query` in `text`, higher is better
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = (position..text.len()).find(|&i| text[i] == q)?;
+        score += 1;
+        if previous_match == Some(found.wrapping_sub(1)) {
+            score += 5;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        score -= (found - position) as i64;
+        previous_match = Some(found);
+        position = found + 1;
+    }
+    Some(score)
+}
+
+/// Command line an app launcher starts: its target words, then its arguments
+fn launcher_argv(launcher: &Launcher) -> Option<Vec<String>> {
+    if launcher.launch_type != LaunchType::App {
+        return None;
+    }
+    let mut argv = split_command_line(&launcher.target);
+    if argv.is_empty() {
+        return None;
+    }
+    argv.extend(launcher.options.iter().flat_map(|o| o.args.iter().cloned()));
+    Some(argv)
+}
+
+/// Whether a process command line is exactly `expected`
+///
+/// A program given without directories matches it in any directory; every
+/// argument must be identical, so `python3 app.py` does not match other scripts.
+fn argv_matches(expected: &[String], actual: &[String]) -> bool {
+    let (Some(program), Some(running)) = (expected.first(), actual.first()) else {
+        return false;
+    };
+    let program_matches = if program.contains('/') {
+        running == program
+    } else {
+        running.rsplit('/').next() == Some(program.as_str())
+    };
+    program_matches && expected.len() == actual.len() && expected[1..] == actual[1..]
+}
+
+/// Find running processes whose command line is the one of an app launcher
+#[cfg(target_os = "linux")]
+pub fn find_launcher_processes(launchers: &[Launcher]) -> Vec<RunningProcess> {
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    let own_pid = std::process::id();
+    let mut processes = Vec::new();
+
+    for entry in entries.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|n| n.parse::<u32>().ok()) else {
+            continue;
+        };
+        if pid == own_pid {
+            continue;
+        }
+        let Ok(cmdline) = std::fs::read(entry.path().join("cmdline")) else {
+            continue;
+        };
+        let args: Vec<String> = cmdline
+            .split(|b| *b == 0)
+            .filter(|a| !a.is_empty())
+            .map(|a| String::from_utf8_lossy(a).to_string())
+            .collect();
+        if let Some(launcher) = launchers
+            .iter()
+            .find(|l| launcher_argv(l).is_some_and(|argv| argv_matches(&argv, &args)))
+        {
+            processes.push(RunningProcess {
+                pid,
+                launcher_name: launcher.name.clone(),
+                command: args.join(" "),
+            });
+        }
+    }
+    processes.sort_by_key(|p| p.pid);
+    processes
+}
+
+/// Find running processes whose command line is the one of an app launcher
+#[cfg(not(target_os = "linux"))]
+pub fn find_launcher_processes(_launchers: &[Launcher]) -> Vec<RunningProcess> {
+    Vec::new()
+}
+
+/// Parameter values being asked before a launch
+struct ParameterPrompt {
+    launcher_id: String,
+    parameters: Vec<Parameter>,
+    values: HashMap<String, String>,
+    input: String,
+}
+
+impl ParameterPrompt {
+    /// Parameter asked next
+    fn current(&self) -> Option<&Parameter> {
+        self.parameters.get(self.values.len())
+    }
+
+    fn label(&self) -> String {
+        let Some(parameter) = self.current() else {
+            return String::new();
+        };
+        let hint = match &parameter.kind {
+            ParameterKind::Choice { choices } => format!(" ({})", choices.join(", ")),
+            ParameterKind::Boolean => " (true/false)".to_string(),
+            _ => String::new(),
+        };
+        format!("{}{}", parameter.label.as_deref().unwrap_or(&parameter.name), hint)
+    }
+}
+
+/// Terminal UI state
+pub struct TuiApp {
+    launchers: Vec<Launcher>,
+    categories: Vec<String>,
+    category_index: usize,
+    query: String,
+    searching: bool,
+    selected: usize,
+    view: View,
+    processes: Vec<RunningProcess>,
+    process_selected: usize,
+    status: String,
+    prompt: Option<ParameterPrompt>,
+    should_quit: bool,
+}
+
+impl TuiApp {
+    /// Create the UI state from a launcher list
+    pub fn new(launchers: Vec<Launcher>) -> Self {
+        let mut categories: Vec<String> = launchers
+            .iter()
+            .map(|l| category_of(l).to_string())
+            .collect();
+        categories.sort();
+        categories.dedup();
+        categories.insert(0, ALL_CATEGORY.to_string());
+
+        TuiApp {
+            launchers,
+            categories,
+            category_index: 0,
+            query: String::new(),
+            searching: false,
+            selected: 0,
+            view: View::Launchers,
+            processes: Vec::new(),
+            process_selected: 0,
+            status: String::new(),
+            prompt: None,
+            should_quit: false,
+        }
+    }
+
+    /// Launchers of the current category matching the search, best match first
+    pub fn visible_launchers(&self) -> Vec<&Launcher> {
+        let category = &self.categories[self.category_index];
+        let mut scored: Vec<(i64, &Launcher)> = self
+            .launchers
+            .iter()
+            .filter(|l| category == ALL_CATEGORY || category_of(l) == category)
+            .filter_map(|l| fuzzy_score(&self.query, &l.name).map(|score| (score, l)))
+            .collect();
+        if !self.query.is_empty() {
+            scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        }
+        scored.into_iter().map(|(_, l)| l).collect()
+    }
+
+    /// Currently selected launcher
+    pub fn selected_launcher(&self) -> Option<&Launcher> {
+        self.visible_launchers().get(self.selected).copied()
+    }
+
+    pub fn view(&self) -> View {
+        self.view
+    }
+
+    pub fn should_quit(&self) -> bool {
+        self.should_quit
+    }
+
+    pub fn set_status(&mut self, status: String) {
+        self.status = status;
+    }
+
+    pub fn set_processes(&mut self, processes: Vec<RunningProcess>) {
+        self.processes = processes;
+        self.process_selected = self
+            .process_selected
+            .min(self.processes.len().saturating_sub(1));
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let (selected, len) = match self.view {
+            View::Processes => (&mut self.process_selected, self.processes.len()),
+            _ => {
+                let len = self.visible_launchers().len();
+                (&mut self.selected, len)
+            }
+        };
+        if len == 0 {
+            *selected = 0;
+            return;
+        }
+        *selected = (*selected as isize + delta).clamp(0, len as isize - 1) as usize;
+    }
+
+    /// Launch the selected launcher, asking its parameter values first
+    fn launch_selected(&mut self) -> Option<Action> {
+        let launcher = self.selected_launcher()?;
+        let id = launcher.id.clone();
+        match launcher.parameters.clone() {
+            Some(parameters) if !parameters.is_empty() => {
+                let input = parameters[0].default.clone().unwrap_or_default();
+                self.prompt = Some(ParameterPrompt {
+                    launcher_id: id,
+                    parameters,
+                    values: HashMap::new(),
+                    input,
+                });
+                None
+            }
+            _ => Some(Action::Launch(id)),
+        }
+    }
+
+    /// Handle a key press while parameter values are asked
+    fn handle_prompt_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let prompt = self.prompt.as_mut()?;
+        match key.code {
+            KeyCode::Esc => self.prompt = None,
+            KeyCode::Backspace => {
+                prompt.input.pop();
+            }
+            KeyCode::Char(c) => prompt.input.push(c),
+            KeyCode::Enter => {
+                let name = prompt.current()?.name.clone();
+                prompt.values.insert(name, std::mem::take(&mut prompt.input));
+                match prompt.current() {
+                    Some(next) => prompt.input = next.default.clone().unwrap_or_default(),
+                    None => {
+                        let prompt = self.prompt.take()?;
+                        return Some(Action::LaunchWith(prompt.launcher_id, prompt.values));
+                    }
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn move_category(&mut self, delta: isize) {
+        let len = self.categories.len() as isize;
+        self.category_index = ((self.category_index as isize + delta + len) % len) as usize;
+        self.selected = 0;
+    }
+
+    /// Handle a key press, returning the action to execute if any
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.should_quit = true;
+            return None;
+        }
+
+        if self.prompt.is_some() {
+            return self.handle_prompt_key(key);
+        }
+
+        if self.searching {
+            match key.code {
+                KeyCode::Esc => {
+                    self.searching = false;
+                    self.query.clear();
+                    self.selected = 0;
+                }
+                KeyCode::Enter => {
+                    self.searching = false;
+                    return self.launch_selected();
+                }
+                KeyCode::Backspace => {
+                    self.query.pop();
+                    self.selected = 0;
+                }
+                KeyCode::Up => self.move_selection(-1),
+                KeyCode::Down => self.move_selection(1),
+                KeyCode::Char(c) => {
+                    self.query.push(c);
+                    self.selected = 0;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Tab => {
+                self.view = self.view.next();
+                if self.view == View::Processes {
+                    return Some(Action::RefreshProcesses);
+                }
+            }
+            KeyCode::BackTab => {
+                self.view = self.view.previous();
+                if self.view == View::Processes {
+                    return Some(Action::RefreshProcesses);
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            _ => match self.view {
+                View::Launchers => match key.code {
+                    KeyCode::Left | KeyCode::Char('h') => self.move_category(-1),
+                    KeyCode::Right | KeyCode::Char('l') => self.move_category(1),
+                    KeyCode::Char('/') => {
+                        self.searching = true;
+                        self.query.clear();
+                        self.selected = 0;
+                    }
+                    KeyCode::Enter => return self.launch_selected(),
+                    _ => {}
+                },
+                View::Processes => match key.code {
+                    KeyCode::Char('r') => return Some(Action::RefreshProcesses),
+                    KeyCode::Char('x') => {
+                        return self
+                            .processes
+                            .get(self.process_selected)
+                            .map(|p| Action::Kill(p.pid));
+                    }
+                    _ => {}
+                },
+                View::Logs => {}
+            },
+        }
+        None
+    }
+
+    /// Draw the whole interface
+    pub fn draw(&self, frame: &mut Frame) {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(3),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        let titles: Vec<Line> = View::ALL.iter().map(|v| Line::from(v.title())).collect();
+        let tabs = Tabs::new(titles)
+            .block(Block::default().borders(Borders::ALL).title(" Initium "))
+            .select(self.view.index())
+            .highlight_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+        frame.render_widget(tabs, rows[0]);
+
+        match self.view {
+            View::Launchers => self.draw_launchers(frame, rows[1]),
+            View::Processes => self.draw_processes(frame, rows[1]),
+            View::Logs => self.draw_logs(frame, rows[1]),
+        }
+
+        let footer = if let Some(prompt) = &self.prompt {
+            format!("{}: {}▏", prompt.label(), prompt.input)
+        } else if self.searching {
+            format!("/{}▏", self.query)
+        } else if !self.status.is_empty() {
+            self.status.clone()
+        } else {
+            match self.view {
+                View::Launchers => "↑↓ select  ←→ category  / search  Enter launch  Tab view  q quit",
+                View::Processes => "↑↓ select  x kill  r refresh  Tab view  q quit",
+                View::Logs => "Tab view  q quit",
+            }
+            .to_string()
+        };
+        frame.render_widget(
+            Paragraph::new(footer).block(Block::default().borders(Borders::ALL)),
+            rows[2],
+        );
+    }
+
+    fn draw_launchers(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(25), Constraint::Percentage(75)])
+            .split(area);
+
+        let categories: Vec<ListItem> = self
+            .categories
+            .iter()
+            .map(|c| ListItem::new(c.as_str()))
+            .collect();
+        let mut category_state = ListState::default().with_selected(Some(self.category_index));
+        frame.render_stateful_widget(
+            List::new(categories)
+                .block(Block::default().borders(Borders::ALL).title(" Categories "))
+                .highlight_style(Style::default().fg(Color::Cyan)),
+            columns[0],
+            &mut category_state,
+        );
+
+        let launchers: Vec<ListItem> = self
+            .visible_launchers()
+            .iter()
+            .map(|l| {
+                ListItem::new(format!("{:<30} {:<8} {}", l.name, l.launch_type.as_str(), l.target))
+            })
+            .collect();
+        let mut launcher_state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(
+            List::new(launchers)
+                .block(Block::default().borders(Borders::ALL).title(" Launchers "))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+            columns[1],
+            &mut launcher_state,
+        );
+    }
+
+    fn draw_processes(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let items: Vec<ListItem> = self
+            .processes
+            .iter()
+            .map(|p| ListItem::new(format!("{:>7}  {:<24} {}", p.pid, p.launcher_name, p.command)))
+            .collect();
+        let mut state = ListState::default().with_selected(Some(self.process_selected));
+        frame.render_stateful_widget(
+            List::new(items)
+                .block(Block::default().borders(Borders::ALL).title(" Running processes "))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+            area,
+            &mut state,
+        );
+    }
+
+    fn draw_logs(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let lines = log_lines();
+        let visible = area.height.saturating_sub(2) as usize;
+        let start = lines.len().saturating_sub(visible);
+        let text: Vec<Line> = lines[start..].iter().map(|l| Line::from(l.as_str())).collect();
+        frame.render_widget(
+            Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(" Logs ")),
+            area,
+        );
+    }
+}
+
+fn category_of(launcher: &Launcher) -> &str {
+    launcher
+        .category
+        .as_deref()
+        .filter(|c| !c.is_empty())
+        .unwrap_or(UNCATEGORIZED)
+}
+
+/// Run the terminal UI until the user quits
+pub async fn run_tui() -> Result<(), String> {
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(log::LevelFilter::Info);
+    }
+
+    let manager = ConfigManager::load_or_default()?;
+    let mut app = TuiApp::new(manager.config().launchers.clone());
+    let mut terminal = ratatui::init();
+
+    let result = loop {
+        if let Err(e) = terminal.draw(|frame| app.draw(frame)) {
+            break Err(format!("Failed to draw terminal UI: {}", e));
+        }
+        if app.should_quit() {
+            break Ok(());
+        }
+
+        match event::poll(Duration::from_millis(250)) {
+            Ok(true) => {}
+            Ok(false) => continue,
+            Err(e) => break Err(format!("Failed to read terminal events: {}", e)),
+        }
+        let key = match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => key,
+            Ok(_) => continue,
+            Err(e) => break Err(format!("Failed to read terminal events: {}", e)),
+        };
+
+        match app.handle_key(key) {
+            Some(Action::Launch(id)) => {
+                let Some(launcher) = manager.config().find_launcher(&id) else {
+                    continue;
+                };
+                let status = match launcher.execute_in(&manager.config().launchers).await.result() {
+                    Ok(()) => format!("Launched '{}'", launcher.name),
+                    Err(e) => format!("Failed to launch '{}': {}", launcher.name, e),
+                };
+                app.set_status(status);
+            }
+            Some(Action::LaunchWith(id, values)) => {
+                let Some(launcher) = manager.config().find_launcher(&id) else {
+                    continue;
+                };
+                let result = match launcher.with_parameters(&values) {
+                    Ok(resolved) => resolved.execute_in(&manager.config().launchers).await.result(),
+                    Err(e) => Err(e),
+                };
+                let status = match result {
+                    Ok(()) => format!("Launched '{}'", launcher.name),
+                    Err(e) => format!("Failed to launch '{}': {}", launcher.name, e),
+                };
+                app.set_status(status);
+            }
+            Some(Action::Kill(pid)) => {
+                let status = match platform_runner().kill_process(pid) {
+                    Ok(()) => format!("Killed process {}", pid),
+                    Err(e) => e,
+                };
+                app.set_status(status);
+                app.set_processes(find_launcher_processes(&manager.config().launchers));
+            }
+            Some(Action::RefreshProcesses) => {
+                app.set_processes(find_launcher_processes(&manager.config().launchers));
+            }
+            None => {}
+        }
+    };
+
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn launchers() -> Vec<Launcher> {
+        let mut github = Launcher::new(
+            "github".to_string(),
+            "GitHub".to_string(),
+            LaunchType::Web,
+            "https://github.com".to_string(),
+        );
+        github.category = Some("Dev".to_string());
+        let mut gimp = Launcher::new(
+            "gimp".to_string(),
+            "GIMP".to_string(),
+            LaunchType::App,
+            "/usr/bin/gimp --new-instance".to_string(),
+        );
+        gimp.category = Some("Graphics".to_string());
+        let terminal = Launcher::new(
+            "terminal".to_string(),
+            "Terminal".to_string(),
+            LaunchType::App,
+            "gnome-terminal".to_string(),
+        );
+        vec![github, gimp, terminal]
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("ghb", "GitHub").is_some());
+        assert!(fuzzy_score("xyz", "GitHub").is_none());
+        assert!(fuzzy_score("git", "GitHub") > fuzzy_score("gtb", "GitHub"));
+        assert_eq!(fuzzy_score("", "GitHub"), Some(0));
+    }
+
+    #[test]
+    fn test_categories() {
+        let app = TuiApp::new(launchers());
+        assert_eq!(app.categories, vec!["All", "Dev", "Graphics", "Uncategorized"]);
+    }
+
+    #[test]
+    fn test_category_navigation_filters_launchers() {
+        let mut app = TuiApp::new(launchers());
+        assert_eq!(app.visible_launchers().len(), 3);
+        app.handle_key(key(KeyCode::Right));
+        let visible = app.visible_launchers();
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].id, "github");
+        app.handle_key(key(KeyCode::Left));
+        app.handle_key(key(KeyCode::Left));
+        assert_eq!(app.visible_launchers()[0].id, "terminal");
+    }
+
+    #[test]
+    fn test_search_and_launch() {
+        let mut app = TuiApp::new(launchers());
+        app.handle_key(key(KeyCode::Char('/')));
+        for c in "gmp".chars() {
+            app.handle_key(key(KeyCode::Char(c)));
+        }
+        assert_eq!(app.visible_launchers().len(), 1);
+        match app.handle_key(key(KeyCode::Enter)) {
+            Some(Action::Launch(id)) => assert_eq!(id, "gimp"),
+            other => panic!("Unexpected action: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_launch_asks_parameters() {
+        let mut launchers = launchers();
+        launchers[1].parameters = Some(vec![
+            serde_json::from_str(r#"{"name": "file", "kind": "file", "default": "a.png"}"#).unwrap(),
+            serde_json::from_str(r#"{"name": "mode", "kind": "choice", "choices": ["rgb", "gray"]}"#).unwrap(),
+        ]);
+        let mut app = TuiApp::new(launchers);
+        app.handle_key(key(KeyCode::Down));
+        assert_eq!(app.handle_key(key(KeyCode::Enter)), None);
+        assert_eq!(app.prompt.as_ref().unwrap().input, "a.png");
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.prompt.as_ref().unwrap().label(), "mode (rgb, gray)");
+        for c in "gray".chars() {
+            app.handle_key(key(KeyCode::Char(c)));
+        }
+        match app.handle_key(key(KeyCode::Enter)) {
+            Some(Action::LaunchWith(id, values)) => {
+                assert_eq!(id, "gimp");
+                assert_eq!(values["file"], "a.png");
+                assert_eq!(values["mode"], "gray");
+            }
+            other => panic!("Unexpected action: {:?}", other),
+        }
+        assert!(app.prompt.is_none());
+
+        app.handle_key(key(KeyCode::Enter));
+        app.handle_key(key(KeyCode::Char('q')));
+        app.handle_key(key(KeyCode::Esc));
+        assert!(app.prompt.is_none());
+        assert!(!app.should_quit());
+    }
+
+    #[test]
+    fn test_search_escape_clears_query() {
+        let mut app = TuiApp::new(launchers());
+        app.handle_key(key(KeyCode::Char('/')));
+        app.handle_key(key(KeyCode::Char('q')));
+        assert!(!app.should_quit());
+        app.handle_key(key(KeyCode::Esc));
+        assert_eq!(app.visible_launchers().len(), 3);
+        assert!(!app.should_quit());
+    }
+
+    #[test]
+    fn test_view_switch_and_kill() {
+        let mut app = TuiApp::new(launchers());
+        assert_eq!(app.handle_key(key(KeyCode::Tab)), Some(Action::RefreshProcesses));
+        assert_eq!(app.view(), View::Processes);
+        app.set_processes(vec![RunningProcess {
+            pid: 42,
+            launcher_name: "GIMP".to_string(),
+            command: "gimp".to_string(),
+        }]);
+        assert_eq!(app.handle_key(key(KeyCode::Char('x'))), Some(Action::Kill(42)));
+        app.handle_key(key(KeyCode::BackTab));
+        assert_eq!(app.view(), View::Launchers);
+    }
+
+    #[test]
+    fn test_quit() {
+        let mut app = TuiApp::new(launchers());
+        app.handle_key(key(KeyCode::Char('q')));
+        assert!(app.should_quit());
+    }
+
+    #[test]
+    fn test_launcher_argv_matches() {
+        let argv = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<String>>();
+        let mut script = Launcher::new("app".to_string(), "App".to_string(), LaunchType::App, "python3 app.py".to_string());
+        let expected = launcher_argv(&script).unwrap();
+        assert!(argv_matches(&expected, &argv(&["/usr/bin/python3", "app.py"])));
+        assert!(!argv_matches(&expected, &argv(&["python3", "other.py"])));
+        assert!(!argv_matches(&expected, &argv(&["python3"])));
+
+        script.target = "'/home/me/My Apps/Foo.AppImage'".to_string();
+        script.options = Some(crate::launcher::LaunchOptions {
+            args: vec!["--new".to_string()],
+            ..Default::default()
+        });
+        let expected = launcher_argv(&script).unwrap();
+        assert!(argv_matches(&expected, &argv(&["/home/me/My Apps/Foo.AppImage", "--new"])));
+        assert!(!argv_matches(&expected, &argv(&["/opt/Foo.AppImage", "--new"])));
+
+        script.launch_type = LaunchType::Web;
+        assert_eq!(launcher_argv(&script), None);
+    }
+}
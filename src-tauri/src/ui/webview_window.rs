@@ -0,0 +1,252 @@
+//! Embedded Webview Windows
+//!
+//! Web launchers in embedded display mode open in their own Initium window,
+//! without browser chrome.
+//! Features:
+//! - One window per launcher, focused again when already open
+//! - Persistent data directory per launcher (cookies, storage)
+//! - Window size and position remembered per launcher
+
+use crate::config_manager::ConfigManager;
+use crate::launcher::Launcher;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Default size of a new embedded window
+pub const DEFAULT_WINDOW_SIZE: (u32, u32) = (1024, 768);
+
+/// Logical size and position of a window
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct WindowGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    #[serde(default)]
+    pub maximized: bool,
+}
+
+/// Remembered window geometries, keyed by launcher id
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WindowStates {
+    #[serde(default)]
+    windows: HashMap<String, WindowGeometry>,
+}
+
+impl WindowStates {
+    /// Get window states file path
+    pub fn get_states_path() -> PathBuf {
+        ConfigManager::get_settings_dir_path().join("window_states.json")
+    }
+
+    /// Load states from a file, empty if the file does not exist
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(WindowStates::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read window states: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse window states: {}", e))
+    }
+
+    /// Save states to a file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self)
+            .map_err(|e| format!("Failed to serialize window states: {}", e))?;
+        std::fs::write(path, json).map_err(|e| format!("Failed to save window states: {}", e))
+    }
+
+    /// Geometry of a launcher window
+    pub fn get(&self, launcher_id: &str) -> Option<WindowGeometry> {
+        self.windows.get(launcher_id).copied()
+    }
+
+    /// Remember the geometry of a launcher window
+    pub fn set(&mut self, launcher_id: &str, geometry: WindowGeometry) {
+        self.windows.insert(launcher_id.to_string(), geometry);
+    }
+}
+
+/// Remember a window geometry in the window states file
+pub fn remember_geometry(launcher_id: &str, geometry: WindowGeometry) -> Result<(), String> {
+    let path = WindowStates::get_states_path();
+    let mut states = WindowStates::load(&path)?;
+    states.set(launcher_id, geometry);
+    states.save(&path)
+}
+
+/// Window label of a launcher (letters, digits, `-` and `_` only)
+pub fn window_label(launcher_id: &str) -> String {
+    let id: String = launcher_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    format!("launcher-{}", id)
+}
+
+/// Data directory of a launcher window
+pub fn data_directory(launcher_id: &str) -> PathBuf {
+    ConfigManager::get_settings_dir_path()
+        .join("webviews")
+        .join(window_label(launcher_id))
+}
+
+/// Icon of an embedded window
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowIcon {
+    /// Icon file or theme name
+    File(String),
+    /// Image bytes decoded from a data URL
+    Data(Vec<u8>),
+}
+
+impl WindowIcon {
+    /// Icon of a launcher, `None` when unset or the data URL is invalid
+    pub fn for_launcher(launcher: &Launcher) -> Option<Self> {
+        let icon = launcher.icon.as_deref()?;
+        if icon.starts_with("data:") {
+            return decode_data_url(icon).map(WindowIcon::Data);
+        }
+        launcher.icon_name().map(|name| WindowIcon::File(name.to_string()))
+    }
+}
+
+/// Bytes of a base64 data URL (`data:image/png;base64,...`)
+pub fn decode_data_url(url: &str) -> Option<Vec<u8>> {
+    let (header, data) = url.strip_prefix("data:")?.split_once(',')?;
+    if !header.ends_with(";base64") {
+        return None;
+    }
+    base64_decode(data)
+}
+
+/// Decode standard base64, ignoring whitespace and padding
+fn base64_decode(data: &str) -> Option<Vec<u8>> {
+    let mut result = Vec::with_capacity(data.len() / 4 * 3);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in data.bytes().filter(|c| !c.is_ascii_whitespace() && *c != b'=') {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(result)
+}
+
+/// Everything needed to open the embedded window of a launcher
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddedWindow {
+    pub label: String,
+    pub url: String,
+    pub title: String,
+    pub data_directory: PathBuf,
+    pub icon: Option<WindowIcon>,
+    /// Remembered geometry, default size when unknown
+    pub geometry: Option<WindowGeometry>,
+}
+
+impl EmbeddedWindow {
+    /// Describe the embedded window of a web launcher
+    pub fn for_launcher(launcher: &Launcher, states: &WindowStates) -> Result<Self, String> {
+        let url = launcher.target.trim();
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err(format!("Only http(s) URLs can be embedded: {}", url));
+        }
+        Ok(EmbeddedWindow {
+            label: window_label(&launcher.id),
+            url: url.to_string(),
+            title: launcher.name.clone(),
+            data_directory: data_directory(&launcher.id),
+            icon: WindowIcon::for_launcher(launcher),
+            geometry: states.get(&launcher.id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launcher::LaunchType;
+
+    #[test]
+    fn test_window_label() {
+        assert_eq!(window_label("grafana"), "launcher-grafana");
+        assert_eq!(window_label("my tool.v2"), "launcher-my_tool_v2");
+    }
+
+    #[test]
+    fn test_embedded_window_for_launcher() {
+        let mut launcher = Launcher::new(
+            "grafana".to_string(),
+            "Grafana".to_string(),
+            LaunchType::Web,
+            "https://grafana.internal".to_string(),
+        );
+        let mut states = WindowStates::default();
+        let geometry = WindowGeometry {
+            x: 10,
+            y: 20,
+            width: 800,
+            height: 600,
+            maximized: false,
+        };
+        states.set("grafana", geometry);
+
+        let window = EmbeddedWindow::for_launcher(&launcher, &states).unwrap();
+        assert_eq!(window.label, "launcher-grafana");
+        assert_eq!(window.geometry, Some(geometry));
+        assert!(window.data_directory.ends_with("webviews/launcher-grafana"));
+
+        assert_eq!(window.icon, None);
+
+        launcher.icon = Some("data:image/png;base64,iVBORw0K".to_string());
+        let window = EmbeddedWindow::for_launcher(&launcher, &states).unwrap();
+        assert_eq!(window.icon, Some(WindowIcon::Data(b"\x89PNG\r\n".to_vec())));
+
+        launcher.target = "file:///etc/passwd".to_string();
+        assert!(EmbeddedWindow::for_launcher(&launcher, &states).is_err());
+    }
+
+    #[test]
+    fn test_decode_data_url() {
+        assert_eq!(decode_data_url("data:image/png;base64,aW5p\ndGl1bQ=="), Some(b"initium".to_vec()));
+        assert_eq!(decode_data_url("data:image/svg+xml,<svg/>"), None);
+        assert_eq!(decode_data_url("data:image/png;base64,a*b"), None);
+        assert_eq!(decode_data_url("/usr/share/icons/a.png"), None);
+    }
+
+    #[test]
+    fn test_window_states_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("window_states.json");
+        let mut states = WindowStates::load(&path).unwrap();
+        assert!(states.get("grafana").is_none());
+
+        states.set(
+            "grafana",
+            WindowGeometry {
+                x: 0,
+                y: 0,
+                width: 1024,
+                height: 768,
+                maximized: true,
+            },
+        );
+        states.save(&path).unwrap();
+        assert!(WindowStates::load(&path).unwrap().get("grafana").unwrap().maximized);
+    }
+}